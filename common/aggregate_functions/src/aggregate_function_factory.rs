@@ -37,12 +37,19 @@ impl AggregateFunctionFactory {
     }
 
     pub fn check(name: &str) -> bool {
+        Self::contains(name)
+    }
+
+    pub fn contains(name: &str) -> bool {
         let map = FACTORY.read();
         map.contains_key(&*name.to_lowercase())
     }
 
+    /// All the registered function names, sorted.
     pub fn registered_names() -> Vec<String> {
         let map = FACTORY.read();
-        map.keys().into_iter().map(|x| x.to_string()).collect()
+        let mut names: Vec<String> = map.keys().map(|x| x.to_string()).collect();
+        names.sort();
+        names
     }
 }
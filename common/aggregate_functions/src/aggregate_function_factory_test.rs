@@ -0,0 +1,32 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::*;
+
+#[test]
+fn test_aggregate_function_factory_registered_names() -> Result<()> {
+    let names = AggregateFunctionFactory::registered_names();
+    assert!(names.contains(&"count".to_string()));
+    assert!(!names.contains(&"not_exists_function".to_string()));
+
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(sorted, names);
+
+    assert!(AggregateFunctionFactory::contains("sum"));
+    assert!(AggregateFunctionFactory::contains("SUM"));
+    assert!(!AggregateFunctionFactory::contains("not_exists_function"));
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_factory_case_insensitive() -> Result<()> {
+    assert!(AggregateFunctionFactory::get("COUNT").is_ok());
+    assert!(AggregateFunctionFactory::get("count").is_ok());
+    assert!(AggregateFunctionFactory::get("Count").is_ok());
+    assert!(AggregateFunctionFactory::get("NOT_EXISTS_FUNCTION").is_err());
+    Ok(())
+}
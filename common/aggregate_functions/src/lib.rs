@@ -2,6 +2,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+mod aggregate_function_factory_test;
 #[cfg(test)]
 mod aggregator_test;
 
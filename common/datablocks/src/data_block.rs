@@ -8,10 +8,13 @@ use std::sync::Arc;
 
 use common_arrow::arrow;
 use common_arrow::arrow::record_batch::RecordBatch;
+use common_datavalues::index_of_ignore_case;
 use common_datavalues::DataArrayRef;
 use common_datavalues::DataColumnarValue;
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCodes;
 use common_exception::Result;
 
@@ -72,8 +75,9 @@ impl DataBlock {
     }
 
     /// Data Block physical memory size
+    /// Nested arrays are accounted recursively, constant columns are near-zero.
     pub fn memory_size(&self) -> usize {
-        self.columns.iter().map(|x| x.get_array_memory_size()).sum()
+        self.columns.iter().map(|x| x.memory_size()).sum()
     }
 
     pub fn column(&self, index: usize) -> &DataColumnarValue {
@@ -84,11 +88,12 @@ impl DataBlock {
         &self.columns
     }
 
+    /// The column of the name, the name is matched as `field_with_name_ignore_case`.
     pub fn try_column_by_name(&self, name: &str) -> Result<&DataColumnarValue> {
         if name == "*" {
             Ok(&self.columns[0])
         } else {
-            let idx = self.schema.index_of(name)?;
+            let idx = index_of_ignore_case(&self.schema, name)?;
             Ok(&self.columns[idx])
         }
     }
@@ -102,7 +107,7 @@ impl DataBlock {
             return Some(&self.columns[0]);
         };
 
-        if let Ok(idx) = self.schema.index_of(name) {
+        if let Ok(idx) = index_of_ignore_case(&self.schema, name) {
             Some(&self.columns[idx])
         } else {
             None
@@ -113,10 +118,73 @@ impl DataBlock {
         if name == "*" {
             self.columns[0].to_array()
         } else {
-            let idx = self.schema.index_of(name)?;
+            let idx = index_of_ignore_case(&self.schema, name)?;
             self.columns[idx].to_array()
         }
     }
+
+    /// Returns a new block with only the columns at `indices`, in that order.
+    pub fn project(&self, indices: &[usize]) -> Result<DataBlock> {
+        let mut fields = Vec::with_capacity(indices.len());
+        let mut columns = Vec::with_capacity(indices.len());
+        for &index in indices {
+            if index >= self.num_columns() {
+                return Result::Err(ErrorCodes::BadArguments(format!(
+                    "Projection index {} is out of range, block has {} columns",
+                    index,
+                    self.num_columns()
+                )));
+            }
+            fields.push(self.schema.field(index).clone());
+            columns.push(self.columns[index].clone());
+        }
+        Ok(DataBlock::create(DataSchemaRefExt::create(fields), columns))
+    }
+
+    /// Returns the `length` rows from `offset`, the range is clamped to the rows of the block.
+    /// The array columns share the buffers with the slice.
+    pub fn slice(&self, offset: usize, length: usize) -> DataBlock {
+        let offset = offset.min(self.num_rows());
+        let length = length.min(self.num_rows() - offset);
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| match column {
+                DataColumnarValue::Array(array) => {
+                    DataColumnarValue::Array(array.slice(offset, length))
+                }
+                DataColumnarValue::Constant(scalar, _) => {
+                    DataColumnarValue::Constant(scalar.clone(), length)
+                }
+            })
+            .collect();
+        DataBlock::create(self.schema.clone(), columns)
+    }
+
+    /// Returns a new block with the `column` appended as `field`.
+    /// The column must have the same rows as the block and a unique name.
+    pub fn add_column(&self, field: DataField, column: DataColumnarValue) -> Result<DataBlock> {
+        if self.num_columns() > 0 && column.len() != self.num_rows() {
+            return Result::Err(ErrorCodes::BadDataArrayLength(format!(
+                "Column {} has {} rows, but the block has {} rows",
+                field.name(),
+                column.len(),
+                self.num_rows()
+            )));
+        }
+        if self.schema.index_of(field.name()).is_ok() {
+            return Result::Err(ErrorCodes::BadArguments(format!(
+                "Column {} already exists in the block",
+                field.name()
+            )));
+        }
+
+        let mut fields = self.schema.fields().clone();
+        fields.push(field);
+        let mut columns = self.columns.clone();
+        columns.push(column);
+        Ok(DataBlock::create(DataSchemaRefExt::create(fields), columns))
+    }
 }
 
 impl TryFrom<DataBlock> for RecordBatch {
@@ -25,5 +25,208 @@ fn test_data_block() -> anyhow::Result<()> {
     assert_eq!(true, block.column_by_name("a").is_some());
     assert_eq!(true, block.column_by_name("a_not_found").is_none());
 
+    // The names are matched case-insensitively if no exact match.
+    assert_eq!(3, block.try_column_by_name("A")?.len());
+    assert_eq!(3, block.try_array_by_name("A")?.len());
+    assert_eq!(true, block.column_by_name("A").is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_memory_size() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+
+    use crate::DataBlock;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+
+    let block = DataBlock::create_by_array(schema.clone(), vec![Arc::new(Int64Array::from(
+        (0..1000).collect::<Vec<i64>>(),
+    ))]);
+    let single = block.memory_size();
+    assert!(single >= 1000 * 8);
+
+    // Concat should roughly scale with the row count.
+    let merged = DataBlock::concat_blocks(&[block.clone(), block.clone()])?;
+    assert_eq!(2000, merged.num_rows());
+    assert!(merged.memory_size() >= single * 3 / 2);
+    assert!(merged.memory_size() <= single * 5 / 2);
+
+    // Constant column is near-zero.
+    let constant = DataBlock::create(schema, vec![DataColumnarValue::Constant(
+        DataValue::Int64(Some(1)),
+        1000,
+    )]);
+    assert!(constant.memory_size() < single);
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_project() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+        DataField::new("c", DataType::Boolean, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Arc::new(Int64Array::from(vec![1, 2])),
+        Arc::new(StringArray::from(vec!["x", "y"])),
+        Arc::new(BooleanArray::from(vec![true, false])),
+    ]);
+
+    // Subset.
+    let projected = block.project(&[0, 2])?;
+    assert_eq!(
+        DataSchemaRefExt::create(vec![
+            DataField::new("a", DataType::Int64, false),
+            DataField::new("c", DataType::Boolean, false),
+        ]),
+        projected.schema().clone()
+    );
+    assert_eq!(2, projected.num_rows());
+    let expected = vec![
+        "+---+-------+",
+        "| a | c     |",
+        "+---+-------+",
+        "| 1 | true  |",
+        "| 2 | false |",
+        "+---+-------+",
+    ];
+    assert_blocks_eq(expected, &[projected]);
+
+    // Reorder.
+    let projected = block.project(&[1, 0])?;
+    let expected = vec![
+        "+---+---+",
+        "| b | a |",
+        "+---+---+",
+        "| x | 1 |",
+        "| y | 2 |",
+        "+---+---+",
+    ];
+    assert_blocks_eq(expected, &[projected]);
+
+    // Out of range.
+    let result = block.project(&[0, 3]);
+    assert_eq!(
+        "Code: 6, displayText = Projection index 3 is out of range, block has 3 columns.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_slice() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let block = DataBlock::create(schema.clone(), vec![
+        DataColumnarValue::Array(Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5]))),
+        DataColumnarValue::Constant(DataValue::Utf8(Some("x".to_string())), 5),
+    ]);
+
+    // Middle.
+    let sliced = block.slice(1, 3);
+    assert_eq!(&schema, sliced.schema());
+    assert_eq!(3, sliced.num_rows());
+    assert_eq!(3, sliced.column(1).len());
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 2 | x |",
+        "| 3 | x |",
+        "| 4 | x |",
+        "+---+---+",
+    ];
+    assert_blocks_eq(expected, &[sliced]);
+
+    // Over range length is clamped.
+    let sliced = block.slice(3, 10);
+    assert_eq!(2, sliced.num_rows());
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 4 | x |",
+        "| 5 | x |",
+        "+---+---+",
+    ];
+    assert_blocks_eq(expected, &[sliced]);
+
+    // Over range offset gives an empty block.
+    let sliced = block.slice(7, 2);
+    assert_eq!(0, sliced.num_rows());
+    assert_eq!(2, sliced.num_columns());
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_add_column() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(schema, vec![Arc::new(Int64Array::from(vec![1, 2]))]);
+
+    // Computed column.
+    let added = block.add_column(
+        DataField::new("b", DataType::Int64, false),
+        DataColumnarValue::Array(Arc::new(Int64Array::from(vec![10, 20]))),
+    )?;
+    assert_eq!(2, added.num_columns());
+    assert_eq!(1, block.num_columns());
+    let expected = vec![
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 1 | 10 |",
+        "| 2 | 20 |",
+        "+---+----+",
+    ];
+    assert_blocks_eq(expected, &[added]);
+
+    // Length mismatch.
+    let result = block.add_column(
+        DataField::new("b", DataType::Int64, false),
+        DataColumnarValue::Array(Arc::new(Int64Array::from(vec![10, 20, 30]))),
+    );
+    assert_eq!(
+        "Code: 18, displayText = Column b has 3 rows, but the block has 2 rows.",
+        result.err().unwrap().to_string()
+    );
+
+    // Duplicate name.
+    let result = block.add_column(
+        DataField::new("a", DataType::Int64, false),
+        DataColumnarValue::Array(Arc::new(Int64Array::from(vec![10, 20]))),
+    );
+    assert_eq!(
+        "Code: 6, displayText = Column a already exists in the block.",
+        result.err().unwrap().to_string()
+    );
+
     Ok(())
 }
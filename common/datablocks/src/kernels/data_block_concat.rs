@@ -2,40 +2,157 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use common_arrow::arrow::array::Array;
-use common_arrow::arrow::compute;
+use common_datavalues::wrap_nullable;
+use common_datavalues::DataArrayConcat;
+use common_datavalues::DataArrayRef;
+use common_datavalues::DataColumnarValue;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
 use common_exception::ErrorCodes;
 use common_exception::Result;
 
 use crate::DataBlock;
 
 impl DataBlock {
+    /// Concat the blocks of the same schema into one block.
+    /// The columns may differ in nullability only, the column is nullable in the result
+    /// if it's nullable in any block.
+    /// A block without columns has no rows, so the zero-column blocks are concatenated
+    /// into an empty zero-column block.
     pub fn concat_blocks(blocks: &[DataBlock]) -> Result<DataBlock> {
         if blocks.is_empty() {
             return Result::Err(ErrorCodes::EmptyData("Can't concat empty blocks"));
         }
 
         let first_block = &blocks[0];
-        for block in blocks.iter() {
-            if block.schema().ne(first_block.schema()) {
-                return Result::Err(ErrorCodes::DataStructMissMatch("Schema not matched"));
-            }
+        let schema = Self::concat_schema(blocks)?;
+        if first_block.num_columns() == 0 {
+            return Ok(DataBlock::create(schema, vec![]));
         }
 
         let mut arrays = Vec::with_capacity(first_block.num_columns());
         for (i, _f) in blocks[0].schema().fields().iter().enumerate() {
             let mut arr = Vec::with_capacity(blocks.len());
+            // The constants of the same value and size are built only once.
+            let mut constants: Vec<(&DataValue, usize, DataArrayRef)> = vec![];
             for block in blocks.iter() {
-                arr.push(block.column(i).to_array()?);
+                match block.column(i) {
+                    DataColumnarValue::Constant(value, size) => {
+                        let cached = constants
+                            .iter()
+                            .find(|(v, s, _)| Self::same_constant(v, value) && s == size)
+                            .map(|(_, _, array)| array.clone());
+                        let array = match cached {
+                            Some(array) => array,
+                            None => {
+                                let array = value.to_array_with_size(*size)?;
+                                constants.push((value, *size, array.clone()));
+                                array
+                            }
+                        };
+                        arr.push(array);
+                    }
+                    column => arr.push(column.to_array()?),
+                }
+            }
+            arrays.push(DataArrayConcat::concat(&arr)?);
+        }
+
+        Ok(DataBlock::create_by_array(schema, arrays))
+    }
+
+    // The floats are compared by bits, the derived equality takes -0.0 as 0.0
+    // and the sign would be lost if the array of the other one is reused.
+    fn same_constant(lhs: &DataValue, rhs: &DataValue) -> bool {
+        let same_values = |lhs: &[DataValue], rhs: &[DataValue]| {
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .all(|(l, r)| Self::same_constant(l, r))
+        };
+        match (lhs, rhs) {
+            (DataValue::Float32(l), DataValue::Float32(r)) => {
+                l.map(f32::to_bits) == r.map(f32::to_bits)
+            }
+            (DataValue::Float64(l), DataValue::Float64(r)) => {
+                l.map(f64::to_bits) == r.map(f64::to_bits)
+            }
+            (DataValue::List(l, l_type), DataValue::List(r, r_type)) => {
+                l_type == r_type
+                    && match (l, r) {
+                        (Some(l), Some(r)) => same_values(l, r),
+                        (None, None) => true,
+                        _ => false,
+                    }
             }
+            (DataValue::Struct(l), DataValue::Struct(r)) => same_values(l, r),
+            _ => lhs == rhs,
+        }
+    }
+
+    // The schema of the concatenated blocks, the arrays of a nullable and a non-nullable
+    // column share the representation(the latter has no null bitmap), only the field changes.
+    fn concat_schema(blocks: &[DataBlock]) -> Result<DataSchemaRef> {
+        let first_schema = blocks[0].schema();
+        let mut nullables: Vec<bool> = first_schema
+            .fields()
+            .iter()
+            .map(|f| f.is_nullable())
+            .collect();
 
-            let arr: Vec<&dyn Array> = arr.iter().map(|c| c.as_ref()).collect();
-            arrays.push(compute::concat(&arr)?);
+        for block in blocks.iter() {
+            let schema = block.schema();
+            if schema == first_schema {
+                continue;
+            }
+
+            let fields = schema.fields();
+            if fields.len() != first_schema.fields().len() {
+                return Result::Err(ErrorCodes::DataStructMissMatch(format!(
+                    "Schema not matched, expect {} columns, but got {}",
+                    first_schema.fields().len(),
+                    fields.len()
+                )));
+            }
+            for (i, field) in fields.iter().enumerate() {
+                let first_field = first_schema.field(i);
+                if field.name() != first_field.name()
+                    || field.data_type() != first_field.data_type()
+                {
+                    return Result::Err(ErrorCodes::DataStructMissMatch(format!(
+                        "Schema not matched, expect field {}:{:?}, but got {}:{:?}",
+                        first_field.name(),
+                        first_field.data_type(),
+                        field.name(),
+                        field.data_type()
+                    )));
+                }
+                nullables[i] |= field.is_nullable();
+            }
         }
 
-        Ok(DataBlock::create_by_array(
-            first_block.schema().clone(),
-            arrays,
+        let fields = first_schema.fields();
+        if fields
+            .iter()
+            .zip(nullables.iter())
+            .all(|(f, n)| f.is_nullable() == *n)
+        {
+            return Ok(first_schema.clone());
+        }
+        Ok(DataSchemaRefExt::create(
+            fields
+                .iter()
+                .zip(nullables.iter())
+                .map(|(f, nullable)| {
+                    if *nullable {
+                        wrap_nullable(f)
+                    } else {
+                        f.clone()
+                    }
+                })
+                .collect(),
         ))
     }
 }
@@ -51,3 +51,197 @@ fn test_data_block_concat() -> anyhow::Result<()> {
     crate::assert_blocks_eq(expected, &[results]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_concat_constants() -> anyhow::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![
+            DataColumnarValue::Array(Arc::new(Int64Array::from(vec![1, 2]))),
+            DataColumnarValue::Constant(DataValue::Utf8(Some("x".to_string())), 2),
+        ]),
+        DataBlock::create(schema.clone(), vec![
+            DataColumnarValue::Constant(DataValue::Int64(Some(3)), 2),
+            DataColumnarValue::Constant(DataValue::Utf8(Some("x".to_string())), 2),
+        ]),
+        DataBlock::create(schema.clone(), vec![
+            DataColumnarValue::Constant(DataValue::Int64(Some(3)), 1),
+            DataColumnarValue::Constant(DataValue::Utf8(Some("y".to_string())), 1),
+        ]),
+    ];
+
+    let results = DataBlock::concat_blocks(&blocks)?;
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | x |",
+        "| 2 | x |",
+        "| 3 | x |",
+        "| 3 | x |",
+        "| 3 | y |",
+        "+---+---+",
+    ];
+    crate::assert_blocks_eq(expected, &[results]);
+    Ok(())
+}
+
+#[test]
+fn test_data_block_concat_signed_zero_constants() -> anyhow::Result<()> {
+    use common_arrow::arrow::array::Array;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Float64, false)]);
+
+    // 0.0 == -0.0, the constant array of one must not be reused for the other.
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![DataColumnarValue::Constant(
+            DataValue::Float64(Some(0.0)),
+            1,
+        )]),
+        DataBlock::create(schema.clone(), vec![DataColumnarValue::Constant(
+            DataValue::Float64(Some(-0.0)),
+            1,
+        )]),
+        DataBlock::create(schema.clone(), vec![DataColumnarValue::Constant(
+            DataValue::Float64(Some(0.0)),
+            1,
+        )]),
+    ];
+
+    let results = DataBlock::concat_blocks(&blocks)?;
+    let array = results.try_array_by_name("a")?;
+    let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+    let signs = array
+        .values()
+        .iter()
+        .map(|v| v.is_sign_negative())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![false, true, false], signs);
+    Ok(())
+}
+
+#[test]
+fn test_data_block_concat_zero_columns() -> anyhow::Result<()> {
+    let blocks = vec![DataBlock::empty(), DataBlock::empty()];
+
+    let results = DataBlock::concat_blocks(&blocks)?;
+    assert_eq!(0, results.num_columns());
+    assert_eq!(0, results.num_rows());
+    assert_eq!(blocks[0].schema(), results.schema());
+
+    // Empty blocks list.
+    let results = DataBlock::concat_blocks(&[]);
+    assert_eq!(
+        "Code: 16, displayText = Can't concat empty blocks.",
+        results.unwrap_err().to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_data_block_concat_mixed_nullable() -> anyhow::Result<()> {
+    let non_nullable = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let nullable = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    let blocks = vec![
+        DataBlock::create_by_array(non_nullable.clone(), vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec!["x", "y"])),
+        ]),
+        DataBlock::create_by_array(nullable.clone(), vec![
+            Arc::new(Int32Array::from(vec![Some(3), None])),
+            Arc::new(StringArray::from(vec!["z", "w"])),
+        ]),
+    ];
+
+    let results = DataBlock::concat_blocks(&blocks)?;
+    assert_eq!(&nullable, results.schema());
+    let array = results.column(0).to_array()?;
+    assert_eq!(4, array.len());
+    assert_eq!(1, array.null_count());
+    assert!(array.is_null(3));
+
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | x |",
+        "| 2 | y |",
+        "| 3 | z |",
+        "|   | w |",
+        "+---+---+",
+    ];
+    crate::assert_blocks_eq(expected, &[results]);
+
+    // Non-nullable first or last, the result is nullable either way.
+    let reversed = vec![blocks[1].clone(), blocks[0].clone()];
+    let results = DataBlock::concat_blocks(&reversed)?;
+    assert_eq!(&nullable, results.schema());
+
+    // Different data types are not compatible.
+    let other = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let mismatched = vec![
+        blocks[0].clone(),
+        DataBlock::create_by_array(other, vec![
+            Arc::new(Int64Array::from(vec![Some(3)])),
+            Arc::new(StringArray::from(vec!["z"])),
+        ]),
+    ];
+    let results = DataBlock::concat_blocks(&mismatched);
+    assert_eq!(
+        "Code: 17, displayText = Schema not matched, expect field a:Int32, but got a:Int64.",
+        results.unwrap_err().to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_data_block_concat_schema_mismatch() -> anyhow::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Arc::new(Int64Array::from(vec![1, 2])),
+        Arc::new(StringArray::from(vec!["b1", "b2"])),
+    ]);
+
+    // Different column names.
+    let renamed = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("c", DataType::Utf8, false),
+    ]);
+    let renamed = DataBlock::create_by_array(renamed, vec![
+        Arc::new(Int64Array::from(vec![3])),
+        Arc::new(StringArray::from(vec!["c1"])),
+    ]);
+    let result = DataBlock::concat_blocks(&[block.clone(), renamed]);
+    assert_eq!(
+        "Code: 17, displayText = Schema not matched, expect field b:Utf8, but got c:Utf8.",
+        result.unwrap_err().to_string()
+    );
+
+    // Different column numbers.
+    let narrow = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let narrow = DataBlock::create_by_array(narrow, vec![Arc::new(Int64Array::from(vec![3]))]);
+    let result = DataBlock::concat_blocks(&[block, narrow]);
+    assert_eq!(
+        "Code: 17, displayText = Schema not matched, expect 2 columns, but got 1.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
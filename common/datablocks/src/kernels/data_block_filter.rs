@@ -0,0 +1,47 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_arrow::arrow::compute;
+use common_datavalues::BooleanArray;
+use common_datavalues::DataColumnarValue;
+use common_datavalues::DataType;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+
+use crate::DataBlock;
+
+impl DataBlock {
+    /// Keeps the rows whose predicate is true, the schema is kept.
+    /// The predicate must be a boolean column with the same rows as the block.
+    pub fn filter(&self, predicate: &DataColumnarValue) -> Result<DataBlock> {
+        if predicate.data_type() != DataType::Boolean {
+            return Result::Err(ErrorCodes::BadDataValueType(format!(
+                "The filter predicate must be Boolean, but got {:?}",
+                predicate.data_type()
+            )));
+        }
+        if predicate.len() != self.num_rows() {
+            return Result::Err(ErrorCodes::BadArguments(format!(
+                "The filter predicate must have {} rows, but got {}",
+                self.num_rows(),
+                predicate.len()
+            )));
+        }
+
+        let predicate = predicate.to_array()?;
+        let predicate = common_datavalues::downcast_array!(predicate, BooleanArray)?;
+
+        let columns = self
+            .columns()
+            .iter()
+            .map(|column| {
+                let array = column.to_array()?;
+                let filtered = compute::filter(array.as_ref(), predicate)?;
+                Ok(DataColumnarValue::Array(filtered))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DataBlock::create(self.schema().clone(), columns))
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_datavalues::*;
+
+use crate::*;
+
+#[test]
+fn test_data_block_filter() -> anyhow::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    let raw = DataBlock::create_by_array(schema.clone(), vec![
+        Arc::new(Int64Array::from(vec![1, 2, 3])),
+        Arc::new(StringArray::from(vec!["b1", "b2", "b3"])),
+    ]);
+
+    // All true.
+    let predicate = DataColumnarValue::Array(Arc::new(BooleanArray::from(vec![true, true, true])));
+    let filtered = raw.filter(&predicate)?;
+    assert_eq!(raw.schema(), filtered.schema());
+    let expected = vec![
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 1 | b1 |",
+        "| 2 | b2 |",
+        "| 3 | b3 |",
+        "+---+----+",
+    ];
+    crate::assert_blocks_eq(expected, &[filtered]);
+
+    // All false.
+    let predicate =
+        DataColumnarValue::Array(Arc::new(BooleanArray::from(vec![false, false, false])));
+    let filtered = raw.filter(&predicate)?;
+    assert_eq!(raw.schema(), filtered.schema());
+    assert_eq!(0, filtered.num_rows());
+
+    // Mixed.
+    let predicate = DataColumnarValue::Array(Arc::new(BooleanArray::from(vec![true, false, true])));
+    let filtered = raw.filter(&predicate)?;
+    let expected = vec![
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 1 | b1 |",
+        "| 3 | b3 |",
+        "+---+----+",
+    ];
+    crate::assert_blocks_eq(expected, &[filtered]);
+
+    // Non boolean predicate.
+    let predicate = DataColumnarValue::Array(Arc::new(Int64Array::from(vec![1, 0, 1])));
+    let result = raw.filter(&predicate);
+    assert_eq!(
+        "Code: 10, displayText = The filter predicate must be Boolean, but got Int64.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
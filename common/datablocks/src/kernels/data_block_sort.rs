@@ -2,6 +2,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use common_arrow::arrow::array::build_compare;
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::compute;
 use common_datavalues::DataArrayMerge;
 use common_exception::ErrorCodes;
@@ -15,7 +20,105 @@ pub struct SortColumnDescription {
     pub nulls_first: bool,
 }
 
+/// The row of the top-N heap, the largest row is on the top to be replaced first.
+struct TopRow<'a> {
+    row: usize,
+    compare: &'a dyn Fn(usize, usize) -> Ordering,
+}
+
+impl<'a> Ord for TopRow<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(self.row, other.row)
+    }
+}
+
+impl<'a> PartialOrd for TopRow<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for TopRow<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for TopRow<'a> {}
+
 impl DataBlock {
+    /// Sorts all the rows of the block by the sort columns, the schema is kept.
+    pub fn sort(&self, sort_columns_descriptions: &[SortColumnDescription]) -> Result<DataBlock> {
+        DataBlock::sort_block(self, sort_columns_descriptions, None)
+    }
+
+    /// The first `limit` rows of the sorted block, without sorting all the rows:
+    /// a heap of the top `limit` rows is kept while scanning, O(n log(limit)).
+    /// The rows of the same sort keys are in their original order.
+    pub fn sort_limit(
+        &self,
+        sort_columns_descriptions: &[SortColumnDescription],
+        limit: usize,
+    ) -> Result<DataBlock> {
+        let arrays = sort_columns_descriptions
+            .iter()
+            .map(|f| Ok(self.try_array_by_name(&f.column_name)?.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        let comparators = arrays
+            .iter()
+            .map(|array| build_compare(array.as_ref(), array.as_ref()))
+            .collect::<common_arrow::arrow::error::Result<Vec<_>>>()?;
+
+        let compare = |left: usize, right: usize| -> Ordering {
+            let columns = arrays.iter().zip(comparators.iter());
+            for ((array, comparator), f) in columns.zip(sort_columns_descriptions.iter()) {
+                // The nulls are placed by nulls_first regardless of the direction.
+                let result = match (array.is_valid(left), array.is_valid(right)) {
+                    (true, true) if f.asc => comparator(left, right),
+                    (true, true) => comparator(left, right).reverse(),
+                    (false, true) if f.nulls_first => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (true, false) if f.nulls_first => Ordering::Greater,
+                    (true, false) => Ordering::Less,
+                    (false, false) => Ordering::Equal,
+                };
+                if result != Ordering::Equal {
+                    return result;
+                }
+            }
+            left.cmp(&right)
+        };
+
+        let mut heap = BinaryHeap::with_capacity(limit.min(self.num_rows()));
+        for row in 0..self.num_rows() {
+            if heap.len() < limit {
+                heap.push(TopRow {
+                    row,
+                    compare: &compare,
+                });
+                continue;
+            }
+
+            // Replace the largest row of the heap if the row is smaller.
+            if let Some(top) = heap.peek() {
+                if compare(row, top.row) == Ordering::Less {
+                    heap.pop();
+                    heap.push(TopRow {
+                        row,
+                        compare: &compare,
+                    });
+                }
+            }
+        }
+
+        let indices: Vec<u32> = heap
+            .into_sorted_vec()
+            .iter()
+            .map(|top| top.row as u32)
+            .collect();
+        DataBlock::block_take_by_indices(self, &indices)
+    }
+
     pub fn sort_block(
         block: &DataBlock,
         sort_columns_descriptions: &[SortColumnDescription],
@@ -63,3 +63,151 @@ fn test_data_block_sort() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_data_block_sort_method() -> anyhow::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    let raw = DataBlock::create_by_array(schema.clone(), vec![
+        Arc::new(Int64Array::from(vec![2, 1, 2, 1, 3])),
+        Arc::new(StringArray::from(vec!["x", "y", "z", "w", "v"])),
+    ]);
+
+    {
+        // Single key.
+        let results = raw.sort(&[SortColumnDescription {
+            column_name: "b".to_owned(),
+            asc: false,
+            nulls_first: false,
+        }])?;
+        assert_eq!(raw.schema(), results.schema());
+
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 2 | z |",
+            "| 1 | y |",
+            "| 2 | x |",
+            "| 1 | w |",
+            "| 3 | v |",
+            "+---+---+",
+        ];
+        crate::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        // Two keys with mixed directions.
+        let results = raw.sort(&[
+            SortColumnDescription {
+                column_name: "a".to_owned(),
+                asc: true,
+                nulls_first: false,
+            },
+            SortColumnDescription {
+                column_name: "b".to_owned(),
+                asc: false,
+                nulls_first: false,
+            },
+        ])?;
+        assert_eq!(raw.schema(), results.schema());
+
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | y |",
+            "| 1 | w |",
+            "| 2 | z |",
+            "| 2 | x |",
+            "| 3 | v |",
+            "+---+---+",
+        ];
+        crate::assert_blocks_eq(expected, &[results]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_sort_limit() -> anyhow::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    // The (a, b) keys are unique, so the full sort order is deterministic.
+    let raw = DataBlock::create_by_array(schema.clone(), vec![
+        Arc::new(Int64Array::from(vec![
+            Some(6),
+            None,
+            Some(3),
+            Some(6),
+            Some(1),
+            None,
+            Some(7),
+            Some(3),
+        ])),
+        Arc::new(StringArray::from(vec![
+            "b1", "b2", "b3", "b4", "b5", "b6", "b7", "b8",
+        ])),
+    ]);
+
+    for asc in [true, false].iter() {
+        for nulls_first in [true, false].iter() {
+            let options = vec![
+                SortColumnDescription {
+                    column_name: "a".to_owned(),
+                    asc: *asc,
+                    nulls_first: *nulls_first,
+                },
+                SortColumnDescription {
+                    column_name: "b".to_owned(),
+                    asc: true,
+                    nulls_first: false,
+                },
+            ];
+            let sorted = raw.sort(&options)?;
+
+            for limit in [0, 1, 3, 8, 10].iter() {
+                let result = raw.sort_limit(&options, *limit)?;
+                let expected = sorted.slice(0, *limit);
+                assert_eq!(raw.schema(), result.schema());
+                assert_eq!(expected.num_rows(), result.num_rows());
+                for i in 0..raw.num_columns() {
+                    assert_eq!(
+                        expected.column(i).to_array()?.as_ref(),
+                        result.column(i).to_array()?.as_ref(),
+                        "asc: {}, nulls_first: {}, limit: {}",
+                        asc,
+                        nulls_first,
+                        limit
+                    );
+                }
+            }
+        }
+    }
+
+    // The rows of the same keys are in their original order.
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: false,
+        nulls_first: false,
+    }];
+    let result = raw.sort_limit(&options, 3)?;
+    let expected = vec![
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 7 | b7 |",
+        "| 6 | b1 |",
+        "| 6 | b4 |",
+        "+---+----+",
+    ];
+    crate::assert_blocks_eq(expected, &[result]);
+
+    Ok(())
+}
@@ -5,6 +5,8 @@
 #[cfg(test)]
 mod data_block_concat_test;
 #[cfg(test)]
+mod data_block_filter_test;
+#[cfg(test)]
 mod data_block_groupby_test;
 #[cfg(test)]
 mod data_block_scatter_test;
@@ -14,6 +16,7 @@ mod data_block_sort_test;
 mod data_block_take_test;
 
 mod data_block_concat;
+mod data_block_filter;
 mod data_block_groupby;
 mod data_block_scatter;
 mod data_block_sort;
@@ -0,0 +1,195 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayData;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::BooleanBufferBuilder;
+use common_arrow::arrow::array::FixedSizeListArray;
+use common_arrow::arrow::buffer::Buffer;
+use common_arrow::arrow::compute;
+use common_arrow::arrow::datatypes::DataType;
+use common_arrow::arrow::util::bit_util;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+
+pub struct DataArrayConcat;
+
+impl DataArrayConcat {
+    pub fn concat(arrays: &[ArrayRef]) -> Result<ArrayRef> {
+        if arrays.is_empty() {
+            return Result::Err(ErrorCodes::EmptyData("Can't concat empty arrays"));
+        }
+
+        // The dictionaries are decoded to their values before concatenating, so the result is
+        // not dictionary encoded: merging the dictionaries(remapping the keys) is not supported yet.
+        if let DataType::Dictionary(_, value_type) = arrays[0].data_type() {
+            let decoded = arrays
+                .iter()
+                .map(|array| Self::decode_dictionary(array, value_type))
+                .collect::<Result<Vec<_>>>()?;
+            return Self::concat(&decoded);
+        }
+
+        let data_type = arrays[0].data_type();
+        for array in arrays.iter() {
+            if array.data_type() != data_type {
+                return Result::Err(ErrorCodes::BadDataValueType(format!(
+                    "It is impossible to concat arrays of different data types: {:?} and {:?}",
+                    data_type,
+                    array.data_type()
+                )));
+            }
+        }
+
+        match data_type {
+            DataType::Boolean => Self::concat_boolean(arrays),
+            DataType::FixedSizeList(_, size) => Self::concat_fixed_size_list(arrays, *size),
+            _ => {
+                let arrays: Vec<&dyn Array> = arrays.iter().map(|c| c.as_ref()).collect();
+                Ok(compute::concat(&arrays)?)
+            }
+        }
+    }
+
+    // Unpack the dictionary to its values, the dictionaries of any key type are accepted
+    // if their values are of the value_type.
+    fn decode_dictionary(array: &ArrayRef, value_type: &DataType) -> Result<ArrayRef> {
+        match array.data_type() {
+            DataType::Dictionary(_, array_value_type) if array_value_type.as_ref() == value_type => {
+                Ok(compute::cast(array, value_type)?)
+            }
+            other => Result::Err(ErrorCodes::BadDataValueType(format!(
+                "It is impossible to concat arrays of different data types: Dictionary(_, {:?}) and {:?}",
+                value_type, other
+            ))),
+        }
+    }
+
+    // Concat the values of the lists, and rebuild the validity of the lists.
+    fn concat_fixed_size_list(arrays: &[ArrayRef], size: i32) -> Result<ArrayRef> {
+        let size = size as usize;
+        let len = arrays.iter().map(|array| array.len()).sum();
+
+        let mut values = Vec::with_capacity(arrays.len());
+        let mut validity = BooleanBufferBuilder::new(len);
+        let mut null_count = 0;
+        for array in arrays {
+            let list = array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .ok_or_else(|| {
+                    ErrorCodes::BadDataValueType(format!(
+                        "Cannot downcast {:?} to FixedSizeListArray",
+                        array.data_type()
+                    ))
+                })?;
+
+            let offset = list.value_offset(0) as usize;
+            values.push(list.values().slice(offset, list.len() * size));
+
+            for i in 0..list.len() {
+                validity.append(list.is_valid(i));
+            }
+            null_count += list.null_count();
+        }
+
+        let values: Vec<&dyn Array> = values.iter().map(|c| c.as_ref()).collect();
+        let values = compute::concat(&values)?;
+
+        let mut builder = ArrayData::builder(arrays[0].data_type().clone())
+            .len(len)
+            .add_child_data(values.data().clone());
+        if null_count > 0 {
+            builder = builder.null_bit_buffer(validity.finish());
+        }
+        Ok(Arc::new(FixedSizeListArray::from(builder.build())))
+    }
+
+    // Concat the value bitmaps(and validity bitmaps) byte by byte instead of bit by bit.
+    fn concat_boolean(arrays: &[ArrayRef]) -> Result<ArrayRef> {
+        let len: usize = arrays.iter().map(|array| array.len()).sum();
+        let null_count: usize = arrays.iter().map(|array| array.null_count()).sum();
+
+        let mut values = vec![0u8; bit_util::ceil(len, 8)];
+        let mut validity = vec![0u8; if null_count > 0 { values.len() } else { 0 }];
+        let mut offset = 0;
+        for array in arrays {
+            let array = array
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .ok_or_else(|| {
+                    ErrorCodes::BadDataValueType(format!(
+                        "Cannot downcast {:?} to BooleanArray",
+                        array.data_type()
+                    ))
+                })?;
+
+            let data = array.data();
+            let values_buffer = &data.buffers()[0];
+            Self::append_bits(
+                &mut values,
+                offset,
+                values_buffer.as_slice(),
+                data.offset(),
+                array.len(),
+            );
+
+            if null_count > 0 {
+                match data.null_buffer() {
+                    Some(nulls) => Self::append_bits(
+                        &mut validity,
+                        offset,
+                        nulls.as_slice(),
+                        data.offset(),
+                        array.len(),
+                    ),
+                    None => (offset..offset + array.len())
+                        .for_each(|i| bit_util::set_bit(&mut validity, i)),
+                }
+            }
+            offset += array.len();
+        }
+
+        let mut builder = ArrayData::builder(DataType::Boolean)
+            .len(len)
+            .add_buffer(Buffer::from(values));
+        if null_count > 0 {
+            builder = builder.null_bit_buffer(Buffer::from(validity));
+        }
+        Ok(Arc::new(BooleanArray::from(builder.build())))
+    }
+
+    // Copy the len bits of the src from the src_offset to the zeroed dst at the dst_offset.
+    // The bits are copied one by one until the dst is byte aligned, then a whole byte at a time.
+    fn append_bits(dst: &mut [u8], dst_offset: usize, src: &[u8], src_offset: usize, len: usize) {
+        let mut i = 0;
+        while i < len && (dst_offset + i) % 8 != 0 {
+            if bit_util::get_bit(src, src_offset + i) {
+                bit_util::set_bit(dst, dst_offset + i);
+            }
+            i += 1;
+        }
+
+        let shift = (src_offset + i) % 8;
+        while len - i >= 8 {
+            let src_byte = (src_offset + i) / 8;
+            dst[(dst_offset + i) / 8] = match shift {
+                0 => src[src_byte],
+                _ => (src[src_byte] >> shift) | (src[src_byte + 1] << (8 - shift)),
+            };
+            i += 8;
+        }
+
+        while i < len {
+            if bit_util::get_bit(src, src_offset + i) {
+                bit_util::set_bit(dst, dst_offset + i);
+            }
+            i += 1;
+        }
+    }
+}
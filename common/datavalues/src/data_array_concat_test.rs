@@ -0,0 +1,167 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_array_concat() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use crate::*;
+
+    let a: DataArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+    let b: DataArrayRef = Arc::new(Int64Array::from(vec![3]));
+    let result = DataArrayConcat::concat(&[a, b.clone()])?;
+    let expect: DataArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3]));
+    assert_eq!(result.as_ref(), expect.as_ref());
+
+    // Different data types.
+    let c: DataArrayRef = Arc::new(StringArray::from(vec!["x"]));
+    let result = DataArrayConcat::concat(&[b, c]);
+    assert_eq!(
+        "Code: 10, displayText = It is impossible to concat arrays of different data types: Int64 and Utf8.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_array_concat_fixed_size_list() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::array::Array;
+    use common_arrow::arrow::array::FixedSizeListArray;
+    use common_arrow::arrow::array::FixedSizeListBuilder;
+    use common_arrow::arrow::array::Int32Builder;
+
+    use crate::*;
+
+    fn build_list(values: &[Option<[i32; 2]>], size: i32) -> anyhow::Result<DataArrayRef> {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(16), size);
+        for value in values {
+            match value {
+                Some(v) => {
+                    for _ in 0..size / 2 {
+                        builder.values().append_slice(v)?;
+                    }
+                    builder.append(true)?;
+                }
+                None => {
+                    for _ in 0..size {
+                        builder.values().append_null()?;
+                    }
+                    builder.append(false)?;
+                }
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+
+    let a = build_list(&[Some([1, 2]), None], 2)?;
+    let b = build_list(&[Some([3, 4])], 2)?;
+    let result = DataArrayConcat::concat(&[a, b])?;
+    assert_eq!(3, result.len());
+    assert_eq!(1, result.null_count());
+
+    let list = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(2, list.value_length());
+    assert!(list.is_valid(0));
+    assert!(list.is_null(1));
+    assert!(list.is_valid(2));
+
+    let first = list.value(0);
+    let first = first.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(first.values(), &[1, 2]);
+
+    let last = list.value(2);
+    let last = last.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(last.values(), &[3, 4]);
+
+    // Different fixed sizes.
+    let a = build_list(&[Some([1, 2])], 2)?;
+    let b = build_list(&[Some([1, 2])], 4)?;
+    let result = DataArrayConcat::concat(&[a, b]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_data_array_concat_boolean() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::array::Array;
+    use common_arrow::arrow::compute;
+    use rand::Rng;
+
+    use crate::*;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let mut arrays: Vec<DataArrayRef> = vec![];
+        for _ in 0..rng.gen_range(1..8) {
+            let len = rng.gen_range(0..200);
+            let with_nulls = rng.gen_bool(0.5);
+            let values = (0..len)
+                .map(|_| match with_nulls && rng.gen_bool(0.2) {
+                    true => None,
+                    false => Some(rng.gen_bool(0.5)),
+                })
+                .collect::<Vec<_>>();
+            let array: DataArrayRef = Arc::new(BooleanArray::from(values));
+
+            // Slice to get the unaligned offsets.
+            let offset = rng.gen_range(0..=len);
+            let length = rng.gen_range(0..=len - offset);
+            arrays.push(array.slice(offset, length));
+        }
+
+        let result = DataArrayConcat::concat(&arrays)?;
+        let generic = compute::concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?;
+        assert_eq!(result.as_ref(), generic.as_ref());
+        assert_eq!(result.null_count(), generic.null_count());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_array_concat_dictionary() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::array::DictionaryArray;
+    use common_arrow::arrow::datatypes::Int16Type;
+    use common_arrow::arrow::datatypes::Int8Type;
+
+    use crate::*;
+
+    let a: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+    let b: DictionaryArray<Int16Type> = vec![Some("z"), None, Some("x")].into_iter().collect();
+    let a: DataArrayRef = Arc::new(a);
+    let b: DataArrayRef = Arc::new(b);
+
+    // Decoded to the plain strings, even the key types are different.
+    let result = DataArrayConcat::concat(&[a.clone(), b])?;
+    let expect: DataArrayRef = Arc::new(StringArray::from(vec![
+        Some("x"),
+        Some("y"),
+        Some("x"),
+        Some("z"),
+        None,
+        Some("x"),
+    ]));
+    assert_eq!(result.as_ref(), expect.as_ref());
+
+    // Different value types.
+    let c: DataArrayRef = Arc::new(Int64Array::from(vec![1]));
+    let result = DataArrayConcat::concat(&[a, c]);
+    assert_eq!(
+        "Code: 10, displayText = It is impossible to concat arrays of different data types: Dictionary(_, Utf8) and Int64.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
@@ -2,12 +2,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::ops::Range;
 use std::sync::Arc;
 
 use common_arrow::arrow;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::compute::CastOptions;
 use common_arrow::arrow::datatypes::ArrowPrimitiveType;
+use common_exception::ErrorCodes;
 use common_exception::Result;
 
+use crate::is_numeric;
 use crate::BooleanArray;
 use crate::DataArrayRef;
 use crate::DataType;
@@ -57,6 +62,20 @@ impl DataColumnarValue {
         }
     }
 
+    /// Estimated memory size of the column.
+    /// A constant only holds one value, so it is accounted as a single-row array
+    /// instead of materializing all the rows.
+    #[inline]
+    pub fn memory_size(&self) -> usize {
+        match self {
+            DataColumnarValue::Array(array) => array.get_array_memory_size(),
+            DataColumnarValue::Constant(scalar, _) => scalar
+                .to_array_with_size(1)
+                .map(|arr| arr.get_array_memory_size())
+                .unwrap_or(0),
+        }
+    }
+
     #[inline]
     pub fn get_array_memory_size(&self) -> usize {
         match self {
@@ -90,6 +109,92 @@ impl DataColumnarValue {
             }
         }
     }
+
+    /// Slice the rows of the range, the array column shares the buffers with the slice.
+    pub fn try_slice(&self, range: Range<usize>) -> Result<DataColumnarValue> {
+        if range.start > range.end || range.end > self.len() {
+            return Result::Err(ErrorCodes::BadArguments(format!(
+                "Slice range {}..{} is out of range, column has {} rows",
+                range.start,
+                range.end,
+                self.len()
+            )));
+        }
+
+        let length = range.end - range.start;
+        match self {
+            DataColumnarValue::Array(array) => {
+                Ok(DataColumnarValue::Array(array.slice(range.start, length)))
+            }
+            DataColumnarValue::Constant(scalar, _) => {
+                Ok(DataColumnarValue::Constant(scalar.clone(), length))
+            }
+        }
+    }
+
+    /// Cast the column to the data type, errors if any value can't be cast,
+    /// e.g. a narrowing overflow(NumericOverflow) or an unparsable string.
+    pub fn cast_to(&self, data_type: &DataType) -> Result<DataColumnarValue> {
+        let (array, casted) = self.cast_array(data_type)?;
+        if casted.null_count() > array.null_count() {
+            for i in 0..array.len() {
+                if array.is_valid(i) && casted.is_null(i) {
+                    let message = format!(
+                        "Cannot cast value {} of type {:?} to {:?}",
+                        DataValue::try_from_array(&array, i)?,
+                        array.data_type(),
+                        data_type
+                    );
+                    // A numeric value can only fail by being out of the range.
+                    if is_numeric(array.data_type()) && is_numeric(data_type) {
+                        return Result::Err(ErrorCodes::NumericOverflow(message));
+                    }
+                    return Result::Err(ErrorCodes::BadDataValueType(message));
+                }
+            }
+        }
+        self.with_casted(casted)
+    }
+
+    /// Cast the column to the data type, the values that can't be cast are NULL.
+    pub fn try_cast_to(&self, data_type: &DataType) -> Result<DataColumnarValue> {
+        let (_, casted) = self.cast_array(data_type)?;
+        self.with_casted(casted)
+    }
+
+    // A constant is cast as a single-row array.
+    fn cast_array(&self, data_type: &DataType) -> Result<(DataArrayRef, DataArrayRef)> {
+        let array = match self {
+            DataColumnarValue::Array(array) => array.clone(),
+            DataColumnarValue::Constant(scalar, _) => scalar.to_array_with_size(1)?,
+        };
+        let options = CastOptions { safe: true };
+        let casted = arrow::compute::cast_with_options(&array, data_type, &options)?;
+        Ok((array, casted))
+    }
+
+    fn with_casted(&self, casted: DataArrayRef) -> Result<DataColumnarValue> {
+        match self {
+            DataColumnarValue::Array(_) => Ok(DataColumnarValue::Array(casted)),
+            DataColumnarValue::Constant(_, size) => Ok(DataColumnarValue::Constant(
+                DataValue::try_from_array(&casted, 0)?,
+                *size,
+            )),
+        }
+    }
+
+    /// Resize to a constant column with the size, an array column takes its first value as the constant.
+    pub fn resize_constant(&self, size: usize) -> Result<DataColumnarValue> {
+        match self {
+            DataColumnarValue::Array(array) => Ok(DataColumnarValue::Constant(
+                DataValue::try_from_array(array, 0)?,
+                size,
+            )),
+            DataColumnarValue::Constant(scalar, _) => {
+                Ok(DataColumnarValue::Constant(scalar.clone(), size))
+            }
+        }
+    }
 }
 
 impl From<DataArrayRef> for DataColumnarValue {
@@ -0,0 +1,93 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_columnar_value_try_slice() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use crate::*;
+
+    let array: DataArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5]));
+    let column = DataColumnarValue::Array(array);
+
+    // Valid slice.
+    let sliced = column.try_slice(1..4)?;
+    let expect: DataArrayRef = Arc::new(Int64Array::from(vec![2, 3, 4]));
+    assert_eq!(sliced.to_array()?.as_ref(), expect.as_ref());
+
+    // Empty slice.
+    let sliced = column.try_slice(5..5)?;
+    assert!(sliced.is_empty());
+    assert_eq!(sliced.data_type(), DataType::Int64);
+
+    // Constant.
+    let constant = DataColumnarValue::Constant(DataValue::Int64(Some(7)), 5);
+    let sliced = constant.try_slice(2..5)?;
+    assert_eq!(sliced.len(), 3);
+    let expect: DataArrayRef = Arc::new(Int64Array::from(vec![7, 7, 7]));
+    assert_eq!(sliced.to_array()?.as_ref(), expect.as_ref());
+
+    // Out of range slice.
+    let result = column.try_slice(3..6);
+    assert_eq!(
+        "Code: 6, displayText = Slice range 3..6 is out of range, column has 5 rows.",
+        result.unwrap_err().to_string()
+    );
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let result = constant.try_slice(4..2);
+    assert_eq!(
+        "Code: 6, displayText = Slice range 4..2 is out of range, column has 5 rows.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_columnar_value_cast_to() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use crate::*;
+
+    // Widening.
+    let column = DataColumnarValue::Array(Arc::new(Int8Array::from(vec![1, -2, 127])));
+    let casted = column.cast_to(&DataType::Int64)?;
+    let expect: DataArrayRef = Arc::new(Int64Array::from(vec![1, -2, 127]));
+    assert_eq!(casted.to_array()?.as_ref(), expect.as_ref());
+
+    // Narrowing overflow.
+    let column =
+        DataColumnarValue::Array(Arc::new(Int64Array::from(vec![Some(1), None, Some(300)])));
+    let result = column.cast_to(&DataType::Int8);
+    assert_eq!(
+        "Code: 43, displayText = Cannot cast value 300 of type Int64 to Int8.",
+        result.unwrap_err().to_string()
+    );
+
+    // Try cast produces NULL instead.
+    let casted = column.try_cast_to(&DataType::Int8)?;
+    let expect: DataArrayRef = Arc::new(Int8Array::from(vec![Some(1), None, None]));
+    assert_eq!(casted.to_array()?.as_ref(), expect.as_ref());
+
+    // Numeric and string.
+    let column = DataColumnarValue::Array(Arc::new(StringArray::from(vec!["12", "x"])));
+    let result = column.cast_to(&DataType::UInt32);
+    assert_eq!(
+        "Code: 10, displayText = Cannot cast value x of type Utf8 to UInt32.",
+        result.unwrap_err().to_string()
+    );
+    let casted = column.try_cast_to(&DataType::UInt32)?;
+    let expect: DataArrayRef = Arc::new(UInt32Array::from(vec![Some(12), None]));
+    assert_eq!(casted.to_array()?.as_ref(), expect.as_ref());
+
+    // Constant stays constant.
+    let constant = DataColumnarValue::Constant(DataValue::Int32(Some(7)), 3);
+    let casted = constant.cast_to(&DataType::Utf8)?;
+    assert_eq!(casted.len(), 3);
+    let expect: DataArrayRef = Arc::new(StringArray::from(vec!["7", "7", "7"]));
+    assert_eq!(casted.to_array()?.as_ref(), expect.as_ref());
+
+    Ok(())
+}
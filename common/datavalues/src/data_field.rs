@@ -5,3 +5,19 @@
 use common_arrow::arrow;
 
 pub type DataField = arrow::datatypes::Field;
+
+/// The nullable version of the field, the field is returned as is if it's already nullable.
+pub fn wrap_nullable(field: &DataField) -> DataField {
+    if field.is_nullable() {
+        return field.clone();
+    }
+    DataField::new(field.name(), field.data_type().clone(), true)
+}
+
+/// The non-nullable version of the field, the field is returned as is if it's not nullable.
+pub fn remove_nullable(field: &DataField) -> DataField {
+    if !field.is_nullable() {
+        return field.clone();
+    }
+    DataField::new(field.name(), field.data_type().clone(), false)
+}
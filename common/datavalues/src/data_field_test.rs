@@ -0,0 +1,23 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_field_nullable() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let field = DataField::new("a", DataType::Int64, false);
+
+    let wrapped = wrap_nullable(&field);
+    assert_eq!(DataField::new("a", DataType::Int64, true), wrapped);
+    assert_eq!(wrapped, wrap_nullable(&wrapped));
+
+    let removed = remove_nullable(&wrapped);
+    assert_eq!(field, removed);
+    assert_eq!(removed, remove_nullable(&removed));
+    assert_eq!(field, remove_nullable(&field));
+
+    Ok(())
+}
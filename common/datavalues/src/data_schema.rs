@@ -2,18 +2,129 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow;
+use common_exception::ErrorCodes;
+use common_exception::Result;
 
 use crate::DataField;
 
 pub type DataSchema = arrow::datatypes::Schema;
 pub type DataSchemaRef = arrow::datatypes::SchemaRef;
 
+/// How DataSchemaRefExt::try_merge resolves the column names shared by the schemas.
+#[derive(Clone, Copy, Debug)]
+pub enum SchemaMergePolicy<'a> {
+    /// Fail on the duplicate names.
+    Error,
+    /// Rename the duplicate columns to qualifier.name, with one qualifier per schema.
+    Qualify(&'a [&'a str]),
+}
+
 pub struct DataSchemaRefExt;
 impl DataSchemaRefExt {
     pub fn create(fields: Vec<DataField>) -> DataSchemaRef {
         Arc::new(DataSchema::new(fields))
     }
+
+    /// Concatenate the fields of the schemas in order, e.g. the output schema of a join.
+    pub fn try_merge(
+        schemas: &[DataSchemaRef],
+        policy: SchemaMergePolicy,
+    ) -> Result<DataSchemaRef> {
+        if let SchemaMergePolicy::Qualify(qualifiers) = policy {
+            if qualifiers.len() != schemas.len() {
+                return Result::Err(ErrorCodes::BadArguments(format!(
+                    "Schema merge expects {} qualifiers, but got {}",
+                    schemas.len(),
+                    qualifiers.len()
+                )));
+            }
+        }
+
+        let mut occurrences: HashMap<&str, usize> = HashMap::new();
+        for schema in schemas {
+            for field in schema.fields() {
+                *occurrences.entry(field.name().as_str()).or_insert(0) += 1;
+            }
+        }
+
+        let mut fields = vec![];
+        for (i, schema) in schemas.iter().enumerate() {
+            for field in schema.fields() {
+                if occurrences[field.name().as_str()] == 1 {
+                    fields.push(field.clone());
+                    continue;
+                }
+
+                match policy {
+                    SchemaMergePolicy::Error => {
+                        return Result::Err(ErrorCodes::BadArguments(format!(
+                            "Duplicate column name '{}' in the merged schema",
+                            field.name()
+                        )));
+                    }
+                    SchemaMergePolicy::Qualify(qualifiers) => fields.push(DataField::new(
+                        &format!("{}.{}", qualifiers[i], field.name()),
+                        field.data_type().clone(),
+                        field.is_nullable(),
+                    )),
+                }
+            }
+        }
+
+        // The qualified names may still collide, e.g. with the same qualifier.
+        let merged = DataSchema::new(fields);
+        for (i, field) in merged.fields().iter().enumerate() {
+            if merged.fields()[..i]
+                .iter()
+                .any(|f| f.name() == field.name())
+            {
+                return Result::Err(ErrorCodes::BadArguments(format!(
+                    "Duplicate column name '{}' in the merged schema",
+                    field.name()
+                )));
+            }
+        }
+        Ok(Arc::new(merged))
+    }
+}
+
+/// Find the field by the exact name first, then fallback to the unique case-insensitive match.
+pub fn field_with_name_ignore_case<'a>(
+    schema: &'a DataSchema,
+    name: &str,
+) -> Result<&'a DataField> {
+    let index = index_of_ignore_case(schema, name)?;
+    Ok(schema.field(index))
+}
+
+/// The index of the field found as `field_with_name_ignore_case`.
+pub fn index_of_ignore_case(schema: &DataSchema, name: &str) -> Result<usize> {
+    let err = match schema.index_of(name) {
+        Ok(index) => return Ok(index),
+        Err(e) => e,
+    };
+
+    let candidates: Vec<usize> = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.name().eq_ignore_ascii_case(name))
+        .map(|(index, _)| index)
+        .collect();
+    match candidates.len() {
+        0 => Result::Err(err.into()),
+        1 => Ok(candidates[0]),
+        _ => Result::Err(ErrorCodes::BadArguments(format!(
+            "Ambiguous column name: '{}', candidates: {:?}",
+            name,
+            candidates
+                .iter()
+                .map(|index| schema.field(*index).name())
+                .collect::<Vec<_>>()
+        ))),
+    }
 }
@@ -0,0 +1,112 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_field_with_name_ignore_case() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("col", DataType::UInt64, false),
+        DataField::new("Name", DataType::Utf8, false),
+        DataField::new("name", DataType::Utf8, true),
+    ]);
+
+    // Exact match.
+    let field = field_with_name_ignore_case(&schema, "col")?;
+    assert_eq!("col", field.name());
+    let field = field_with_name_ignore_case(&schema, "name")?;
+    assert_eq!(true, field.is_nullable());
+
+    // Case-variant match.
+    let field = field_with_name_ignore_case(&schema, "COL")?;
+    assert_eq!("col", field.name());
+    assert_eq!(0, index_of_ignore_case(&schema, "Col")?);
+
+    // Ambiguous match.
+    let result = field_with_name_ignore_case(&schema, "NAME");
+    assert_eq!(true, result.is_err());
+    assert_eq!(
+        "Code: 6, displayText = Ambiguous column name: 'NAME', candidates: [\"Name\", \"name\"].",
+        format!("{}", result.unwrap_err())
+    );
+
+    // Absent.
+    let result = field_with_name_ignore_case(&schema, "xx");
+    assert_eq!(true, result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_data_schema_try_merge() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let left = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("name", DataType::Utf8, true),
+    ]);
+    let right = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("price", DataType::Float64, false),
+    ]);
+    let other = DataSchemaRefExt::create(vec![DataField::new("age", DataType::UInt8, false)]);
+
+    // Non-overlapping.
+    let merged =
+        DataSchemaRefExt::try_merge(&[left.clone(), other.clone()], SchemaMergePolicy::Error)?;
+    assert_eq!(
+        DataSchemaRefExt::create(vec![
+            DataField::new("id", DataType::UInt64, false),
+            DataField::new("name", DataType::Utf8, true),
+            DataField::new("age", DataType::UInt8, false),
+        ]),
+        merged
+    );
+
+    // Colliding with the error policy.
+    let result =
+        DataSchemaRefExt::try_merge(&[left.clone(), right.clone()], SchemaMergePolicy::Error);
+    assert_eq!(
+        "Code: 6, displayText = Duplicate column name 'id' in the merged schema.",
+        result.unwrap_err().to_string()
+    );
+
+    // Colliding with the qualify policy, only the duplicates are qualified.
+    let merged = DataSchemaRefExt::try_merge(
+        &[left.clone(), right.clone()],
+        SchemaMergePolicy::Qualify(&["t1", "t2"]),
+    )?;
+    assert_eq!(
+        DataSchemaRefExt::create(vec![
+            DataField::new("t1.id", DataType::UInt64, false),
+            DataField::new("name", DataType::Utf8, true),
+            DataField::new("t2.id", DataType::UInt64, false),
+            DataField::new("price", DataType::Float64, false),
+        ]),
+        merged
+    );
+
+    // The same qualifier still collides.
+    let result = DataSchemaRefExt::try_merge(
+        &[left.clone(), right.clone()],
+        SchemaMergePolicy::Qualify(&["t", "t"]),
+    );
+    assert_eq!(
+        "Code: 6, displayText = Duplicate column name 't.id' in the merged schema.",
+        result.unwrap_err().to_string()
+    );
+
+    // Qualifiers mismatch.
+    let result = DataSchemaRefExt::try_merge(&[left, right], SchemaMergePolicy::Qualify(&["t"]));
+    assert_eq!(
+        "Code: 6, displayText = Schema merge expects 2 qualifiers, but got 1.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
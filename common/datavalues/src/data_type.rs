@@ -284,3 +284,90 @@ pub fn equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataTy
 
     numerical_coercion(lhs_type, rhs_type).or_else(|_| dictionary_coercion(lhs_type, rhs_type))
 }
+
+/// The least common type(supertype) of all the types, used by the multi-branch expressions.
+/// Null is the subtype of all the types, numeric types are widened and strings are widened to LargeUtf8.
+pub fn common_type(types: &[DataType]) -> Result<DataType> {
+    if types.is_empty() {
+        return Result::Err(ErrorCodes::BadArguments(
+            "Can not find the common type of empty types",
+        ));
+    }
+
+    let mut common = DataType::Null;
+    for data_type in types {
+        let next = match (&common, data_type) {
+            (DataType::Null, _) => data_type.clone(),
+            (_, DataType::Null) => common.clone(),
+            (lhs, rhs) if lhs == rhs => common.clone(),
+            (lhs, rhs) if is_numeric(lhs) && is_numeric(rhs) => numerical_coercion(lhs, rhs)?,
+            (lhs, rhs) => string_coercion(lhs, rhs)
+                .or_else(|_| dictionary_coercion(lhs, rhs))
+                .map_err(|_| {
+                    ErrorCodes::IllegalDataType(format!(
+                        "Can not find the common type of {:?} and {:?}",
+                        lhs, rhs
+                    ))
+                })?,
+        };
+        common = next;
+    }
+    Ok(common)
+}
+
+/// Determine if a DataType is temporal(date/time/timestamp/interval) or not
+pub fn is_temporal(dt: &DataType) -> bool {
+    matches!(
+        dt,
+        DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_)
+            | DataType::Interval(_)
+    )
+}
+
+/// The type name shown in the system tables, e.g. UInt16, List(Utf8).
+pub fn data_type_display_name(dt: &DataType) -> String {
+    match dt {
+        DataType::Timestamp(unit, None) => format!("Timestamp({:?})", unit),
+        DataType::Timestamp(unit, Some(tz)) => format!("Timestamp({:?}, {})", unit, tz),
+        DataType::List(field) => format!("List({})", data_type_display_name(field.data_type())),
+        DataType::LargeList(field) => {
+            format!("LargeList({})", data_type_display_name(field.data_type()))
+        }
+        DataType::FixedSizeList(field, size) => format!(
+            "FixedSizeList({}, {})",
+            data_type_display_name(field.data_type()),
+            size
+        ),
+        DataType::Struct(fields) => format!(
+            "Struct({})",
+            fields
+                .iter()
+                .map(|f| format!("{} {}", f.name(), data_type_display_name(f.data_type())))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        DataType::Dictionary(key, value) => format!(
+            "Dictionary({}, {})",
+            data_type_display_name(key),
+            data_type_display_name(value)
+        ),
+        _ => format!("{:?}", dt),
+    }
+}
+
+/// The category of the type: numeric, string, temporal, boolean, complex or null.
+pub fn data_type_category(dt: &DataType) -> &'static str {
+    match dt {
+        DataType::Null => "null",
+        DataType::Boolean => "boolean",
+        DataType::Utf8 | DataType::LargeUtf8 => "string",
+        _ if is_numeric(dt) => "numeric",
+        _ if is_temporal(dt) => "temporal",
+        _ => "complex",
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_type_common_type() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    struct Test {
+        name: &'static str,
+        types: Vec<DataType>,
+        expect: DataType,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "same-type-passed",
+            types: vec![DataType::Int32, DataType::Int32],
+            expect: DataType::Int32,
+            error: "",
+        },
+        Test {
+            name: "signed-widening-passed",
+            types: vec![
+                DataType::Int8,
+                DataType::Int16,
+                DataType::Int32,
+                DataType::Int64,
+            ],
+            expect: DataType::Int64,
+            error: "",
+        },
+        Test {
+            name: "unsigned-widening-passed",
+            types: vec![DataType::UInt8, DataType::UInt16, DataType::UInt32],
+            expect: DataType::UInt32,
+            error: "",
+        },
+        Test {
+            name: "signed-unsigned-widening-passed",
+            types: vec![DataType::Int8, DataType::UInt8],
+            expect: DataType::Int16,
+            error: "",
+        },
+        Test {
+            name: "float-widening-passed",
+            types: vec![DataType::Int32, DataType::Float32],
+            expect: DataType::Float64,
+            error: "",
+        },
+        Test {
+            name: "string-passed",
+            types: vec![DataType::Utf8, DataType::LargeUtf8],
+            expect: DataType::LargeUtf8,
+            error: "",
+        },
+        Test {
+            name: "null-passed",
+            types: vec![DataType::Null, DataType::Int8, DataType::Null],
+            expect: DataType::Int8,
+            error: "",
+        },
+        Test {
+            name: "all-null-passed",
+            types: vec![DataType::Null, DataType::Null],
+            expect: DataType::Null,
+            error: "",
+        },
+        Test {
+            name: "incompatible-failed",
+            types: vec![DataType::Int32, DataType::Utf8],
+            expect: DataType::Null,
+            error: "Code: 7, displayText = Can not find the common type of Int32 and Utf8.",
+        },
+        Test {
+            name: "empty-failed",
+            types: vec![],
+            expect: DataType::Null,
+            error: "Code: 6, displayText = Can not find the common type of empty types.",
+        },
+    ];
+
+    for t in tests {
+        match common_type(&t.types) {
+            Ok(v) => assert_eq!(t.expect, v, "{}", t.name),
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_data_type_display_name_and_category() -> anyhow::Result<()> {
+    use common_arrow::arrow::datatypes::TimeUnit;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let tests = vec![
+        (DataType::UInt16, "UInt16", "numeric"),
+        (DataType::Utf8, "Utf8", "string"),
+        (DataType::Float64, "Float64", "numeric"),
+        (DataType::Boolean, "Boolean", "boolean"),
+        (DataType::Null, "Null", "null"),
+        (DataType::Date32, "Date32", "temporal"),
+        (
+            DataType::Timestamp(TimeUnit::Second, None),
+            "Timestamp(Second)",
+            "temporal",
+        ),
+        (
+            DataType::List(Box::new(DataField::new("item", DataType::Utf8, true))),
+            "List(Utf8)",
+            "complex",
+        ),
+        (
+            DataType::Struct(vec![
+                DataField::new("a", DataType::Int32, false),
+                DataField::new("b", DataType::Utf8, false),
+            ]),
+            "Struct(a Int32, b Utf8)",
+            "complex",
+        ),
+    ];
+
+    for (data_type, name, category) in tests {
+        assert_eq!(name, data_type_display_name(&data_type));
+        assert_eq!(category, data_type_category(&data_type), "{}", name);
+    }
+    Ok(())
+}
@@ -97,9 +97,14 @@ impl DataValue {
                 | DataValue::Float64(None)
                 | DataValue::Binary(None)
                 | DataValue::Utf8(None)
+                | DataValue::Date32(None)
+                | DataValue::Date64(None)
+                | DataValue::TimestampSecond(None)
                 | DataValue::TimestampMillisecond(None)
                 | DataValue::TimestampMicrosecond(None)
                 | DataValue::TimestampNanosecond(None)
+                | DataValue::IntervalYearMonth(None)
+                | DataValue::IntervalDayTime(None)
                 | DataValue::List(None, _)
         )
     }
@@ -299,6 +304,46 @@ typed_cast_from_data_value_to_std!(Float32, f32);
 typed_cast_from_data_value_to_std!(Float64, f64);
 typed_cast_from_data_value_to_std!(Boolean, bool);
 
+// The value keeps the width of the Rust type, e.g. 3i64 is Int64 and 3u8 is UInt8.
+macro_rules! std_to_data_value {
+    ($NATIVE:ty, $SCALAR:ident) => {
+        impl From<$NATIVE> for DataValue {
+            fn from(v: $NATIVE) -> Self {
+                DataValue::$SCALAR(Some(v))
+            }
+        }
+    };
+}
+
+std_to_data_value!(i8, Int8);
+std_to_data_value!(i16, Int16);
+std_to_data_value!(i32, Int32);
+std_to_data_value!(i64, Int64);
+std_to_data_value!(u8, UInt8);
+std_to_data_value!(u16, UInt16);
+std_to_data_value!(u32, UInt32);
+std_to_data_value!(u64, UInt64);
+std_to_data_value!(f32, Float32);
+std_to_data_value!(f64, Float64);
+std_to_data_value!(bool, Boolean);
+std_to_data_value!(String, Utf8);
+
+impl From<&str> for DataValue {
+    fn from(v: &str) -> Self {
+        DataValue::Utf8(Some(v.to_string()))
+    }
+}
+
+// None has no type, it's the untyped Null.
+impl<T: Into<DataValue>> From<Option<T>> for DataValue {
+    fn from(v: Option<T>) -> Self {
+        match v {
+            Some(v) => v.into(),
+            None => DataValue::Null,
+        }
+    }
+}
+
 impl TryFrom<&DataType> for DataValue {
     type Error = ErrorCodes;
 
@@ -326,6 +371,10 @@ impl TryFrom<&DataType> for DataValue {
             DataType::Timestamp(TimeUnit::Nanosecond, _) => {
                 Ok(DataValue::TimestampNanosecond(None))
             }
+            DataType::Date32 => Ok(DataValue::Date32(None)),
+            DataType::Date64 => Ok(DataValue::Date64(None)),
+            DataType::Interval(IntervalUnit::YearMonth) => Ok(DataValue::IntervalYearMonth(None)),
+            DataType::Interval(IntervalUnit::DayTime) => Ok(DataValue::IntervalDayTime(None)),
             _ => Result::Err(ErrorCodes::BadDataValueType(format!(
                 "DataValue Error: Unsupported try_from() for data type: {:?}",
                 data_type
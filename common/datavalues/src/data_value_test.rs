@@ -0,0 +1,42 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_value_from_std() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    // The width of the Rust type is kept.
+    assert_eq!(DataValue::Int64(Some(3)), DataValue::from(3i64));
+    assert_eq!(DataValue::Int32(Some(3)), DataValue::from(3i32));
+    assert_eq!(DataValue::Int8(Some(-3)), DataValue::from(-3i8));
+    assert_eq!(DataValue::UInt8(Some(3)), DataValue::from(3u8));
+    assert_eq!(DataValue::UInt64(Some(3)), DataValue::from(3u64));
+    assert_eq!(DataValue::Float32(Some(1.5)), DataValue::from(1.5f32));
+    assert_eq!(DataValue::Float64(Some(1.5)), DataValue::from(1.5f64));
+    assert_eq!(DataValue::Boolean(Some(true)), DataValue::from(true));
+
+    // Strings.
+    assert_eq!(DataValue::Utf8(Some("x".to_string())), DataValue::from("x"));
+    assert_eq!(
+        DataValue::Utf8(Some("x".to_string())),
+        DataValue::from("x".to_string())
+    );
+
+    // Option.
+    assert_eq!(DataValue::Int64(Some(3)), DataValue::from(Some(3i64)));
+    assert_eq!(
+        DataValue::Utf8(Some("x".to_string())),
+        DataValue::from(Some("x"))
+    );
+    assert_eq!(DataValue::Null, DataValue::from(None::<i64>));
+    assert_eq!(DataValue::Null, DataValue::from(None::<&str>));
+
+    // Into.
+    let value: DataValue = 3u16.into();
+    assert_eq!(DataType::UInt16, value.data_type());
+
+    Ok(())
+}
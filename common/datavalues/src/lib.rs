@@ -9,15 +9,27 @@ mod data_array_arithmetic_test;
 #[cfg(test)]
 mod data_array_comparison_test;
 #[cfg(test)]
+mod data_array_concat_test;
+#[cfg(test)]
 mod data_array_logic_test;
 #[cfg(test)]
 mod data_array_merge_sort_test;
 #[cfg(test)]
+mod data_columnar_value_test;
+#[cfg(test)]
+mod data_field_test;
+#[cfg(test)]
+mod data_schema_test;
+#[cfg(test)]
+mod data_type_test;
+#[cfg(test)]
 mod data_value_aggregate_test;
 #[cfg(test)]
 mod data_value_arithmetic_test;
 #[cfg(test)]
 mod data_value_kernel_test;
+#[cfg(test)]
+mod data_value_test;
 
 #[cfg(test)]
 mod data_array_scatter_test;
@@ -29,6 +41,7 @@ mod data_array;
 mod data_array_aggregate;
 mod data_array_arithmetic;
 mod data_array_comparison;
+mod data_array_concat;
 mod data_array_hash;
 mod data_array_logic;
 mod data_array_merge_sort;
@@ -47,16 +60,22 @@ pub use data_array::*;
 pub use data_array_aggregate::DataArrayAggregate;
 pub use data_array_arithmetic::DataArrayArithmetic;
 pub use data_array_comparison::DataArrayComparison;
+pub use data_array_concat::DataArrayConcat;
 pub use data_array_hash::DataArrayHashDispatcher;
 pub use data_array_hash::FuseDataHasher;
 pub use data_array_logic::DataArrayLogic;
 pub use data_array_merge_sort::DataArrayMerge;
 pub use data_array_scatter::DataArrayScatter;
 pub use data_columnar_value::DataColumnarValue;
+pub use data_field::remove_nullable;
+pub use data_field::wrap_nullable;
 pub use data_field::DataField;
+pub use data_schema::field_with_name_ignore_case;
+pub use data_schema::index_of_ignore_case;
 pub use data_schema::DataSchema;
 pub use data_schema::DataSchemaRef;
 pub use data_schema::DataSchemaRefExt;
+pub use data_schema::SchemaMergePolicy;
 pub use data_type::DataType;
 pub use data_type::*;
 pub use data_value::DataValue;
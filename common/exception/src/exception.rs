@@ -49,6 +49,15 @@ impl ErrorCodes {
             .unwrap_or_else(|| self.display_text.clone())
     }
 
+    /// The error this one is converted from(by `From<anyhow::Error>`), if it is of type `E`.
+    /// The callers can classify the errors by their causes, e.g. the status codes of the RPCs.
+    pub fn downcast_cause_ref<E>(&self) -> Option<&E>
+    where E: Display + Debug + Send + Sync + 'static {
+        match self.cause.as_ref()?.downcast_ref::<OtherErrors>()? {
+            OtherErrors::AnyHow { error } => error.downcast_ref::<E>(),
+        }
+    }
+
     pub fn backtrace(&self) -> Option<ErrorCodesBacktrace> {
         self.backtrace.clone()
     }
@@ -126,6 +135,11 @@ build_exceptions! {
     DnsParseError(37),
     CannotConnectNode(38),
     DuplicateGetStream(39),
+    UnknownTableEngine(40),
+    UnknownDatabaseEngine(41),
+    OverflowedExpression(42),
+    NumericOverflow(43),
+    DuplicateTableFunction(44),
 
     UnknownException(1000),
     TokioError(1001)
@@ -24,3 +24,47 @@ fn test_format_with_error_codes() {
         "Code: 1000, displayText = test message 2."
     );
 }
+
+#[test]
+fn test_error_codes_stable() {
+    use crate::exception::*;
+
+    // The clients classify the errors by the codes, they must not change.
+    assert_eq!(40, ErrorCodes::UnknownTableEngine("").code());
+    assert_eq!(41, ErrorCodes::UnknownDatabaseEngine("").code());
+    assert_eq!(42, ErrorCodes::OverflowedExpression("").code());
+    assert_eq!(43, ErrorCodes::NumericOverflow("").code());
+    assert_eq!(44, ErrorCodes::DuplicateTableFunction("").code());
+    assert_eq!(
+        "Code: 43, displayText = 300 overflows Int8.",
+        format!("{}", ErrorCodes::NumericOverflow("300 overflows Int8"))
+    );
+}
+
+#[test]
+fn test_error_codes_downcast_cause() {
+    use std::fmt;
+
+    use crate::exception::*;
+
+    #[derive(Debug)]
+    struct RpcError {
+        code: i32,
+    }
+
+    impl fmt::Display for RpcError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "rpc error {}", self.code)
+        }
+    }
+
+    impl std::error::Error for RpcError {}
+
+    let error = ErrorCodes::from(anyhow::Error::new(RpcError { code: 14 }));
+    assert_eq!(14, error.downcast_cause_ref::<RpcError>().unwrap().code);
+    assert!(error.downcast_cause_ref::<std::io::Error>().is_none());
+
+    // No cause.
+    let error = ErrorCodes::UnknownException("rpc error 14");
+    assert!(error.downcast_cause_ref::<RpcError>().is_none());
+}
@@ -2,7 +2,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::fmt;
+
 use common_arrow::arrow_flight;
+use tonic::Code;
 use tonic::Status;
 
 pub fn flight_result_to_str(r: &arrow_flight::Result) -> String {
@@ -12,10 +15,36 @@ pub fn flight_result_to_str(r: &arrow_flight::Result) -> String {
     }
 }
 
+/// The error status returned by the flight server, the code is kept for the callers
+/// to classify the failures, e.g. to retry the unavailable ones.
+#[derive(Debug, Clone)]
+pub struct StatusError {
+    code: Code,
+    message: String,
+}
+
+impl StatusError {
+    pub fn code(&self) -> Code {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status: {}: {}", self.code.description(), self.message)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// Convert the status to an anyhow error, which can be downcast to the StatusError.
 pub fn status_err(status: Status) -> anyhow::Error {
-    anyhow::anyhow!(
-        "status: {}: {}",
-        status.code().description(),
-        status.message(),
-    )
+    anyhow::Error::new(StatusError {
+        code: status.code(),
+        message: status.message().to_string(),
+    })
 }
@@ -4,6 +4,7 @@
 
 pub use common::flight_result_to_str;
 pub use common::status_err;
+pub use common::StatusError;
 pub use flight_token::FlightClaim;
 pub use flight_token::FlightToken;
 pub use store_client::BlockStream;
@@ -18,8 +19,12 @@ pub use store_do_action::DropTableAction;
 pub use store_do_action::DropTableActionResult;
 pub use store_do_action::GetTableAction;
 pub use store_do_action::GetTableActionResult;
+pub use store_do_action::PingAction;
+pub use store_do_action::PingActionResult;
 pub use store_do_action::ReadPlanAction;
 pub use store_do_action::ReadPlanActionResult;
+pub use store_do_action::RenameTableAction;
+pub use store_do_action::RenameTableActionResult;
 pub use store_do_action::StoreDoAction;
 pub use store_do_action::StoreDoActionResult;
 pub use store_do_get::StoreDoGet;
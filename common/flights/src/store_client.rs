@@ -46,6 +46,10 @@ use crate::DropTableAction;
 use crate::DropTableActionResult;
 use crate::GetTableAction;
 use crate::GetTableActionResult;
+use crate::PingAction;
+use crate::PingActionResult;
+use crate::RenameTableAction;
+use crate::RenameTableActionResult;
 
 pub type BlockStream =
     std::pin::Pin<Box<dyn futures::stream::Stream<Item = DataBlock> + Sync + Send + 'static>>;
@@ -144,6 +148,26 @@ impl StoreClient {
         anyhow::bail!("invalid response")
     }
 
+    /// Rename table call.
+    pub async fn rename_table(
+        &mut self,
+        db: String,
+        table: String,
+        new_table: String,
+    ) -> anyhow::Result<RenameTableActionResult> {
+        let action = StoreDoAction::RenameTable(RenameTableAction {
+            db,
+            table,
+            new_table,
+        });
+        let rst = self.do_action(&action).await?;
+
+        if let StoreDoActionResult::RenameTable(rst) = rst {
+            return Ok(rst);
+        }
+        anyhow::bail!("invalid response")
+    }
+
     /// Get table.
     pub async fn get_table(
         &mut self,
@@ -159,6 +183,17 @@ impl StoreClient {
         anyhow::bail!("invalid response")
     }
 
+    /// Ping the store server, for the health checks and latency measurement.
+    pub async fn ping(&mut self) -> anyhow::Result<PingActionResult> {
+        let action = StoreDoAction::Ping(PingAction {});
+        let rst = self.do_action(&action).await?;
+
+        if let StoreDoActionResult::Ping(rst) = rst {
+            return Ok(rst);
+        }
+        anyhow::bail!("invalid response")
+    }
+
     /// Handshake.
     async fn handshake(
         client: &mut FlightServiceClient<Channel>,
@@ -181,10 +216,14 @@ impl StoreClient {
         }));
         req.set_timeout(timeout);
 
-        let rx = client.handshake(req).await?;
+        let rx = client.handshake(req).await.map_err(status_err)?;
         let mut rx = rx.into_inner();
 
-        let resp = rx.next().await.expect("Must respond from handshake")?;
+        let resp = rx
+            .next()
+            .await
+            .expect("Must respond from handshake")
+            .map_err(status_err)?;
         let token = resp.payload;
         Ok(token)
     }
@@ -202,7 +241,7 @@ impl StoreClient {
             .map_err(status_err)?
             .into_inner();
 
-        match stream.message().await? {
+        match stream.message().await.map_err(status_err)? {
             None => anyhow::bail!(
                 "Can not receive data from store flight server, action: {:?}",
                 action
@@ -252,7 +291,7 @@ impl StoreClient {
         let meta = req.metadata_mut();
         store_do_put::set_do_put_meta(meta, &db_name, &tbl_name);
 
-        let res = self.client.do_put(req).await?;
+        let res = self.client.do_put(req).await.map_err(status_err)?;
 
         use anyhow::Context;
         let put_result = res.into_inner().next().await.context("empty response")??;
@@ -58,6 +58,15 @@ pub struct DropTableAction {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct DropTableActionResult {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RenameTableAction {
+    pub db: String,
+    pub table: String,
+    pub new_table: String,
+}
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RenameTableActionResult {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct GetTableAction {
     pub db: String,
@@ -71,6 +80,16 @@ pub struct GetTableActionResult {
     pub schema: DataSchemaRef,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PingAction {}
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PingActionResult {
+    /// The commit version of the store server.
+    pub version: String,
+    /// The current time of the store server, in milliseconds since the unix epoch.
+    pub server_time_ms: u64,
+}
+
 // Action wrapper for do_action.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub enum StoreDoAction {
@@ -79,7 +98,9 @@ pub enum StoreDoAction {
     DropDatabase(DropDatabaseAction),
     CreateTable(CreateTableAction),
     DropTable(DropTableAction),
+    RenameTable(RenameTableAction),
     GetTable(GetTableAction),
+    Ping(PingAction),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -89,7 +110,9 @@ pub enum StoreDoActionResult {
     DropDatabase(DropDatabaseActionResult),
     CreateTable(CreateTableActionResult),
     DropTable(DropTableActionResult),
+    RenameTable(RenameTableActionResult),
     GetTable(GetTableActionResult),
+    Ping(PingActionResult),
 }
 
 /// Try convert tonic::Request<Action> to DoActionAction.
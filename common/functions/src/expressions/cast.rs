@@ -4,8 +4,6 @@
 
 use std::fmt;
 
-use common_arrow::arrow::compute;
-use common_arrow::arrow::compute::CastOptions;
 use common_datavalues::DataColumnarValue;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
@@ -13,9 +11,6 @@ use common_exception::Result;
 
 use crate::function::IFunction;
 
-/// provide Datafuse default cast options
-pub const DEFAULT_DATAFUSE_CAST_OPTIONS: CastOptions = CastOptions { safe: false };
-
 #[derive(Clone)]
 pub struct CastFunction {
     /// The data type to cast to
@@ -43,14 +38,7 @@ impl IFunction for CastFunction {
     }
 
     fn eval(&self, columns: &[DataColumnarValue], _input_rows: usize) -> Result<DataColumnarValue> {
-        let value = columns[0].to_array()?;
-        Ok(DataColumnarValue::Array(
-            compute::kernels::cast::cast_with_options(
-                &value,
-                &self.cast_type,
-                &DEFAULT_DATAFUSE_CAST_OPTIONS,
-            )?,
-        ))
+        columns[0].cast_to(&self.cast_type)
     }
 
     fn num_arguments(&self) -> usize {
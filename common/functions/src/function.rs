@@ -23,6 +23,12 @@ pub trait IFunction: fmt::Display + Sync + Send + DynClone {
         None
     }
 
+    // A deterministic function always returns the same result for the same arguments,
+    // the results of a non-deterministic one(e.g. rand) can't be cached or deduplicated.
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
     fn return_type(&self, args: &[DataType]) -> Result<DataType>;
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool>;
     fn eval(&self, columns: &[DataColumnarValue], _input_rows: usize) -> Result<DataColumnarValue>;
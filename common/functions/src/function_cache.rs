@@ -0,0 +1,56 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::IFunction;
+
+/// A bounded cache of the functions by name, the least recently used one is evicted when full.
+/// The functions are immutable, the entries never go stale.
+pub(crate) struct FunctionCache {
+    capacity: usize,
+    // Increased by every access, the entry with the smallest tick is the least recently used.
+    tick: u64,
+    entries: HashMap<String, (u64, Arc<dyn IFunction>)>,
+}
+
+impl FunctionCache {
+    pub fn create(capacity: usize) -> Self {
+        FunctionCache {
+            capacity,
+            tick: 0,
+            entries: HashMap::with_capacity(capacity),
+        }
+    }
+
+    pub fn get(&mut self, name: &str) -> Option<Arc<dyn IFunction>> {
+        self.tick += 1;
+        let tick = self.tick;
+        self.entries.get_mut(name).map(|entry| {
+            entry.0 = tick;
+            entry.1.clone()
+        })
+    }
+
+    pub fn insert(&mut self, name: &str, function: Arc<dyn IFunction>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if !self.entries.contains_key(name) && self.entries.len() >= self.capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.0)
+                .map(|(name, _)| name.clone());
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+
+        self.tick += 1;
+        self.entries.insert(name.to_string(), (self.tick, function));
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::function_cache::FunctionCache;
+use crate::*;
+
+#[test]
+fn test_function_cache_evict_lru() -> Result<()> {
+    let mut cache = FunctionCache::create(2);
+    let plus: Arc<dyn IFunction> = Arc::from(FunctionFactory::get("plus")?);
+    let minus: Arc<dyn IFunction> = Arc::from(FunctionFactory::get("minus")?);
+    let multiply: Arc<dyn IFunction> = Arc::from(FunctionFactory::get("multiply")?);
+
+    cache.insert("plus", plus.clone());
+    cache.insert("minus", minus);
+    assert!(cache.get("not_exists_function").is_none());
+
+    // The hit is shared, and makes plus the most recently used.
+    assert!(Arc::ptr_eq(&plus, &cache.get("plus").unwrap()));
+
+    // Full, minus is evicted.
+    cache.insert("multiply", multiply);
+    assert!(cache.get("minus").is_none());
+    assert!(cache.get("plus").is_some());
+    assert!(cache.get("multiply").is_some());
+
+    // Zero capacity caches nothing.
+    let mut cache = FunctionCache::create(0);
+    cache.insert("plus", plus);
+    assert!(cache.get("plus").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_function_factory_get_cached() -> Result<()> {
+    use common_datavalues::DataType;
+
+    for name in &["plus", "+", "PLUS", "and", "siphash", "rand"] {
+        let uncached = FunctionFactory::get(name)?;
+        let cached = FunctionFactory::get_cached(name)?;
+        assert_eq!(uncached.name(), cached.name());
+        assert_eq!(format!("{}", uncached), format!("{}", cached));
+        assert_eq!(uncached.is_deterministic(), cached.is_deterministic());
+
+        // The repeated lookups share the function.
+        assert!(Arc::ptr_eq(&cached, &FunctionFactory::get_cached(name)?));
+    }
+
+    let args = [DataType::Int64, DataType::UInt8];
+    assert_eq!(
+        FunctionFactory::get("plus")?.return_type(&args)?,
+        FunctionFactory::get_cached("plus")?.return_type(&args)?
+    );
+
+    // The unknown functions are not cached.
+    let result = FunctionFactory::get_cached("not_exists_function");
+    assert_eq!(
+        "Code: 8, displayText = Unsupported Function: not_exists_function.",
+        result.err().unwrap().to_string()
+    );
+    Ok(())
+}
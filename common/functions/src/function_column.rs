@@ -4,6 +4,7 @@
 
 use std::fmt;
 
+use common_datavalues::field_with_name_ignore_case;
 use common_datavalues::DataColumnarValue;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
@@ -37,7 +38,7 @@ impl IFunction for ColumnFunction {
     }
 
     fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
-        let field = input_schema.field_with_name(self.value.as_str())?;
+        let field = field_with_name_ignore_case(input_schema, self.value.as_str())?;
         Ok(field.is_nullable())
     }
 
@@ -6,12 +6,14 @@ use std::sync::Arc;
 
 use common_exception::ErrorCodes;
 use common_exception::Result;
+use common_infallible::Mutex;
 use common_infallible::RwLock;
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
 
 use crate::arithmetics::ArithmeticFunction;
 use crate::comparisons::ComparisonFunction;
+use crate::function_cache::FunctionCache;
 use crate::hashes::HashesFunction;
 use crate::logics::LogicFunction;
 use crate::strings::StringFunction;
@@ -23,6 +25,8 @@ pub type FactoryFunc = fn(name: &str) -> Result<Box<dyn IFunction>>;
 
 pub type FactoryFuncRef = Arc<RwLock<IndexMap<&'static str, FactoryFunc>>>;
 
+const FUNCTION_CACHE_CAPACITY: usize = 256;
+
 lazy_static! {
     static ref FACTORY: FactoryFuncRef = {
         let map: FactoryFuncRef = Arc::new(RwLock::new(IndexMap::new()));
@@ -34,6 +38,8 @@ lazy_static! {
         HashesFunction::register(map.clone()).unwrap();
         map
     };
+    static ref CACHE: Mutex<FunctionCache> =
+        Mutex::new(FunctionCache::create(FUNCTION_CACHE_CAPACITY));
 }
 
 impl FunctionFactory {
@@ -45,13 +51,32 @@ impl FunctionFactory {
         (creator)(name)
     }
 
+    /// Same as get, but the function is shared from a cache of the recently used functions,
+    /// for the hot paths which only ask the function, e.g. the return type.
+    pub fn get_cached(name: &str) -> Result<Arc<dyn IFunction>> {
+        if let Some(function) = CACHE.lock().get(name) {
+            return Ok(function);
+        }
+
+        let function: Arc<dyn IFunction> = Arc::from(Self::get(name)?);
+        CACHE.lock().insert(name, function.clone());
+        Ok(function)
+    }
+
     pub fn check(name: &str) -> bool {
+        Self::contains(name)
+    }
+
+    pub fn contains(name: &str) -> bool {
         let map = FACTORY.read();
         map.contains_key(&*name.to_lowercase())
     }
 
+    /// All the registered function names, sorted.
     pub fn registered_names() -> Vec<String> {
         let map = FACTORY.read();
-        map.keys().into_iter().map(|x| x.to_string()).collect()
+        let mut names: Vec<String> = map.keys().map(|x| x.to_string()).collect();
+        names.sort();
+        names
     }
 }
@@ -0,0 +1,37 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::*;
+
+#[test]
+fn test_function_factory_registered_names() -> Result<()> {
+    let names = FunctionFactory::registered_names();
+    assert!(names.contains(&"plus".to_string()));
+    assert!(!names.contains(&"not_exists_function".to_string()));
+
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(sorted, names);
+
+    assert!(FunctionFactory::contains("plus"));
+    assert!(FunctionFactory::contains("PLUS"));
+    assert!(!FunctionFactory::contains("not_exists_function"));
+    Ok(())
+}
+
+#[test]
+fn test_function_factory_case_insensitive() -> Result<()> {
+    let upper = FunctionFactory::get("PLUS")?;
+    let lower = FunctionFactory::get("plus")?;
+    let mixed = FunctionFactory::get("Plus")?;
+    assert_eq!(upper.name(), lower.name());
+    assert_eq!(mixed.name(), lower.name());
+    assert_eq!(format!("{}", upper), format!("{}", lower));
+
+    let result = FunctionFactory::get("NOT_EXISTS_FUNCTION");
+    assert!(result.is_err());
+    Ok(())
+}
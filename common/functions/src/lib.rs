@@ -2,19 +2,25 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+mod function_cache_test;
 #[cfg(test)]
 mod function_column_test;
+#[cfg(test)]
+mod function_factory_test;
 
 mod arithmetics;
 mod comparisons;
 mod expressions;
 mod function;
 mod function_alias;
+mod function_cache;
 mod function_column;
 mod function_factory;
 mod function_literal;
 mod hashes;
 mod logics;
+mod maths;
 mod strings;
 mod udfs;
 
@@ -25,3 +31,6 @@ pub use function_column::ColumnFunction;
 pub use function_factory::FactoryFuncRef;
 pub use function_factory::FunctionFactory;
 pub use function_literal::LiteralFunction;
+pub use maths::eval_binary_f64;
+pub use maths::eval_constant_propagation;
+pub use maths::eval_unary_f64;
@@ -0,0 +1,145 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::new_null_array;
+use common_datavalues::data_array_cast;
+use common_datavalues::is_numeric;
+use common_datavalues::DataArrayRef;
+use common_datavalues::DataColumnarValue;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_datavalues::Float64Array;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+
+/// Apply a float->float closure over a numeric column, the column is casted to Float64 firstly.
+/// A `None` from the closure yields a NULL.
+pub fn eval_unary_f64<F>(column: &DataColumnarValue, f: F) -> Result<DataColumnarValue>
+where F: Fn(f64) -> Option<f64> {
+    check_numeric(column)?;
+
+    eval_constant_propagation(std::slice::from_ref(column), |columns| {
+        let array = to_f64_array(&columns[0].to_array()?)?;
+        let result: Float64Array = array.iter().map(|v| v.and_then(&f)).collect();
+        Ok(DataColumnarValue::Array(Arc::new(result)))
+    })
+}
+
+/// Apply a (float, float)->float closure over two numeric columns, a constant column is
+/// broadcasted against the other one. A NULL input or a `None` from the closure yields a NULL.
+pub fn eval_binary_f64<F>(
+    lhs: &DataColumnarValue,
+    rhs: &DataColumnarValue,
+    f: F,
+) -> Result<DataColumnarValue>
+where
+    F: Fn(f64, f64) -> Option<f64>,
+{
+    check_numeric(lhs)?;
+    check_numeric(rhs)?;
+
+    if lhs.len() != rhs.len() {
+        return Result::Err(ErrorCodes::BadArguments(format!(
+            "The columns must have the same length, but got {} and {}",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+
+    eval_constant_propagation(&[lhs.clone(), rhs.clone()], |columns| {
+        let result: Float64Array = match (&columns[0], &columns[1]) {
+            (DataColumnarValue::Constant(l, _), DataColumnarValue::Array(r)) => {
+                let l = to_f64_scalar(l)?;
+                let r = to_f64_array(r)?;
+                r.iter().map(|r| apply(l, r, &f)).collect()
+            }
+            (DataColumnarValue::Array(l), DataColumnarValue::Constant(r, _)) => {
+                let l = to_f64_array(l)?;
+                let r = to_f64_scalar(r)?;
+                l.iter().map(|l| apply(l, r, &f)).collect()
+            }
+            (l, r) => {
+                let l = to_f64_array(&l.to_array()?)?;
+                let r = to_f64_array(&r.to_array()?)?;
+                l.iter()
+                    .zip(r.iter())
+                    .map(|(l, r)| apply(l, r, &f))
+                    .collect()
+            }
+        };
+        Ok(DataColumnarValue::Array(Arc::new(result)))
+    })
+}
+
+/// Deterministic scalar functions should produce a constant column if all the inputs are constant:
+/// the inputs are shrunk to single-row arrays so that `f` is evaluated only once,
+/// then the result is resized back by `resize_constant`. Otherwise `f` is evaluated as usual.
+pub fn eval_constant_propagation<F>(
+    columns: &[DataColumnarValue],
+    f: F,
+) -> Result<DataColumnarValue>
+where
+    F: Fn(&[DataColumnarValue]) -> Result<DataColumnarValue>,
+{
+    let all_constant = !columns.is_empty()
+        && columns
+            .iter()
+            .all(|column| matches!(column, DataColumnarValue::Constant(_, _)));
+    if !all_constant {
+        return f(columns);
+    }
+
+    let rows = columns[0].len();
+    let columns = columns
+        .iter()
+        .map(|column| {
+            Ok(DataColumnarValue::Array(
+                column.resize_constant(1)?.to_array()?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    f(&columns)?.resize_constant(rows)
+}
+
+fn check_numeric(column: &DataColumnarValue) -> Result<()> {
+    let data_type = column.data_type();
+    if data_type != DataType::Null && !is_numeric(&data_type) {
+        return Result::Err(ErrorCodes::BadArguments(format!(
+            "Expected numeric types, but got {}",
+            data_type
+        )));
+    }
+    Ok(())
+}
+
+#[inline]
+fn apply<F>(lhs: Option<f64>, rhs: Option<f64>, f: &F) -> Option<f64>
+where F: Fn(f64, f64) -> Option<f64> {
+    match (lhs, rhs) {
+        (Some(l), Some(r)) => f(l, r),
+        _ => None,
+    }
+}
+
+fn to_f64_scalar(value: &DataValue) -> Result<Option<f64>> {
+    let array = to_f64_array(&value.to_array_with_size(1)?)?;
+    Ok(array.iter().next().flatten())
+}
+
+fn to_f64_array(array: &DataArrayRef) -> Result<Float64Array> {
+    let array = match array.data_type() {
+        DataType::Null => new_null_array(&DataType::Float64, array.len()),
+        _ => data_array_cast(array, &DataType::Float64)?,
+    };
+
+    match array.data_type() {
+        DataType::Float64 => Ok(Float64Array::from(array.data().clone())),
+        data_type => Result::Err(ErrorCodes::BadDataValueType(format!(
+            "Cannot convert {:?} to Float64Array",
+            data_type
+        ))),
+    }
+}
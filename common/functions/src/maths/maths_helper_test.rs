@@ -0,0 +1,188 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_datavalues::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::maths::eval_binary_f64;
+use crate::maths::eval_constant_propagation;
+use crate::maths::eval_unary_f64;
+
+fn sqrt(v: f64) -> Option<f64> {
+    if v < 0.0 {
+        None
+    } else {
+        Some(v.sqrt())
+    }
+}
+
+#[test]
+fn test_eval_unary_f64() -> Result<()> {
+    // Regular column, the negative values yield NULLs.
+    {
+        let column: DataColumnarValue =
+            Arc::new(Int64Array::from(vec![Some(4), Some(-1), None, Some(9)])).into();
+        let actual = eval_unary_f64(&column, sqrt)?;
+        let expect: DataArrayRef =
+            Arc::new(Float64Array::from(vec![Some(2.0), None, None, Some(3.0)]));
+        assert_eq!(expect.as_ref(), actual.to_array()?.as_ref());
+    }
+
+    // Constant column is still constant.
+    {
+        let column = DataColumnarValue::Constant(DataValue::UInt8(Some(16)), 3);
+        match eval_unary_f64(&column, sqrt)? {
+            DataColumnarValue::Constant(value, size) => {
+                assert_eq!(DataValue::Float64(Some(4.0)), value);
+                assert_eq!(3, size);
+            }
+            _ => panic!("Must be constant column"),
+        }
+
+        let column = DataColumnarValue::Constant(DataValue::Float32(Some(-1.0)), 3);
+        let actual = eval_unary_f64(&column, sqrt)?;
+        assert_eq!(3, actual.len());
+        assert_eq!(3, actual.to_array()?.null_count());
+    }
+
+    // Non-numeric column.
+    {
+        let column: DataColumnarValue = Arc::new(StringArray::from(vec!["a"])).into();
+        let actual = eval_unary_f64(&column, sqrt);
+        assert_eq!(
+            "Code: 6, displayText = Expected numeric types, but got Utf8.",
+            format!("{}", actual.unwrap_err())
+        );
+    }
+
+    Ok(())
+}
+
+fn pow(base: f64, exp: f64) -> Option<f64> {
+    let v = base.powf(exp);
+    if v.is_nan() {
+        None
+    } else {
+        Some(v)
+    }
+}
+
+#[test]
+fn test_eval_binary_f64() -> Result<()> {
+    let column: DataColumnarValue =
+        Arc::new(Int32Array::from(vec![Some(2), None, Some(-8), Some(3)])).into();
+
+    // Scalar-scalar.
+    {
+        let lhs = DataColumnarValue::Constant(DataValue::UInt8(Some(2)), 4);
+        let rhs = DataColumnarValue::Constant(DataValue::Float64(Some(3.0)), 4);
+        match eval_binary_f64(&lhs, &rhs, pow)? {
+            DataColumnarValue::Constant(value, size) => {
+                assert_eq!(DataValue::Float64(Some(8.0)), value);
+                assert_eq!(4, size);
+            }
+            _ => panic!("Must be constant column"),
+        }
+
+        let rhs = DataColumnarValue::Constant(DataValue::Float64(None), 4);
+        match eval_binary_f64(&lhs, &rhs, pow)? {
+            DataColumnarValue::Constant(value, _) => {
+                assert_eq!(DataValue::Float64(None), value);
+            }
+            _ => panic!("Must be constant column"),
+        }
+    }
+
+    // Scalar-column.
+    {
+        let lhs = DataColumnarValue::Constant(DataValue::Int64(Some(2)), 4);
+        let actual = eval_binary_f64(&lhs, &column, pow)?;
+        let expect: DataArrayRef = Arc::new(Float64Array::from(vec![
+            Some(4.0),
+            None,
+            Some(0.00390625),
+            Some(8.0),
+        ]));
+        assert_eq!(expect.as_ref(), actual.to_array()?.as_ref());
+    }
+
+    // Column-scalar, (-8)^0.5 yields NULL.
+    {
+        let rhs = DataColumnarValue::Constant(DataValue::Float64(Some(0.5)), 4);
+        let actual = eval_binary_f64(&column, &rhs, pow)?;
+        let expect: DataArrayRef = Arc::new(Float64Array::from(vec![
+            Some(2f64.sqrt()),
+            None,
+            None,
+            Some(3f64.sqrt()),
+        ]));
+        assert_eq!(expect.as_ref(), actual.to_array()?.as_ref());
+    }
+
+    // Column-column.
+    {
+        let rhs: DataColumnarValue =
+            Arc::new(UInt8Array::from(vec![Some(3), Some(1), None, Some(2)])).into();
+        let actual = eval_binary_f64(&column, &rhs, pow)?;
+        let expect: DataArrayRef =
+            Arc::new(Float64Array::from(vec![Some(8.0), None, None, Some(9.0)]));
+        assert_eq!(expect.as_ref(), actual.to_array()?.as_ref());
+    }
+
+    // Length mismatch.
+    {
+        let rhs: DataColumnarValue = Arc::new(UInt8Array::from(vec![1])).into();
+        let actual = eval_binary_f64(&column, &rhs, pow);
+        assert_eq!(
+            "Code: 6, displayText = The columns must have the same length, but got 4 and 1.",
+            format!("{}", actual.unwrap_err())
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_constant_propagation() -> Result<()> {
+    let sqrt_column = |columns: &[DataColumnarValue]| eval_unary_f64(&columns[0], sqrt);
+
+    // sqrt(const) is evaluated over a single row and yields a constant column.
+    {
+        let columns = vec![DataColumnarValue::Constant(
+            DataValue::UInt64(Some(25)),
+            1000,
+        )];
+        let actual = eval_constant_propagation(&columns, |columns| {
+            assert_eq!(1, columns[0].len());
+            assert_eq!(true, matches!(columns[0], DataColumnarValue::Array(_)));
+            sqrt_column(columns)
+        })?;
+        match actual {
+            DataColumnarValue::Constant(value, size) => {
+                assert_eq!(DataValue::Float64(Some(5.0)), value);
+                assert_eq!(1000, size);
+            }
+            _ => panic!("Must be constant column"),
+        }
+    }
+
+    // Mixed constant and array columns are evaluated as usual.
+    {
+        let columns = vec![
+            DataColumnarValue::Constant(DataValue::UInt64(Some(25)), 2),
+            Arc::new(UInt64Array::from(vec![1, 4])).into(),
+        ];
+        let actual = eval_constant_propagation(&columns, |columns| {
+            assert_eq!(2, columns[0].len());
+            sqrt_column(&columns[1..])
+        })?;
+        let expect: DataArrayRef = Arc::new(Float64Array::from(vec![1.0, 2.0]));
+        assert_eq!(expect.as_ref(), actual.to_array()?.as_ref());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod maths_helper_test;
+
+mod maths_helper;
+
+pub use maths_helper::eval_binary_f64;
+pub use maths_helper::eval_constant_propagation;
+pub use maths_helper::eval_unary_f64;
@@ -5,16 +5,20 @@
 #[cfg(test)]
 mod database_test;
 #[cfg(test)]
+mod random_test;
+#[cfg(test)]
 mod to_type_name_test;
 #[cfg(test)]
 mod udf_example_test;
 
 mod database;
+mod random;
 mod to_type_name;
 mod udf;
 mod udf_example;
 
 pub use database::DatabaseFunction;
+pub use random::RandFunction;
 pub use to_type_name::ToTypeNameFunction;
 pub use udf::UdfFunction;
 pub use udf_example::UdfExampleFunction;
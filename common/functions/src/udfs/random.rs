@@ -0,0 +1,67 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::DataColumnarValue;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::Float64Array;
+use common_exception::Result;
+use rand::Rng;
+
+use crate::IFunction;
+
+/// rand() returns a random Float64 in [0, 1) for each row.
+#[derive(Clone)]
+pub struct RandFunction {
+    display_name: String,
+}
+
+impl RandFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn IFunction>> {
+        Ok(Box::new(RandFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl IFunction for RandFunction {
+    fn name(&self) -> &str {
+        "RandFunction"
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _columns: &[DataColumnarValue], input_rows: usize) -> Result<DataColumnarValue> {
+        let mut rng = rand::thread_rng();
+        let values = (0..input_rows)
+            .map(|_| rng.gen::<f64>())
+            .collect::<Vec<_>>();
+        Ok(DataColumnarValue::Array(Arc::new(Float64Array::from(
+            values,
+        ))))
+    }
+
+    fn num_arguments(&self) -> usize {
+        0
+    }
+}
+
+impl fmt::Display for RandFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
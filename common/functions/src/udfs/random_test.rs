@@ -0,0 +1,29 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_rand_function() -> anyhow::Result<()> {
+    use common_arrow::arrow::array::Array;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let func = FunctionFactory::get("rand")?;
+    assert_eq!("rand()", format!("{}", func));
+    assert_eq!(false, func.is_deterministic());
+    assert_eq!(DataType::Float64, func.return_type(&[])?);
+
+    let result = func.eval(&[], 16)?.to_array()?;
+    assert_eq!(16, result.len());
+    let values = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    for i in 0..values.len() {
+        assert!((0.0..1.0).contains(&values.value(i)));
+    }
+
+    // The other functions are deterministic.
+    assert_eq!(true, FunctionFactory::get("+")?.is_deterministic());
+
+    Ok(())
+}
@@ -5,6 +5,7 @@
 use common_exception::Result;
 
 use crate::udfs::DatabaseFunction;
+use crate::udfs::RandFunction;
 use crate::udfs::ToTypeNameFunction;
 use crate::udfs::UdfExampleFunction;
 use crate::FactoryFuncRef;
@@ -18,6 +19,7 @@ impl UdfFunction {
         map.insert("example", UdfExampleFunction::try_create);
         map.insert("totypename", ToTypeNameFunction::try_create);
         map.insert("database", DatabaseFunction::try_create);
+        map.insert("rand", RandFunction::try_create);
         Ok(())
     }
 }
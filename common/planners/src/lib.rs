@@ -13,12 +13,18 @@ mod plan_explain_test;
 #[cfg(test)]
 mod plan_expression_test;
 #[cfg(test)]
+mod plan_expression_typed_test;
+#[cfg(test)]
 mod plan_filter_test;
 #[cfg(test)]
 mod plan_having_test;
 #[cfg(test)]
 mod plan_limit_test;
 #[cfg(test)]
+mod plan_node_test;
+#[cfg(test)]
+mod plan_partition_test;
+#[cfg(test)]
 mod plan_projection_test;
 #[cfg(test)]
 mod plan_rewriter_test;
@@ -27,6 +33,10 @@ mod plan_scan_test;
 #[cfg(test)]
 mod plan_select_test;
 #[cfg(test)]
+mod plan_statistics_test;
+#[cfg(test)]
+mod plan_table_create_test;
+#[cfg(test)]
 mod plan_walker_test;
 #[cfg(test)]
 mod test;
@@ -42,12 +52,16 @@ mod plan_explain;
 mod plan_expression;
 mod plan_expression_chain;
 mod plan_expression_column;
+mod plan_expression_constant;
 mod plan_expression_function;
 mod plan_expression_literal;
 mod plan_expression_rewriter;
 mod plan_expression_sort;
+mod plan_expression_sql;
+mod plan_expression_typed;
 mod plan_expression_validator;
 mod plan_expression_visitor;
+mod plan_expression_window;
 mod plan_filter;
 mod plan_having;
 mod plan_insert_into;
@@ -80,6 +94,7 @@ pub use plan_database_drop::DropDatabasePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
 pub use plan_explain::ExplainType;
+pub use plan_expression::DatePart;
 pub use plan_expression::Expression;
 pub use plan_expression::ExpressionPlan;
 pub use plan_expression_chain::*;
@@ -92,9 +107,13 @@ pub use plan_expression_function::sum;
 pub use plan_expression_literal::lit;
 pub use plan_expression_rewriter::ExprRewriter;
 pub use plan_expression_sort::sort;
+pub use plan_expression_typed::TypedExpression;
 pub use plan_expression_validator::validate_expression;
 pub use plan_expression_visitor::ExpressionVisitor;
 pub use plan_expression_visitor::Recursion;
+pub use plan_expression_window::WindowFrame;
+pub use plan_expression_window::WindowFrameBound;
+pub use plan_expression_window::WindowFrameUnits;
 pub use plan_filter::FilterPlan;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertIntoPlan;
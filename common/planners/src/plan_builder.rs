@@ -63,7 +63,7 @@ impl PlanBuilder {
         // Get the projection expressions(Including rewrite).
         let mut projection_exprs = vec![];
         exprs.iter().for_each(|v| match v {
-            Expression::Wildcard => {
+            Expression::Wildcard | Expression::QualifiedWildcard(_) => {
                 for i in 0..input_schema.fields().len() {
                     projection_exprs.push(col(input_schema.fields()[i].name()))
                 }
@@ -3,10 +3,12 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCodes;
 
 /// Database engine type.
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -24,6 +26,21 @@ impl ToString for DatabaseEngineType {
     }
 }
 
+impl FromStr for DatabaseEngineType {
+    type Err = ErrorCodes;
+
+    fn from_str(engine: &str) -> Result<Self, Self::Err> {
+        match engine {
+            "Local" => Ok(DatabaseEngineType::Local),
+            "Remote" => Ok(DatabaseEngineType::Remote),
+            _ => Err(ErrorCodes::UnknownDatabaseEngine(format!(
+                "Unknown database engine '{}', the supported engines are: Local, Remote",
+                engine
+            ))),
+        }
+    }
+}
+
 pub type DatabaseOptions = HashMap<String, String>;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -7,13 +7,28 @@ use std::fmt::Display;
 
 use common_datavalues::DataSchema;
 
+use crate::Expression;
 use crate::PlanNode;
 
 impl PlanNode {
     pub fn display_indent(&self) -> impl fmt::Display + '_ {
-        struct Wrapper<'a>(&'a PlanNode);
+        self.display_indent_with(|expr| format!("{:?}", expr))
+    }
+
+    /// Same as display_indent, but the expressions are rendered by Expression::to_sql.
+    pub fn display_indent_sql(&self) -> impl fmt::Display + '_ {
+        self.display_indent_with(Expression::to_sql)
+    }
+
+    fn display_indent_with(&self, render: fn(&Expression) -> String) -> impl fmt::Display + '_ {
+        struct Wrapper<'a>(&'a PlanNode, fn(&Expression) -> String);
         impl<'a> fmt::Display for Wrapper<'a> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let render = self.1;
+                let render_list = |exprs: &[Expression]| {
+                    let exprs = exprs.iter().map(render).collect::<Vec<_>>();
+                    format!("[{}]", exprs.join(", "))
+                };
                 let mut indent = 0;
                 let mut write_indent = |f: &mut fmt::Formatter| -> fmt::Result {
                     if indent > 0 {
@@ -32,8 +47,8 @@ impl PlanNode {
                         PlanNode::Stage(plan) => {
                             write!(
                                 f,
-                                "RedistributeStage[expr: {:?}]",
-                                plan.scatters_expr,
+                                "RedistributeStage[expr: {}]",
+                                render(&plan.scatters_expr),
                             )?;
                             Ok(true)
                         }
@@ -45,8 +60,8 @@ impl PlanNode {
                                 }
                                 write!(
                                     f,
-                                    "{:?}:{:?}",
-                                    plan.expr[i],
+                                    "{}:{:?}",
+                                    render(&plan.expr[i]),
                                     plan.expr[i].to_data_type(&plan.input.schema()).unwrap()
                                 )?;
                             }
@@ -60,8 +75,8 @@ impl PlanNode {
                                 }
                                 write!(
                                     f,
-                                    "{:?}:{:?}",
-                                    plan.exprs[i],
+                                    "{}:{:?}",
+                                    render(&plan.exprs[i]),
                                     plan.exprs[i].to_data_type(&plan.input.schema()).unwrap()
                                 )?;
                             }
@@ -71,25 +86,27 @@ impl PlanNode {
                         PlanNode::AggregatorPartial(plan) => {
                             write!(
                                 f,
-                                "AggregatorPartial: groupBy=[{:?}], aggr=[{:?}]",
-                                plan.group_expr, plan.aggr_expr
+                                "AggregatorPartial: groupBy=[{}], aggr=[{}]",
+                                render_list(&plan.group_expr),
+                                render_list(&plan.aggr_expr)
                             )?;
                             Ok(true)
                         }
                         PlanNode::AggregatorFinal(plan) => {
                             write!(
                                 f,
-                                "AggregatorFinal: groupBy=[{:?}], aggr=[{:?}]",
-                                plan.group_expr, plan.aggr_expr
+                                "AggregatorFinal: groupBy=[{}], aggr=[{}]",
+                                render_list(&plan.group_expr),
+                                render_list(&plan.aggr_expr)
                             )?;
                             Ok(true)
                         }
                         PlanNode::Filter(plan) => {
-                            write!(f, "Filter: {:?}", plan.predicate)?;
+                            write!(f, "Filter: {}", render(&plan.predicate))?;
                             Ok(true)
                         }
                         PlanNode::Having(plan) => {
-                            write!(f, "Having: {:?}", plan.predicate)?;
+                            write!(f, "Having: {}", render(&plan.predicate))?;
                             Ok(true)
                         }
                         PlanNode::Sort(plan) => {
@@ -101,8 +118,8 @@ impl PlanNode {
                                 let expr = plan.order_by[i].clone();
                                 write!(
                                     f,
-                                    "{:?}:{:?}",
-                                    expr,
+                                    "{}:{:?}",
+                                    render(&expr),
                                     expr.to_data_type(&plan.schema()).unwrap()
                                 )?;
                             }
@@ -162,7 +179,7 @@ impl PlanNode {
                 })
             }
         }
-        Wrapper(self)
+        Wrapper(self, render)
     }
 
     pub fn display_graphviz(&self) -> impl fmt::Display + '_ {
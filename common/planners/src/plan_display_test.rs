@@ -8,6 +8,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 
+use crate::test::Test;
 use crate::*;
 
 #[test]
@@ -36,3 +37,23 @@ fn test_plan_display_indent() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_plan_display_indent_sql() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(col("number").eq(lit(1i64)))?
+        .project(&[col("number").alias("n")])?
+        .build()?;
+
+    let expect = "\
+    Projection: number AS n:UInt64\
+    \n  Filter: (number = 1)\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    let actual = format!("{}", plan.display_indent_sql());
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -7,6 +7,8 @@ use std::sync::Arc;
 
 use common_aggregate_functions::AggregateFunctionFactory;
 use common_aggregate_functions::IAggregateFunction;
+use common_datavalues::common_type;
+use common_datavalues::field_with_name_ignore_case;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
@@ -16,6 +18,7 @@ use common_exception::Result;
 use common_functions::FunctionFactory;
 
 use crate::PlanNode;
+use crate::WindowFrame;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct ExpressionPlan {
@@ -33,6 +36,68 @@ impl ExpressionPlan {
     pub fn set_input(&mut self, node: &PlanNode) {
         self.input = Arc::new(node.clone());
     }
+
+    /// Check that every expression's field matches the field of the same name in the schema.
+    pub fn validate(&self) -> Result<()> {
+        let input_schema = self.input.schema();
+        for expr in &self.exprs {
+            let field = expr.to_data_field(&input_schema)?;
+            match self.schema.field_with_name(field.name()) {
+                Ok(schema_field) if schema_field == &field => {}
+                Ok(schema_field) => {
+                    return Result::Err(ErrorCodes::LogicalError(format!(
+                        "The field of expression {:?} mismatch: expected {:?}, but got {:?}",
+                        expr, field, schema_field
+                    )))
+                }
+                Err(_) => {
+                    return Result::Err(ErrorCodes::LogicalError(format!(
+                        "The field of expression {:?} is missing in the schema: {:?}",
+                        expr, field
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The field of a temporal value used by extract(field from expr).
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum DatePart {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    DayOfWeek,
+    DayOfYear,
+}
+
+impl DatePart {
+    pub fn return_type(&self) -> DataType {
+        match self {
+            DatePart::Year | DatePart::DayOfYear => DataType::UInt16,
+            _ => DataType::UInt8,
+        }
+    }
+}
+
+impl fmt::Display for DatePart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            DatePart::Year => "year",
+            DatePart::Month => "month",
+            DatePart::Day => "day",
+            DatePart::Hour => "hour",
+            DatePart::Minute => "minute",
+            DatePart::Second => "second",
+            DatePart::DayOfWeek => "dayofweek",
+            DatePart::DayOfYear => "dayofyear",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -72,6 +137,8 @@ pub enum Expression {
     },
     /// All fields(*) in a schema.
     Wildcard,
+    /// All fields of the qualified relation(table.*) in a schema.
+    QualifiedWildcard(Vec<String>),
     /// Casts the expression to a given type and will return a runtime error if the expression cannot be cast.
     /// This expression is guaranteed to have a fixed type.
     Cast {
@@ -80,6 +147,32 @@ pub enum Expression {
         /// The `DataType` the expression will yield
         data_type: DataType,
     },
+    /// A lightweight conditional expression: if(condition, then, otherwise).
+    If {
+        /// The boolean condition
+        condition: Box<Expression>,
+        /// The expression yielded when condition is true
+        then: Box<Expression>,
+        /// The expression yielded when condition is false
+        otherwise: Box<Expression>,
+    },
+    /// Extracts a date part from a temporal expression: extract(year from expr).
+    Extract {
+        /// The date part to extract
+        field: DatePart,
+        /// The temporal expression
+        expr: Box<Expression>,
+    },
+    /// A window function: func(args) OVER (PARTITION BY ... ORDER BY ...).
+    WindowFunction {
+        /// The function name, a ranking function or an aggregate function
+        func: String,
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        order_by: Vec<Expression>,
+        /// The optional window frame, the default frame is used if it's None
+        frame: Option<WindowFrame>,
+    },
 }
 
 impl Expression {
@@ -90,6 +183,19 @@ impl Expression {
         }
     }
 
+    /// Returns the column name if the expression is a column reference,
+    /// a single alias over a column is unwrapped.
+    pub fn as_column_ref(&self) -> Option<&str> {
+        match self {
+            Expression::Column(name) => Some(name.as_str()),
+            Expression::Alias(_, expr) => match expr.as_ref() {
+                Expression::Column(name) => Some(name.as_str()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     pub fn to_data_field(&self, input_schema: &DataSchemaRef) -> Result<DataField> {
         let name = self.column_name();
         self.to_data_type(&input_schema).and_then(|return_type| {
@@ -99,51 +205,137 @@ impl Expression {
     }
 
     // TODO
-    pub fn nullable(&self, _input_schema: &DataSchemaRef) -> Result<bool> {
-        Ok(false)
+    pub fn nullable(&self, input_schema: &DataSchemaRef) -> Result<bool> {
+        match self {
+            Expression::Alias(_, expr) => expr.nullable(input_schema),
+            Expression::Literal(v) => Ok(matches!(v, DataValue::Null) || v.is_null()),
+            Expression::BinaryExpression { left, right, .. } => {
+                Ok(left.nullable(input_schema)? || right.nullable(input_schema)?)
+            }
+            Expression::If {
+                then, otherwise, ..
+            } => Ok(then.nullable(input_schema)? || otherwise.nullable(input_schema)?),
+            _ => Ok(false),
+        }
     }
 
     pub fn to_data_type(&self, input_schema: &DataSchemaRef) -> Result<DataType> {
+        let mut children_types = vec![];
+        for child in self.data_type_children() {
+            children_types.push(child.to_data_type(input_schema)?);
+        }
+        self.to_data_type_with_children(input_schema, children_types)
+    }
+
+    /// The sub expressions the data type of this expression is derived from.
+    pub(crate) fn data_type_children(&self) -> Vec<&Expression> {
         match self {
-            Expression::Alias(_, expr) => expr.to_data_type(input_schema),
-            Expression::Column(s) => Ok(input_schema.field_with_name(s)?.data_type().clone()),
-            Expression::Literal(v) => Ok(v.data_type()),
-            Expression::BinaryExpression { op, left, right } => {
-                let arg_types = vec![
-                    left.to_data_type(input_schema)?,
-                    right.to_data_type(input_schema)?,
-                ];
-                let func = FunctionFactory::get(op)?;
-                func.return_type(&arg_types)
+            Expression::Alias(_, expr)
+            | Expression::UnaryExpression { expr, .. }
+            | Expression::Sort { expr, .. }
+            | Expression::Extract { expr, .. } => vec![expr.as_ref()],
+            Expression::BinaryExpression { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+            Expression::ScalarFunction { args, .. }
+            | Expression::AggregateFunction { args, .. } => args.iter().collect(),
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => vec![condition.as_ref(), then.as_ref(), otherwise.as_ref()],
+            Expression::WindowFunction { func, args, .. } => match func.to_lowercase().as_str() {
+                "row_number" | "rank" | "dense_rank" => vec![],
+                _ => args.iter().collect(),
+            },
+            Expression::Column(_)
+            | Expression::Literal(_)
+            | Expression::Wildcard
+            | Expression::QualifiedWildcard(_)
+            | Expression::Cast { .. } => vec![],
+        }
+    }
+
+    /// The data type of this expression, given the types of its `data_type_children`.
+    pub(crate) fn to_data_type_with_children(
+        &self,
+        input_schema: &DataSchemaRef,
+        mut children_types: Vec<DataType>,
+    ) -> Result<DataType> {
+        match self {
+            Expression::Alias(_, _) | Expression::Sort { .. } => Ok(children_types.remove(0)),
+            Expression::Column(s) => {
+                let field = field_with_name_ignore_case(input_schema, s)?;
+                Ok(field.data_type().clone())
             }
+            Expression::Literal(v) => Ok(v.data_type()),
+            Expression::BinaryExpression { op, .. } => {
+                let func = FunctionFactory::get_cached(op)?;
+                let right_type = children_types.remove(1);
+                let left_type = children_types.remove(0);
 
-            Expression::UnaryExpression { op, expr } => {
-                let arg_types = vec![expr.to_data_type(input_schema)?];
-                let func = FunctionFactory::get(op)?;
+                // A NULL operand adopts the type of the other operand to resolve the function.
+                let arg_types = match (&left_type, &right_type) {
+                    (DataType::Null, DataType::Null) => return Ok(DataType::Null),
+                    (DataType::Null, _) => vec![right_type.clone(), right_type],
+                    (_, DataType::Null) => vec![left_type.clone(), left_type],
+                    _ => vec![left_type, right_type],
+                };
                 func.return_type(&arg_types)
             }
 
-            Expression::ScalarFunction { op, args } => {
-                let mut arg_types = Vec::with_capacity(args.len());
-                for arg in args {
-                    arg_types.push(arg.to_data_type(input_schema)?);
-                }
-                let func = FunctionFactory::get(op)?;
-                func.return_type(&arg_types)
+            Expression::UnaryExpression { op, .. } | Expression::ScalarFunction { op, .. } => {
+                let func = FunctionFactory::get_cached(op)?;
+                func.return_type(&children_types)
             }
-            Expression::AggregateFunction { op, args } => {
-                let mut arg_types = Vec::with_capacity(args.len());
-                for arg in args {
-                    arg_types.push(arg.to_data_type(input_schema)?);
-                }
+            Expression::AggregateFunction { op, .. } => {
                 let func = AggregateFunctionFactory::get(op)?;
-                func.return_type(&arg_types)
+                func.return_type(&children_types)
+            }
+            Expression::Wildcard | Expression::QualifiedWildcard(_) => {
+                Result::Err(ErrorCodes::IllegalDataType(
+                    "Wildcard expressions are not valid to get return type",
+                ))
             }
-            Expression::Wildcard => Result::Err(ErrorCodes::IllegalDataType(
-                "Wildcard expressions are not valid to get return type",
-            )),
             Expression::Cast { data_type, .. } => Ok(data_type.clone()),
-            Expression::Sort { expr, .. } => expr.to_data_type(input_schema),
+            Expression::If { .. } => {
+                let otherwise_type = children_types.remove(2);
+                let then_type = children_types.remove(1);
+                let condition_type = children_types.remove(0);
+                if condition_type != DataType::Boolean {
+                    return Result::Err(ErrorCodes::IllegalDataType(format!(
+                        "The condition of if must be Boolean, but got {:?}",
+                        condition_type
+                    )));
+                }
+
+                common_type(&[then_type.clone(), otherwise_type.clone()]).map_err(|_| {
+                    ErrorCodes::IllegalDataType(format!(
+                        "Can not find the common type of if branches: {:?} and {:?}",
+                        then_type, otherwise_type
+                    ))
+                })
+            }
+            Expression::Extract { field, .. } => {
+                let data_type = children_types.remove(0);
+                match data_type {
+                    DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _) => {
+                        Ok(field.return_type())
+                    }
+                    _ => Result::Err(ErrorCodes::IllegalDataType(format!(
+                        "Extract {} expects a temporal argument, but got {:?}",
+                        field, data_type
+                    ))),
+                }
+            }
+            Expression::WindowFunction { func, .. } => match func.to_lowercase().as_str() {
+                "row_number" | "rank" | "dense_rank" => Ok(DataType::UInt64),
+                _ => {
+                    if AggregateFunctionFactory::check(func) {
+                        AggregateFunctionFactory::get(func)?.return_type(&children_types)
+                    } else {
+                        FunctionFactory::get_cached(func)?.return_type(&children_types)
+                    }
+                }
+            },
         }
     }
 
@@ -158,8 +350,24 @@ impl Expression {
 
     pub fn to_aggregate_function_args(&self) -> Result<Vec<String>> {
         match self {
-            Expression::AggregateFunction { args, .. } => {
-                let arg_names = args.iter().map(|arg| arg.column_name()).collect::<Vec<_>>();
+            Expression::AggregateFunction { op, args } => {
+                let mut arg_names: Vec<String> = Vec::with_capacity(args.len());
+                for arg in args {
+                    let name = arg.column_name();
+                    // The input columns are looked up by the names, so the different args
+                    // must not share one, e.g. the string literal 'a' and the column a.
+                    let collided = arg_names
+                        .iter()
+                        .zip(args.iter())
+                        .any(|(other_name, other)| *other_name == name && other != arg);
+                    if collided {
+                        return Err(ErrorCodes::IllegalAggregateExp(format!(
+                            "The different arguments of aggregate function {} have the same column name '{}'",
+                            op, name
+                        )));
+                    }
+                    arg_names.push(name);
+                }
                 Ok(arg_names)
             }
             _ => Err(ErrorCodes::LogicalError(
@@ -209,9 +417,47 @@ impl fmt::Debug for Expression {
 
             Expression::Sort { expr, .. } => write!(f, "{:?}", expr),
             Expression::Wildcard => write!(f, "*"),
+            Expression::QualifiedWildcard(qualifier) => write!(f, "{}.*", qualifier.join(".")),
             Expression::Cast { expr, data_type } => {
                 write!(f, "cast({:?} as {:?})", expr, data_type)
             }
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                write!(f, "if({:?}, {:?}, {:?})", condition, then, otherwise)
+            }
+            Expression::Extract { field, expr } => {
+                write!(f, "extract({} from {:?})", field, expr)
+            }
+            Expression::WindowFunction {
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame,
+            } => {
+                let join = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|expr| format!("{:?}", expr))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+
+                let mut window = vec![];
+                if !partition_by.is_empty() {
+                    window.push(format!("PARTITION BY {}", join(partition_by)));
+                }
+                if !order_by.is_empty() {
+                    window.push(format!("ORDER BY {}", join(order_by)));
+                }
+                if let Some(frame) = frame {
+                    window.push(format!("{}", frame));
+                }
+                write!(f, "{}({}) OVER ({})", func, join(args), window.join(" "))
+            }
         }
     }
 }
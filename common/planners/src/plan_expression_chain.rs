@@ -4,6 +4,7 @@
 
 use common_aggregate_functions::AggregateFunctionFactory;
 use common_aggregate_functions::IAggregateFunction;
+use common_datavalues::field_with_name_ignore_case;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_datavalues::DataValue;
@@ -90,7 +91,7 @@ impl ExpressionChain {
                 self.actions.push(ExpressionAction::Alias(alias));
             }
             Expression::Column(c) => {
-                let arg_type = self.schema.field_with_name(c)?.data_type();
+                let arg_type = field_with_name_ignore_case(&self.schema, c)?.data_type();
                 let input = ActionInput {
                     name: expr.column_name(),
                     return_type: arg_type.clone(),
@@ -198,7 +199,7 @@ impl ExpressionChain {
                 self.add_expr(expr)?;
             }
 
-            Expression::Wildcard => {}
+            Expression::Wildcard | Expression::QualifiedWildcard(_) => {}
             Expression::Cast {
                 expr: sub_expr,
                 data_type,
@@ -215,6 +216,14 @@ impl ExpressionChain {
 
                 self.actions.push(ExpressionAction::Function(function));
             }
+            Expression::If { .. }
+            | Expression::Extract { .. }
+            | Expression::WindowFunction { .. } => {
+                return Result::Err(ErrorCodes::UnImplement(format!(
+                    "Unsupported expression in expression chain: {:?}",
+                    expr
+                )));
+            }
         }
         Ok(())
     }
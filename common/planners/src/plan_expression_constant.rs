@@ -0,0 +1,64 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataColumnarValue;
+use common_datavalues::DataValue;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use common_functions::FunctionFactory;
+
+use crate::Expression;
+
+impl Expression {
+    /// Evaluate the literal-only expression to its single value, e.g. the column defaults.
+    /// Errors if the expression references the columns, or has the non-deterministic
+    /// functions(e.g. rand) or the aggregate functions.
+    pub fn evaluate_constant(&self) -> Result<DataValue> {
+        let column = self.evaluate_constant_column()?;
+        DataValue::try_from_column(&column, 0)
+    }
+
+    fn evaluate_constant_column(&self) -> Result<DataColumnarValue> {
+        match self {
+            Expression::Literal(value) => Ok(DataColumnarValue::Constant(value.clone(), 1)),
+            Expression::Alias(_, expr) => expr.evaluate_constant_column(),
+            Expression::Cast { expr, data_type } => {
+                expr.evaluate_constant_column()?.cast_to(data_type)
+            }
+            Expression::UnaryExpression { op, expr } => {
+                Self::evaluate_constant_function(op, &[expr.as_ref()])
+            }
+            Expression::BinaryExpression { op, left, right } => {
+                Self::evaluate_constant_function(op, &[left.as_ref(), right.as_ref()])
+            }
+            Expression::ScalarFunction { op, args } => {
+                Self::evaluate_constant_function(op, &args.iter().collect::<Vec<_>>())
+            }
+            Expression::Column(name) => Err(ErrorCodes::BadArguments(format!(
+                "Constant expression can not reference the column '{}'",
+                name
+            ))),
+            other => Err(ErrorCodes::BadArguments(format!(
+                "Constant expression can not have {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn evaluate_constant_function(op: &str, args: &[&Expression]) -> Result<DataColumnarValue> {
+        let func = FunctionFactory::get_cached(op)?;
+        if !func.is_deterministic() {
+            return Err(ErrorCodes::BadArguments(format!(
+                "Constant expression can not have the non-deterministic function '{}'",
+                op
+            )));
+        }
+
+        let columns = args
+            .iter()
+            .map(|arg| arg.evaluate_constant_column())
+            .collect::<Result<Vec<_>>>()?;
+        func.eval(&columns, 1)
+    }
+}
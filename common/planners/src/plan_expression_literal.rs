@@ -12,38 +12,38 @@ pub trait ILiteral {
 
 impl ILiteral for &str {
     fn to_literal(&self) -> Expression {
-        Expression::Literal(DataValue::Utf8(Some(self.to_string())))
+        Expression::Literal(DataValue::from(*self))
     }
 }
 
 impl ILiteral for String {
     fn to_literal(&self) -> Expression {
-        Expression::Literal(DataValue::Utf8(Some(self.clone())))
+        Expression::Literal(DataValue::from(self.clone()))
     }
 }
 
 macro_rules! make_literal {
-    ($TYPE:ty, $SCALAR:ident) => {
+    ($TYPE:ty) => {
         #[allow(missing_docs)]
         impl ILiteral for $TYPE {
             fn to_literal(&self) -> Expression {
-                Expression::Literal(DataValue::$SCALAR(Some(self.clone())))
+                Expression::Literal(DataValue::from(*self))
             }
         }
     };
 }
 
-make_literal!(bool, Boolean);
-make_literal!(f32, Float32);
-make_literal!(f64, Float64);
-make_literal!(i8, Int8);
-make_literal!(i16, Int16);
-make_literal!(i32, Int32);
-make_literal!(i64, Int64);
-make_literal!(u8, UInt8);
-make_literal!(u16, UInt16);
-make_literal!(u32, UInt32);
-make_literal!(u64, UInt64);
+make_literal!(bool);
+make_literal!(f32);
+make_literal!(f64);
+make_literal!(i8);
+make_literal!(i16);
+make_literal!(i32);
+make_literal!(i64);
+make_literal!(u8);
+make_literal!(u16);
+make_literal!(u32);
+make_literal!(u64);
 
 pub fn lit<T: ILiteral>(n: T) -> Expression {
     n.to_literal()
@@ -2,6 +2,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::collections::HashMap;
+
+use common_datavalues::is_numeric;
+use common_datavalues::numerical_coercion;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
 use common_exception::Result;
 
 use crate::Expression;
@@ -67,6 +73,10 @@ impl Expression {
                 let expr = expr.rewrite(rewriter)?;
                 Expression::Alias(name, Box::new(expr))
             }
+            Expression::UnaryExpression { op, expr } => Expression::UnaryExpression {
+                op,
+                expr: Box::new(expr.rewrite(rewriter)?),
+            },
             Expression::BinaryExpression { op, left, right } => Expression::BinaryExpression {
                 op,
                 left: Box::new(left.rewrite(rewriter)?),
@@ -93,6 +103,42 @@ impl Expression {
                     data_type,
                 }
             }
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => Expression::If {
+                condition: Box::new(condition.rewrite(rewriter)?),
+                then: Box::new(then.rewrite(rewriter)?),
+                otherwise: Box::new(otherwise.rewrite(rewriter)?),
+            },
+            Expression::Extract { field, expr } => Expression::Extract {
+                field,
+                expr: Box::new(expr.rewrite(rewriter)?),
+            },
+            Expression::WindowFunction {
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame,
+            } => {
+                let mut rewrite_exprs = |exprs: Vec<Expression>| -> Result<Vec<Expression>> {
+                    let mut new_exprs = Vec::with_capacity(exprs.len());
+                    for expr in exprs {
+                        new_exprs.push(expr.rewrite(rewriter)?);
+                    }
+                    Ok(new_exprs)
+                };
+
+                Expression::WindowFunction {
+                    func,
+                    args: rewrite_exprs(args)?,
+                    partition_by: rewrite_exprs(partition_by)?,
+                    order_by: rewrite_exprs(order_by)?,
+                    frame,
+                }
+            }
             Expression::Sort {
                 expr,
                 asc,
@@ -112,3 +158,230 @@ impl Expression {
         rewriter.mutate(expr)
     }
 }
+
+struct ColumnsRenamer<'a> {
+    mapping: &'a HashMap<String, String>,
+}
+
+impl<'a> ColumnsRenamer<'a> {
+    // The qualified name(table.column) is renamed by its last part.
+    fn rename(&self, name: String) -> String {
+        if let Some(new_name) = self.mapping.get(&name) {
+            return new_name.clone();
+        }
+
+        match name.rsplit_once('.') {
+            Some((qualifier, column)) => match self.mapping.get(column) {
+                Some(new_name) => format!("{}.{}", qualifier, new_name),
+                None => name,
+            },
+            None => name,
+        }
+    }
+}
+
+impl<'a> ExprRewriter for ColumnsRenamer<'a> {
+    fn mutate(&mut self, expr: Expression) -> Result<Expression> {
+        Ok(match expr {
+            Expression::Column(name) => Expression::Column(self.rename(name)),
+            Expression::Alias(alias, expr) => Expression::Alias(self.rename(alias), expr),
+            other => other,
+        })
+    }
+}
+
+impl Expression {
+    /// Rewrite the columns(and aliases) from the old name to the new name of the mapping,
+    /// the unmapped columns are untouched.
+    pub fn rename_columns(&self, mapping: &HashMap<String, String>) -> Expression {
+        let mut renamer = ColumnsRenamer { mapping };
+        match self.clone().rewrite(&mut renamer) {
+            Ok(expr) => expr,
+            // The renamer never fails.
+            Err(_) => self.clone(),
+        }
+    }
+}
+
+struct TypeCoercer<'a> {
+    schema: &'a DataSchemaRef,
+}
+
+impl<'a> TypeCoercer<'a> {
+    // Casts the numeric operands to their common type, others are untouched.
+    fn coerce_args(&self, args: Vec<Expression>) -> Result<Vec<Expression>> {
+        let mut arg_types = Vec::with_capacity(args.len());
+        for arg in &args {
+            arg_types.push(arg.to_data_type(self.schema)?);
+        }
+        if arg_types.len() < 2 || !arg_types.iter().all(is_numeric) {
+            return Ok(args);
+        }
+
+        let mut common = arg_types[0].clone();
+        for arg_type in &arg_types[1..] {
+            common = numerical_coercion(&common, arg_type)?;
+        }
+
+        Ok(args
+            .into_iter()
+            .zip(arg_types.iter())
+            .map(|(arg, arg_type)| Self::cast(arg, arg_type, &common))
+            .collect())
+    }
+
+    fn cast(expr: Expression, from: &DataType, to: &DataType) -> Expression {
+        if from == to {
+            return expr;
+        }
+        Expression::Cast {
+            expr: Box::new(expr),
+            data_type: to.clone(),
+        }
+    }
+}
+
+impl<'a> ExprRewriter for TypeCoercer<'a> {
+    fn mutate(&mut self, expr: Expression) -> Result<Expression> {
+        Ok(match expr {
+            Expression::BinaryExpression { op, left, right } => {
+                let mut args = self.coerce_args(vec![*left, *right])?;
+                let right = args.pop().unwrap();
+                let left = args.pop().unwrap();
+                Expression::BinaryExpression {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+            Expression::ScalarFunction { op, args } => Expression::ScalarFunction {
+                op,
+                args: self.coerce_args(args)?,
+            },
+            other => other,
+        })
+    }
+}
+
+impl Expression {
+    /// Insert the implicit casts to the binary expressions and scalar functions,
+    /// the numeric operands of different types are cast to their common type.
+    pub fn coerce(&self, schema: &DataSchemaRef) -> Result<Expression> {
+        let mut coercer = TypeCoercer { schema };
+        self.clone().rewrite(&mut coercer)
+    }
+}
+
+struct AliasRemover;
+
+impl ExprRewriter for AliasRemover {
+    fn mutate(&mut self, expr: Expression) -> Result<Expression> {
+        Ok(match expr {
+            Expression::Alias(_, expr) => *expr,
+            other => other,
+        })
+    }
+}
+
+struct ExpressionReplacer<'a> {
+    // Without aliases.
+    target: Expression,
+    replacement: &'a Expression,
+}
+
+impl<'a> ExprRewriter for ExpressionReplacer<'a> {
+    fn mutate(&mut self, expr: Expression) -> Result<Expression> {
+        // The alias is kept, its child is replaced if matched.
+        if let Expression::Alias(_, _) = expr {
+            return Ok(expr);
+        }
+
+        if expr.clone().rewrite(&mut AliasRemover)? == self.target {
+            return Ok(self.replacement.clone());
+        }
+        Ok(expr)
+    }
+}
+
+impl Expression {
+    /// Replace the sub expressions which are equal to the `target` with the `replacement`,
+    /// the aliases are ignored by the comparison, e.g. (a as x) + b matches a + b.
+    /// The aliases over the replaced expressions are kept.
+    pub fn replace(&self, target: &Expression, replacement: &Expression) -> Expression {
+        let replaced = target
+            .clone()
+            .rewrite(&mut AliasRemover)
+            .and_then(|target| {
+                let mut replacer = ExpressionReplacer {
+                    target,
+                    replacement,
+                };
+                self.clone().rewrite(&mut replacer)
+            });
+
+        match replaced {
+            Ok(expr) => expr,
+            // The replacer never fails.
+            Err(_) => self.clone(),
+        }
+    }
+}
+
+struct Normalizer;
+
+impl Normalizer {
+    fn is_commutative(op: &str) -> bool {
+        matches!(op.to_lowercase().as_str(), "+" | "*" | "and" | "or")
+    }
+
+    // Flatten the chain of the same operator, e.g. (a and b) and c is [a, b, c].
+    fn flatten(op: &str, expr: Expression, operands: &mut Vec<Expression>) {
+        match expr {
+            Expression::BinaryExpression {
+                op: child_op,
+                left,
+                right,
+            } if child_op.eq_ignore_ascii_case(op) => {
+                Self::flatten(op, *left, operands);
+                Self::flatten(op, *right, operands);
+            }
+            other => operands.push(other),
+        }
+    }
+}
+
+impl ExprRewriter for Normalizer {
+    fn mutate(&mut self, expr: Expression) -> Result<Expression> {
+        Ok(match expr {
+            Expression::BinaryExpression { op, left, right } if Self::is_commutative(&op) => {
+                let mut operands = vec![];
+                Self::flatten(&op, *left, &mut operands);
+                Self::flatten(&op, *right, &mut operands);
+                // The Debug format is the stable sort key.
+                operands.sort_by_cached_key(|operand| format!("{:?}", operand));
+
+                let mut operands = operands.into_iter();
+                let first = operands.next().unwrap();
+                operands.fold(first, |left, right| Expression::BinaryExpression {
+                    op: op.clone(),
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            }
+            other => other,
+        })
+    }
+}
+
+impl Expression {
+    /// Canonicalize the commutative binary expressions(+, *, and, or) for comparing:
+    /// the chains of the same operator are flattened and their operands are sorted,
+    /// e.g. b + (c + a) is normalized to (a + b) + c. Other expressions are untouched.
+    pub fn normalize(&self) -> Expression {
+        match self.clone().rewrite(&mut Normalizer) {
+            Ok(expr) => expr,
+            // The normalizer never fails.
+            Err(_) => self.clone(),
+        }
+    }
+}
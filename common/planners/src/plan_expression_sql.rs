@@ -0,0 +1,112 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataValue;
+
+use crate::Expression;
+
+impl Expression {
+    /// Render the expression as SQL, e.g. the string literals are quoted and
+    /// the sort expressions have their direction, it's used by EXPLAIN.
+    /// Unlike the Debug format, it's not used as the column name.
+    pub fn to_sql(&self) -> String {
+        match self {
+            Expression::Alias(alias, expr) => format!("{} AS {}", expr.to_sql(), alias),
+            Expression::Column(name) => name.clone(),
+            Expression::Literal(value) => literal_to_sql(value),
+            Expression::UnaryExpression { op, expr } => {
+                format!("({} {})", op_to_sql(op), expr.to_sql())
+            }
+            Expression::BinaryExpression { op, left, right } => {
+                format!("({} {} {})", left.to_sql(), op_to_sql(op), right.to_sql())
+            }
+            Expression::ScalarFunction { op, args }
+            | Expression::AggregateFunction { op, args } => {
+                format!("{}({})", op, join_to_sql(args))
+            }
+            Expression::Sort {
+                expr,
+                asc,
+                nulls_first,
+            } => format!(
+                "{} {} NULLS {}",
+                expr.to_sql(),
+                if *asc { "ASC" } else { "DESC" },
+                if *nulls_first { "FIRST" } else { "LAST" }
+            ),
+            Expression::Wildcard => "*".to_string(),
+            Expression::QualifiedWildcard(qualifier) => format!("{}.*", qualifier.join(".")),
+            Expression::Cast { expr, data_type } => {
+                format!("CAST({} AS {:?})", expr.to_sql(), data_type)
+            }
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => format!(
+                "CASE WHEN {} THEN {} ELSE {} END",
+                condition.to_sql(),
+                then.to_sql(),
+                otherwise.to_sql()
+            ),
+            Expression::Extract { field, expr } => format!(
+                "EXTRACT({} FROM {})",
+                field.to_string().to_uppercase(),
+                expr.to_sql()
+            ),
+            Expression::WindowFunction {
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame,
+            } => {
+                let mut window = vec![];
+                if !partition_by.is_empty() {
+                    window.push(format!("PARTITION BY {}", join_to_sql(partition_by)));
+                }
+                if !order_by.is_empty() {
+                    window.push(format!("ORDER BY {}", join_to_sql(order_by)));
+                }
+                if let Some(frame) = frame {
+                    window.push(format!("{}", frame));
+                }
+                format!(
+                    "{}({}) OVER ({})",
+                    func,
+                    join_to_sql(args),
+                    window.join(" ")
+                )
+            }
+        }
+    }
+}
+
+fn join_to_sql(exprs: &[Expression]) -> String {
+    exprs
+        .iter()
+        .map(|expr| expr.to_sql())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// The keyword operators are upper case, e.g. and, not, like.
+fn op_to_sql(op: &str) -> String {
+    if op.chars().all(|c| c.is_ascii_alphabetic() || c == ' ') {
+        op.to_uppercase()
+    } else {
+        op.to_string()
+    }
+}
+
+fn literal_to_sql(value: &DataValue) -> String {
+    if matches!(value, DataValue::Null) || value.is_null() {
+        return "NULL".to_string();
+    }
+
+    match value {
+        DataValue::Utf8(Some(v)) => format!("'{}'", v.replace('\'', "''")),
+        other => format!("{}", other),
+    }
+}
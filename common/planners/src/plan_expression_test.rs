@@ -109,3 +109,623 @@ fn test_expression_validate() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_if_expression() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Boolean, false),
+        DataField::new("b", DataType::Int32, false),
+        DataField::new("c", DataType::Int64, false),
+        DataField::new("d", DataType::Utf8, false),
+    ]);
+
+    // Common type resolution.
+    let expr = Expression::If {
+        condition: Box::new(col("a")),
+        then: Box::new(col("b")),
+        otherwise: Box::new(col("c")),
+    };
+    assert_eq!("if(a, b, c)", format!("{:?}", expr));
+    assert_eq!(DataType::Int64, expr.to_data_type(&schema)?);
+    assert!(!expr.nullable(&schema)?);
+
+    // Condition must be boolean.
+    let expr = Expression::If {
+        condition: Box::new(col("b")),
+        then: Box::new(col("b")),
+        otherwise: Box::new(col("c")),
+    };
+    let actual = expr.to_data_type(&schema);
+    assert_eq!(
+        "Code: 7, displayText = The condition of if must be Boolean, but got Int32.",
+        format!("{}", actual.unwrap_err())
+    );
+
+    // No common type.
+    let expr = Expression::If {
+        condition: Box::new(col("a")),
+        then: Box::new(col("b")),
+        otherwise: Box::new(col("d")),
+    };
+    let actual = expr.to_data_type(&schema);
+    assert_eq!(
+        "Code: 7, displayText = Can not find the common type of if branches: Int32 and Utf8.",
+        format!("{}", actual.unwrap_err())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_literal_expression() -> anyhow::Result<()> {
+    use std::convert::TryFrom;
+
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![]);
+
+    // INTERVAL '1' MONTH
+    let month = Expression::Literal(DataValue::IntervalYearMonth(Some(1)));
+    assert_eq!(
+        "Interval(YearMonth)",
+        format!("{:?}", month.to_data_type(&schema)?)
+    );
+
+    // INTERVAL '1' DAY, the high 32 bits are days and the low 32 bits are milliseconds.
+    let day = Expression::Literal(DataValue::IntervalDayTime(Some(1 << 32)));
+    let day_type = day.to_data_type(&schema)?;
+    assert_eq!("Interval(DayTime)", format!("{:?}", day_type));
+
+    for expr in vec![month, day] {
+        let json = serde_json::to_string(&expr)?;
+        let actual: Expression = serde_json::from_str(&json)?;
+        assert_eq!(expr, actual);
+    }
+
+    // Null interval value from data type.
+    let null_value = DataValue::try_from(&day_type)?;
+    assert_eq!(DataValue::IntervalDayTime(None), null_value);
+    assert!(null_value.is_null());
+
+    Ok(())
+}
+
+#[test]
+fn test_extract_expression() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Date32, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    let expr = Expression::Extract {
+        field: DatePart::Year,
+        expr: Box::new(col("a")),
+    };
+    assert_eq!("extract(year from a)", format!("{:?}", expr));
+
+    // Return type of each date part.
+    let parts = vec![
+        (DatePart::Year, DataType::UInt16),
+        (DatePart::Month, DataType::UInt8),
+        (DatePart::Day, DataType::UInt8),
+        (DatePart::Hour, DataType::UInt8),
+        (DatePart::Minute, DataType::UInt8),
+        (DatePart::Second, DataType::UInt8),
+        (DatePart::DayOfWeek, DataType::UInt8),
+        (DatePart::DayOfYear, DataType::UInt16),
+    ];
+    for (field, expect) in parts {
+        let expr = Expression::Extract {
+            field,
+            expr: Box::new(col("a")),
+        };
+        assert_eq!(expect, expr.to_data_type(&schema)?, "{}", field);
+    }
+
+    // Non-temporal input.
+    let expr = Expression::Extract {
+        field: DatePart::Month,
+        expr: Box::new(col("b")),
+    };
+    let actual = expr.to_data_type(&schema);
+    assert_eq!(
+        "Code: 7, displayText = Extract month expects a temporal argument, but got Utf8.",
+        format!("{}", actual.unwrap_err())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_qualified_wildcard_expression() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Utf8, false)]);
+
+    let expr = Expression::QualifiedWildcard(vec!["db".to_string(), "t".to_string()]);
+    assert_eq!("db.t.*", format!("{:?}", expr));
+
+    let json = serde_json::to_string(&expr)?;
+    let actual: Expression = serde_json::from_str(&json)?;
+    assert_eq!(expr, actual);
+
+    let wildcard_error = Expression::Wildcard.to_data_type(&schema).unwrap_err();
+    let qualified_error = expr.to_data_type(&schema).unwrap_err();
+    assert_eq!(wildcard_error.code(), qualified_error.code());
+    assert_eq!(wildcard_error.message(), qualified_error.message());
+
+    Ok(())
+}
+
+#[test]
+fn test_window_function_expression() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+
+    // row_number() OVER (PARTITION BY b ORDER BY a)
+    let expr = Expression::WindowFunction {
+        func: "row_number".to_string(),
+        args: vec![],
+        partition_by: vec![col("b")],
+        order_by: vec![sort("a", true, false)],
+        frame: None,
+    };
+    assert_eq!(
+        "row_number() OVER (PARTITION BY b ORDER BY a)",
+        format!("{:?}", expr)
+    );
+    assert_eq!(DataType::UInt64, expr.to_data_type(&schema)?);
+
+    let json = serde_json::to_string(&expr)?;
+    let actual: Expression = serde_json::from_str(&json)?;
+    assert_eq!(expr, actual);
+
+    // sum(a) OVER (ORDER BY a ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)
+    let expr = Expression::WindowFunction {
+        func: "sum".to_string(),
+        args: vec![col("a")],
+        partition_by: vec![],
+        order_by: vec![sort("a", true, false)],
+        frame: Some(WindowFrame {
+            units: WindowFrameUnits::Rows,
+            start_bound: WindowFrameBound::Preceding(None),
+            end_bound: WindowFrameBound::CurrentRow,
+        }),
+    };
+    assert_eq!(
+        "sum(a) OVER (ORDER BY a ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+        format!("{:?}", expr)
+    );
+    assert_eq!(DataType::Int64, expr.to_data_type(&schema)?);
+
+    let json = serde_json::to_string(&expr)?;
+    let actual: Expression = serde_json::from_str(&json)?;
+    assert_eq!(expr, actual);
+
+    Ok(())
+}
+
+#[test]
+fn test_null_literal_binary_expression() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let null = Expression::Literal(DataValue::Null);
+
+    // a + NULL resolves the same as a + a.
+    let expr = add(col("a"), null.clone());
+    let expect = add(col("a"), col("a")).to_data_type(&schema)?;
+    assert_eq!(expect, expr.to_data_type(&schema)?);
+    assert!(expr.nullable(&schema)?);
+
+    // NULL + a
+    let expr = add(null.clone(), col("a"));
+    assert_eq!(expect, expr.to_data_type(&schema)?);
+    assert!(expr.nullable(&schema)?);
+
+    // NULL + NULL
+    let expr = add(null.clone(), null);
+    assert_eq!(DataType::Null, expr.to_data_type(&schema)?);
+    assert!(expr.nullable(&schema)?);
+
+    // a + a
+    let expr = add(col("a"), col("a"));
+    assert!(!expr.nullable(&schema)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_rename_columns() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    let mut mapping = HashMap::new();
+    mapping.insert("a".to_string(), "x".to_string());
+    mapping.insert("b".to_string(), "y".to_string());
+
+    // (not (a + 1) > t.b) as b, c is unmapped.
+    let expr = Expression::Alias(
+        "b".to_string(),
+        Box::new(Expression::ScalarFunction {
+            op: "and".to_string(),
+            args: vec![
+                not(add(col("a"), lit(1)).gt(col("t.b"))),
+                col("c").eq(col("a")),
+            ],
+        }),
+    );
+    let actual = expr.rename_columns(&mapping);
+    assert_eq!(
+        "and((not ((x + 1) > t.y)), (c = x)) as y",
+        format!("{:?}", actual)
+    );
+
+    // Unmapped columns are untouched.
+    let expr = add(col("c"), col("d"));
+    assert_eq!(expr, expr.rename_columns(&mapping));
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_replace() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let target = add(col("a"), col("b"));
+
+    // (a + b) * 2 > (a + b) and c = (a + b + c)
+    let expr = Expression::ScalarFunction {
+        op: "and".to_string(),
+        args: vec![
+            Expression::BinaryExpression {
+                op: "*".to_string(),
+                left: Box::new(target.clone()),
+                right: Box::new(lit(2)),
+            }
+            .gt(target.clone()),
+            col("c").eq(add(target.clone(), col("c"))),
+        ],
+    };
+    let actual = expr.replace(&target, &col("s"));
+    assert_eq!("and(((s * 2) > s), (c = (s + c)))", format!("{:?}", actual));
+
+    // The aliases are ignored by the comparison and kept.
+    let expr = add(col("a").alias("x"), col("b")).alias("y");
+    assert_eq!("s as y", format!("{:?}", expr.replace(&target, &col("s"))));
+
+    // The unmatched expressions are untouched: b + a, a - b and a + c.
+    let expr = Expression::ScalarFunction {
+        op: "and".to_string(),
+        args: vec![
+            add(col("b"), col("a")).eq(col("c")),
+            Expression::BinaryExpression {
+                op: "-".to_string(),
+                left: Box::new(col("a")),
+                right: Box::new(col("b")),
+            }
+            .eq(add(col("a"), col("c"))),
+        ],
+    };
+    assert_eq!(expr, expr.replace(&target, &col("s")));
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_normalize() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    // Commutative.
+    assert_eq!(
+        add(col("b"), col("a")).normalize(),
+        add(col("a"), col("b")).normalize()
+    );
+    assert_eq!(add(col("a"), col("b")), add(col("b"), col("a")).normalize());
+
+    // The chains are flattened.
+    let expr = col("c").and(col("b").and(col("a")));
+    assert_eq!(col("a").and(col("b")).and(col("c")), expr.normalize());
+
+    // The operands are normalized, the sub chain of another operator is kept.
+    let expr = add(col("b"), col("a")).and(col("c").eq(lit(1)));
+    assert_eq!(
+        add(col("a"), col("b")).and(col("c").eq(lit(1))),
+        expr.normalize()
+    );
+
+    // Non-commutative.
+    let expr = Expression::BinaryExpression {
+        op: "-".to_string(),
+        left: Box::new(col("b")),
+        right: Box::new(col("a")),
+    };
+    assert_eq!(expr, expr.normalize());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_coerce() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("i", DataType::Int32, false),
+        DataField::new("f", DataType::Float64, false),
+        DataField::new("s", DataType::Utf8, false),
+    ]);
+
+    // The int operand is cast.
+    let expr = add(col("i"), col("f")).coerce(&schema)?;
+    assert_eq!("(cast(i as Float64) + f)", format!("{:?}", expr));
+    assert_eq!(DataType::Float64, expr.to_data_type(&schema)?);
+
+    // Nested.
+    let expr = add(col("i"), col("f")).gt(col("i")).coerce(&schema)?;
+    assert_eq!(
+        "((cast(i as Float64) + f) > cast(i as Float64))",
+        format!("{:?}", expr)
+    );
+
+    // Same types and non-numeric operands are untouched.
+    let expr = add(col("f"), col("f"));
+    assert_eq!(expr, expr.coerce(&schema)?);
+    let expr = col("s").eq(col("s"));
+    assert_eq!(expr, expr.coerce(&schema)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_find_aggregates() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    // sum(a) + count(b)
+    let count = Expression::AggregateFunction {
+        op: "count".to_string(),
+        args: vec![col("b")],
+    };
+    let expr = add(sum(col("a")), count.clone()).alias("c");
+    assert_eq!(vec![sum(col("a")), count], expr.find_aggregates());
+
+    // Nested inside scalar functions, duplicates omitted.
+    let expr = not(sum(col("a")).gt(sum(col("a"))));
+    assert_eq!(vec![sum(col("a"))], expr.find_aggregates());
+
+    // Pure scalar expression.
+    let expr = add(col("a"), lit(1)).gt(col("b"));
+    assert_eq!(0, expr.find_aggregates().len());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_contains_aggregate() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(true, sum(col("a")).contains_aggregate());
+    assert_eq!(true, add(sum(col("a")), lit(1)).contains_aggregate());
+    assert_eq!(false, add(col("a"), lit(1)).contains_aggregate());
+
+    // Nested in the aliases, scalar functions and casts.
+    assert_eq!(true, sum(col("a")).alias("s").contains_aggregate());
+    assert_eq!(true, not(avg(col("a")).gt(lit(1))).contains_aggregate());
+    let expr = Expression::Cast {
+        expr: Box::new(add(lit(1), sum(col("a")))),
+        data_type: DataType::Int64,
+    };
+    assert_eq!(true, expr.contains_aggregate());
+    assert_eq!(false, col("a").alias("s").contains_aggregate());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_is_deterministic() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let rand = Expression::ScalarFunction {
+        op: "rand".to_string(),
+        args: vec![],
+    };
+
+    // a + 1
+    assert_eq!(true, add(col("a"), lit(1)).is_deterministic());
+    assert_eq!(true, sum(col("a")).alias("s").is_deterministic());
+
+    // rand() nested in the binary expression, alias and cast.
+    assert_eq!(false, rand.is_deterministic());
+    let expr = Expression::Cast {
+        expr: Box::new(add(col("a"), rand.clone())),
+        data_type: DataType::Int64,
+    };
+    assert_eq!(false, expr.alias("r").is_deterministic());
+    assert_eq!(false, not(col("a").gt(rand.clone())).is_deterministic());
+
+    // rand() followed by a deterministic sibling: rand() + (a + 1)
+    let expr = add(rand.clone(), add(col("a"), lit(1)));
+    assert_eq!(false, expr.is_deterministic());
+    let expr = add(add(col("a"), lit(1)), rand);
+    assert_eq!(false, expr.is_deterministic());
+
+    // Unknown function.
+    let expr = Expression::ScalarFunction {
+        op: "not_exists_function".to_string(),
+        args: vec![],
+    };
+    assert_eq!(false, expr.is_deterministic());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_as_column_ref() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(Some("a"), col("a").as_column_ref());
+    assert_eq!(Some("a"), col("a").alias("b").as_column_ref());
+
+    // Only a single alias is unwrapped.
+    assert_eq!(None, col("a").alias("b").alias("c").as_column_ref());
+    assert_eq!(None, add(col("a"), lit(1)).as_column_ref());
+    assert_eq!(None, sum(col("a")).alias("s").as_column_ref());
+    assert_eq!(None, lit(1).as_column_ref());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_to_sql() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!("a", col("a").to_sql());
+    assert_eq!("(a + 1) AS b", add(col("a"), lit(1)).alias("b").to_sql());
+    assert_eq!("'it''s'", lit("it's").to_sql());
+    assert_eq!("NULL", Expression::Literal(DataValue::Null).to_sql());
+    assert_eq!("(NOT (a = 'x'))", not(col("a").eq(lit("x"))).to_sql());
+    assert_eq!("sum(a)", sum(col("a")).to_sql());
+    assert_eq!("a DESC NULLS FIRST", sort("a", false, true).to_sql());
+    assert_eq!(
+        "CAST(a AS Int64)",
+        Expression::Cast {
+            expr: Box::new(col("a")),
+            data_type: DataType::Int64,
+        }
+        .to_sql()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_aggregate_function_args() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let expr = sum(add(col("a"), col("b")));
+    assert_eq!(vec!["(a + b)"], expr.to_aggregate_function_args()?);
+    // The name is the column name of the argument, which the input column has.
+    assert_eq!(
+        vec![add(col("a"), col("b")).column_name()],
+        expr.to_aggregate_function_args()?
+    );
+
+    // The same argument twice shares the column.
+    let expr = Expression::AggregateFunction {
+        op: "argMin".to_string(),
+        args: vec![col("a"), col("a")],
+    };
+    assert_eq!(vec!["a", "a"], expr.to_aggregate_function_args()?);
+
+    // The string literal 'a' collides with the column a.
+    let expr = Expression::AggregateFunction {
+        op: "argMin".to_string(),
+        args: vec![col("a"), lit("a")],
+    };
+    assert_eq!(
+        "Code: 26, displayText = The different arguments of aggregate function argMin have the same column name 'a'.",
+        expr.to_aggregate_function_args().unwrap_err().to_string()
+    );
+
+    // Not an aggregate function.
+    assert!(col("a").to_aggregate_function_args().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_plan_validate() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .expression(&[add(col("number"), lit(1u64)).alias("c")], "")?
+        .build()?;
+
+    match plan {
+        PlanNode::Expression(plan) => {
+            plan.validate()?;
+
+            // The field "c" with a wrong data type.
+            let mut fields = plan.schema.fields().clone();
+            let last = fields.len() - 1;
+            fields[last] = DataField::new("c", DataType::Utf8, false);
+            let wrong = ExpressionPlan {
+                schema: DataSchemaRefExt::create(fields),
+                ..plan
+            };
+            let actual = wrong.validate();
+            assert_eq!(true, actual.is_err());
+            assert_eq!(15, actual.unwrap_err().code());
+        }
+        _ => panic!("Must be expression plan"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_column_expression_ignore_case() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("col", DataType::UInt64, false),
+        DataField::new("a", DataType::Utf8, false),
+        DataField::new("A", DataType::Utf8, false),
+    ]);
+
+    assert_eq!(DataType::UInt64, col("col").to_data_type(&schema)?);
+    assert_eq!(DataType::UInt64, col("COL").to_data_type(&schema)?);
+    assert_eq!(DataType::Utf8, col("A").to_data_type(&schema)?);
+
+    let result = col("b").to_data_type(&schema);
+    assert_eq!(true, result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_evaluate_constant() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let multiply = |left: Expression, right: Expression| Expression::BinaryExpression {
+        op: "*".to_string(),
+        left: Box::new(left),
+        right: Box::new(right),
+    };
+
+    // 2 * (3 + 4)
+    let expr = multiply(lit(2i64), add(lit(3i64), lit(4i64)));
+    assert_eq!(DataValue::Int64(Some(14)), expr.evaluate_constant()?);
+    assert_eq!(
+        DataValue::Int64(Some(14)),
+        expr.alias("x").evaluate_constant()?
+    );
+
+    // Column reference.
+    let expr = multiply(lit(2i64), add(col("a"), lit(4i64)));
+    assert_eq!(
+        "Code: 6, displayText = Constant expression can not reference the column 'a'.",
+        expr.evaluate_constant().unwrap_err().to_string()
+    );
+
+    // Non-deterministic function.
+    let expr = add(lit(1i64), Expression::ScalarFunction {
+        op: "rand".to_string(),
+        args: vec![],
+    });
+    assert_eq!(
+        "Code: 6, displayText = Constant expression can not have the non-deterministic function 'rand'.",
+        expr.evaluate_constant().unwrap_err().to_string()
+    );
+
+    Ok(())
+}
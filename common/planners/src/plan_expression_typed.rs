@@ -0,0 +1,57 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::Result;
+
+use crate::Expression;
+
+/// An expression annotated with the resolved data type of each node.
+/// The types are resolved once bottom-up, so getting them afterwards is O(1)
+/// instead of re-walking the subtree like `Expression::to_data_type`.
+/// Each node borrows its expression node, the tree is typed in O(n) without copying.
+#[derive(Clone, Debug)]
+pub struct TypedExpression<'a> {
+    expr: &'a Expression,
+    data_type: DataType,
+    // Typed sub expressions which the data type is derived from.
+    children: Vec<TypedExpression<'a>>,
+}
+
+impl<'a> TypedExpression<'a> {
+    pub fn expr(&self) -> &'a Expression {
+        self.expr
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn children(&self) -> &[TypedExpression<'a>] {
+        &self.children
+    }
+}
+
+impl Expression {
+    /// Resolve the data types of the expression tree once, see `TypedExpression`.
+    pub fn typed(&self, input_schema: &DataSchemaRef) -> Result<TypedExpression<'_>> {
+        let mut children = vec![];
+        for child in self.data_type_children() {
+            children.push(child.typed(input_schema)?);
+        }
+
+        let children_types = children
+            .iter()
+            .map(|child| child.data_type.clone())
+            .collect::<Vec<_>>();
+        let data_type = self.to_data_type_with_children(input_schema, children_types)?;
+
+        Ok(TypedExpression {
+            expr: self,
+            data_type,
+            children,
+        })
+    }
+}
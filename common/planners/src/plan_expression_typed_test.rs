@@ -0,0 +1,75 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_expression_typed() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::Float64, false),
+        DataField::new("c", DataType::Boolean, false),
+    ]);
+
+    // The cached types of every node match the uncached ones.
+    fn check(typed: &TypedExpression, schema: &DataSchemaRef) -> anyhow::Result<()> {
+        assert_eq!(&typed.expr().to_data_type(schema)?, typed.data_type());
+        for child in typed.children() {
+            check(child, schema)?;
+        }
+        Ok(())
+    }
+
+    let exprs = vec![
+        col("a"),
+        lit(1u8),
+        add(col("a"), col("b")).gt(lit(1)),
+        Expression::Alias(
+            "x".to_string(),
+            Box::new(add(col("a"), Expression::Literal(DataValue::Null))),
+        ),
+        Expression::If {
+            condition: Box::new(col("c")),
+            then: Box::new(col("a")),
+            otherwise: Box::new(col("b")),
+        },
+        Expression::Cast {
+            expr: Box::new(col("a")),
+            data_type: DataType::Utf8,
+        },
+        sum(col("a")),
+    ];
+    for expr in exprs {
+        let typed = expr.typed(&schema)?;
+        assert_eq!(&expr, typed.expr());
+        check(&typed, &schema)?;
+    }
+
+    // A deep expression.
+    let mut expr = col("a");
+    for i in 0..64 {
+        expr = add(expr, lit(i as i64));
+    }
+    let typed = expr.typed(&schema)?;
+    assert_eq!(&expr.to_data_type(&schema)?, typed.data_type());
+    check(&typed, &schema)?;
+
+    // The nodes borrow the expression instead of copying the subtrees.
+    assert!(std::ptr::eq(&expr, typed.expr()));
+    if let Expression::BinaryExpression { left, .. } = &expr {
+        assert!(std::ptr::eq(left.as_ref(), typed.children()[0].expr()));
+    }
+
+    // Errors are the same.
+    let expr = add(col("not_exists"), lit(1));
+    assert_eq!(
+        expr.to_data_type(&schema).err().unwrap().to_string(),
+        expr.typed(&schema).err().unwrap().to_string()
+    );
+
+    Ok(())
+}
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use common_exception::Result;
+use common_functions::FunctionFactory;
 
 use crate::Expression;
 
@@ -69,6 +70,7 @@ impl Expression {
         // recurse (and cover all expression types)
         let visitor = match self {
             Expression::Alias(_, expr) => expr.accept(visitor),
+            Expression::UnaryExpression { expr, .. } => expr.accept(visitor),
             Expression::BinaryExpression { left, right, .. } => {
                 let mut visitor = visitor;
                 visitor = left.accept(visitor)?;
@@ -91,6 +93,30 @@ impl Expression {
             }
             Expression::Cast { expr, .. } => expr.accept(visitor),
             Expression::Sort { expr, .. } => expr.accept(visitor),
+            Expression::Extract { expr, .. } => expr.accept(visitor),
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut visitor = visitor;
+                for expr in args.iter().chain(partition_by).chain(order_by) {
+                    visitor = expr.accept(visitor)?;
+                }
+                Ok(visitor)
+            }
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                let mut visitor = visitor;
+                visitor = condition.accept(visitor)?;
+                visitor = then.accept(visitor)?;
+                visitor = otherwise.accept(visitor)?;
+                Ok(visitor)
+            }
 
             _ => Ok(visitor),
         }?;
@@ -98,3 +124,110 @@ impl Expression {
         visitor.post_visit(self)
     }
 }
+
+// Visitor that collects the aggregate functions, without descending into them.
+struct AggregatesFinder {
+    aggregates: Vec<Expression>,
+}
+
+impl ExpressionVisitor for AggregatesFinder {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        match expr {
+            Expression::AggregateFunction { .. } => {
+                if !self.aggregates.contains(expr) {
+                    self.aggregates.push(expr.clone());
+                }
+                Ok(Recursion::Stop(self))
+            }
+            _ => Ok(Recursion::Continue(self)),
+        }
+    }
+}
+
+impl Expression {
+    /// Collect all the nested `Expression::AggregateFunction` in order of occurrence (depth first),
+    /// with duplicates omitted. The arguments of an aggregate function are not searched.
+    pub fn find_aggregates(&self) -> Vec<Expression> {
+        match self.accept(AggregatesFinder { aggregates: vec![] }) {
+            Ok(finder) => finder.aggregates,
+            // pre_visit never returns an error.
+            Err(_) => vec![],
+        }
+    }
+}
+
+// Visitor that checks whether any aggregate function appears, found is never reset.
+struct AggregateChecker {
+    found: bool,
+}
+
+impl ExpressionVisitor for AggregateChecker {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        if let Expression::AggregateFunction { .. } = expr {
+            self.found = true;
+        }
+        match self.found {
+            true => Ok(Recursion::Stop(self)),
+            false => Ok(Recursion::Continue(self)),
+        }
+    }
+}
+
+impl Expression {
+    /// Whether any `Expression::AggregateFunction` is nested in the expression(including itself),
+    /// e.g. sum(a) + 1. Cheaper than `find_aggregates` as nothing is collected.
+    pub fn contains_aggregate(&self) -> bool {
+        match self.accept(AggregateChecker { found: false }) {
+            Ok(checker) => checker.found,
+            // pre_visit never returns an error.
+            Err(_) => false,
+        }
+    }
+}
+
+// Visitor that stops at the first non-deterministic function.
+struct DeterminismChecker {
+    deterministic: bool,
+}
+
+impl ExpressionVisitor for DeterminismChecker {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        // Once non-deterministic, the siblings visited later must not reset it.
+        if !self.deterministic {
+            return Ok(Recursion::Stop(self));
+        }
+
+        let op = match expr {
+            Expression::ScalarFunction { op, .. }
+            | Expression::UnaryExpression { op, .. }
+            | Expression::BinaryExpression { op, .. } => op,
+            _ => return Ok(Recursion::Continue(self)),
+        };
+
+        // The unknown functions are taken as non-deterministic.
+        self.deterministic = match FunctionFactory::get_cached(op) {
+            Ok(func) => func.is_deterministic(),
+            Err(_) => false,
+        };
+        match self.deterministic {
+            true => Ok(Recursion::Continue(self)),
+            false => Ok(Recursion::Stop(self)),
+        }
+    }
+}
+
+impl Expression {
+    /// Whether the expression always evaluates to the same result for the same input,
+    /// false if any of its functions is non-deterministic, e.g. rand().
+    /// The aliases and casts are transparent.
+    pub fn is_deterministic(&self) -> bool {
+        let checker = DeterminismChecker {
+            deterministic: true,
+        };
+        match self.accept(checker) {
+            Ok(checker) => checker.deterministic,
+            // pre_visit never returns an error.
+            Err(_) => false,
+        }
+    }
+}
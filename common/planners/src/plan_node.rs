@@ -162,4 +162,18 @@ impl PlanNode {
 
         Ok(())
     }
+
+    /// Returns a clone of this plan with its single input replaced by the new input.
+    pub fn replace_input(&self, new_input: PlanNode) -> Result<PlanNode> {
+        if self.inputs().is_empty() {
+            return Result::Err(ErrorCodes::BadPlanInputs(format!(
+                "{} has no input to replace",
+                self.name()
+            )));
+        }
+
+        let mut node = self.clone();
+        node.set_inputs(vec![&new_input])?;
+        Ok(node)
+    }
 }
@@ -0,0 +1,54 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::test::Test;
+use crate::*;
+
+#[test]
+fn test_plan_node_replace_input() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let new_source = Test::create().generate_source_plan_for_test(100)?;
+
+    // Expression plan.
+    {
+        let plan = PlanBuilder::from(&source)
+            .expression(&[col("number")], "")?
+            .build()?;
+        let actual = plan.replace_input(new_source.clone())?;
+        assert_eq!(new_source, *actual.input(0));
+        assert_eq!(plan.schema(), actual.schema());
+        // The original plan is untouched.
+        assert_eq!(source, *plan.input(0));
+    }
+
+    // Nested two-level plan: Projection -> Filter -> ReadDataSource.
+    {
+        let plan = PlanBuilder::from(&source)
+            .filter(col("number").eq(lit(1i64)))?
+            .project(&[col("number")])?
+            .build()?;
+        let filter = plan.input(0).replace_input(new_source.clone())?;
+        let actual = plan.replace_input(filter)?;
+
+        let expect = "\
+        Projection: number:UInt64\
+        \n  Filter: (number = 1)\
+        \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800]";
+        assert_eq!(expect, format!("{:?}", actual));
+    }
+
+    // Leaf plan has no input.
+    {
+        let actual = source.replace_input(new_source);
+        assert_eq!(true, actual.is_err());
+        assert_eq!(
+            "Code: 33, displayText = ReadSourcePlan has no input to replace.",
+            format!("{}", actual.unwrap_err())
+        );
+    }
+
+    Ok(())
+}
@@ -8,4 +8,10 @@ pub type Partitions = Vec<Partition>;
 pub struct Partition {
     pub name: String,
     pub version: u64,
+    /// Number of rows in the partition, 0 if the engine doesn't know.
+    #[serde(default)]
+    pub rows: u64,
+    /// Number of bytes in the partition, 0 if the engine doesn't know.
+    #[serde(default)]
+    pub bytes: u64,
 }
@@ -0,0 +1,32 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_partition_serde() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let part = Partition {
+        name: "10-0-10".to_string(),
+        version: 0,
+        rows: 10,
+        bytes: 80,
+    };
+    let json = serde_json::to_string(&part)?;
+    assert_eq!(part, serde_json::from_str::<Partition>(&json)?);
+
+    // Plans without the size fields default them to 0.
+    let old: Partition = serde_json::from_str(r#"{"name":"10-0-10","version":1}"#)?;
+    assert_eq!(
+        Partition {
+            name: "10-0-10".to_string(),
+            version: 1,
+            rows: 0,
+            bytes: 0,
+        },
+        old
+    );
+    Ok(())
+}
@@ -374,9 +374,45 @@ impl RewriteHelper {
                     data_type: data_type.clone(),
                 })
             }
-            Expression::Wildcard | Expression::Literal(_) | Expression::Sort { .. } => {
-                Ok(expr.clone())
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => Ok(Expression::If {
+                condition: Box::new(RewriteHelper::expr_rewrite_alias(condition, data)?),
+                then: Box::new(RewriteHelper::expr_rewrite_alias(then, data)?),
+                otherwise: Box::new(RewriteHelper::expr_rewrite_alias(otherwise, data)?),
+            }),
+            Expression::Extract { field, expr } => Ok(Expression::Extract {
+                field: *field,
+                expr: Box::new(RewriteHelper::expr_rewrite_alias(expr, data)?),
+            }),
+            Expression::WindowFunction {
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame,
+            } => {
+                let mut rewrite_exprs = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|v| RewriteHelper::expr_rewrite_alias(v, data))
+                        .collect::<Result<Vec<_>>>()
+                };
+
+                Ok(Expression::WindowFunction {
+                    func: func.clone(),
+                    args: rewrite_exprs(args)?,
+                    partition_by: rewrite_exprs(partition_by)?,
+                    order_by: rewrite_exprs(order_by)?,
+                    frame: frame.clone(),
+                })
             }
+            Expression::Wildcard
+            | Expression::QualifiedWildcard(_)
+            | Expression::Literal(_)
+            | Expression::Sort { .. } => Ok(expr.clone()),
         }
     }
 
@@ -436,8 +472,30 @@ impl RewriteHelper {
             Expression::ScalarFunction { args, .. } => args.clone(),
             Expression::AggregateFunction { args, .. } => args.clone(),
             Expression::Wildcard => vec![],
+            Expression::QualifiedWildcard(_) => vec![],
             Expression::Sort { expr, .. } => vec![expr.as_ref().clone()],
             Expression::Cast { expr, .. } => vec![expr.as_ref().clone()],
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => vec![
+                condition.as_ref().clone(),
+                then.as_ref().clone(),
+                otherwise.as_ref().clone(),
+            ],
+            Expression::Extract { expr, .. } => vec![expr.as_ref().clone()],
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = args.clone();
+                v.extend_from_slice(partition_by);
+                v.extend_from_slice(order_by);
+                v
+            }
         })
     }
 
@@ -471,8 +529,33 @@ impl RewriteHelper {
                 v
             }
             Expression::Wildcard => vec![],
+            Expression::QualifiedWildcard(_) => vec![],
             Expression::Sort { expr, .. } => Self::expression_plan_columns(expr)?,
             Expression::Cast { expr, .. } => Self::expression_plan_columns(expr)?,
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                let mut v = Self::expression_plan_columns(condition)?;
+                v.append(&mut Self::expression_plan_columns(then)?);
+                v.append(&mut Self::expression_plan_columns(otherwise)?);
+                v
+            }
+            Expression::Extract { expr, .. } => Self::expression_plan_columns(expr)?,
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = vec![];
+                for expr in args.iter().chain(partition_by).chain(order_by) {
+                    let mut col = Self::expression_plan_columns(expr)?;
+                    v.append(&mut col);
+                }
+                v
+            }
         })
     }
 
@@ -531,6 +614,32 @@ impl RewriteHelper {
                 op: op.clone(),
                 args: expressions.to_vec(),
             },
+            Expression::If { .. } => Expression::If {
+                condition: Box::new(expressions[0].clone()),
+                then: Box::new(expressions[1].clone()),
+                otherwise: Box::new(expressions[2].clone()),
+            },
+            Expression::Extract { field, .. } => Expression::Extract {
+                field: *field,
+                expr: Box::new(expressions[0].clone()),
+            },
+            Expression::WindowFunction {
+                func,
+                args,
+                partition_by,
+                frame,
+                ..
+            } => {
+                let partition_start = args.len();
+                let order_start = partition_start + partition_by.len();
+                Expression::WindowFunction {
+                    func: func.clone(),
+                    args: expressions[..partition_start].to_vec(),
+                    partition_by: expressions[partition_start..order_start].to_vec(),
+                    order_by: expressions[order_start..].to_vec(),
+                    frame: frame.clone(),
+                }
+            }
             other => other.clone(),
         }
     }
@@ -2,12 +2,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::collections::HashMap;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct Statistics {
     /// Total rows of the query read.
     pub read_rows: usize,
     /// Total bytes of the query read.
     pub read_bytes: usize,
+    /// Null count of the columns by name, None if the engine doesn't compute it.
+    #[serde(default)]
+    pub null_counts: Option<HashMap<String, u64>>,
 }
 
 impl Statistics {
@@ -15,11 +20,47 @@ impl Statistics {
         Statistics {
             read_rows: 0,
             read_bytes: 0,
+            null_counts: None,
         }
     }
 
     pub fn clear(&mut self) {
         self.read_rows = 0;
         self.read_bytes = 0;
+        self.null_counts = None;
+    }
+
+    /// Returns the statistics with the read rows and bytes of both added up.
+    /// The null counts are added up by column, they are unknown if either side is unknown.
+    pub fn merge(&self, other: &Statistics) -> Statistics {
+        let null_counts = match (&self.null_counts, &other.null_counts) {
+            (Some(lhs), Some(rhs)) => {
+                let mut null_counts = lhs.clone();
+                for (column, count) in rhs {
+                    *null_counts.entry(column.clone()).or_insert(0) += count;
+                }
+                Some(null_counts)
+            }
+            _ => None,
+        };
+
+        Statistics {
+            read_rows: self.read_rows + other.read_rows,
+            read_bytes: self.read_bytes + other.read_bytes,
+            null_counts,
+        }
+    }
+
+    /// Null count of the column, None if it's unknown.
+    pub fn null_count(&self, column: &str) -> Option<u64> {
+        self.null_counts
+            .as_ref()
+            .and_then(|null_counts| null_counts.get(column).copied())
+    }
+
+    /// Sums up the statistics, e.g. of all the partitions of a read plan.
+    pub fn sum<'a>(iter: impl IntoIterator<Item = &'a Statistics>) -> Statistics {
+        iter.into_iter()
+            .fold(Statistics::default(), |acc, stat| acc.merge(stat))
     }
 }
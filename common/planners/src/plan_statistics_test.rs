@@ -0,0 +1,97 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_statistics_merge() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let a = Statistics {
+        read_rows: 10,
+        read_bytes: 80,
+        null_counts: None,
+    };
+    let b = Statistics {
+        read_rows: 5,
+        read_bytes: 40,
+        null_counts: None,
+    };
+
+    let merged = a.merge(&b);
+    assert_eq!(15, merged.read_rows);
+    assert_eq!(120, merged.read_bytes);
+
+    // Merging with default keeps the totals.
+    assert_eq!(a, a.merge(&Statistics::default()));
+    assert_eq!(a, Statistics::default().merge(&a));
+    Ok(())
+}
+
+#[test]
+fn test_statistics_sum() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let stats = vec![
+        Statistics {
+            read_rows: 1,
+            read_bytes: 8,
+            null_counts: None,
+        },
+        Statistics {
+            read_rows: 2,
+            read_bytes: 16,
+            null_counts: None,
+        },
+        Statistics {
+            read_rows: 3,
+            read_bytes: 24,
+            null_counts: None,
+        },
+    ];
+
+    let total = Statistics::sum(&stats);
+    assert_eq!(6, total.read_rows);
+    assert_eq!(48, total.read_bytes);
+
+    // Empty sums to default.
+    let empty: Vec<Statistics> = vec![];
+    assert_eq!(Statistics::default(), Statistics::sum(&empty));
+    Ok(())
+}
+
+#[test]
+fn test_statistics_merge_null_counts() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let a = Statistics {
+        read_rows: 10,
+        read_bytes: 80,
+        null_counts: Some(
+            vec![("x".to_string(), 2), ("y".to_string(), 0)]
+                .into_iter()
+                .collect(),
+        ),
+    };
+    let b = Statistics {
+        read_rows: 5,
+        read_bytes: 40,
+        null_counts: Some(vec![("x".to_string(), 3)].into_iter().collect()),
+    };
+
+    let merged = a.merge(&b);
+    assert_eq!(Some(5), merged.null_count("x"));
+    assert_eq!(Some(0), merged.null_count("y"));
+    assert_eq!(None, merged.null_count("z"));
+
+    // Unknown on either side is unknown.
+    let merged = a.merge(&Statistics::default());
+    assert_eq!(None, merged.null_counts);
+    assert_eq!(None, merged.null_count("x"));
+    Ok(())
+}
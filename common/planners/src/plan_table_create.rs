@@ -3,8 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCodes;
 
 /// Types of files to parse as DataFrames
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -22,7 +24,7 @@ pub enum TableEngineType {
 impl ToString for TableEngineType {
     fn to_string(&self) -> String {
         match self {
-            TableEngineType::JsonEachRaw => "JSON".into(),
+            TableEngineType::JsonEachRaw => "JSONEachRaw".into(),
             TableEngineType::Parquet => "Parquet".into(),
             TableEngineType::Csv => "CSV".into(),
             TableEngineType::Null => "Null".into(),
@@ -30,6 +32,23 @@ impl ToString for TableEngineType {
     }
 }
 
+impl FromStr for TableEngineType {
+    type Err = ErrorCodes;
+
+    fn from_str(engine: &str) -> Result<Self, Self::Err> {
+        match engine {
+            "Parquet" => Ok(TableEngineType::Parquet),
+            "JSONEachRaw" => Ok(TableEngineType::JsonEachRaw),
+            "CSV" => Ok(TableEngineType::Csv),
+            "Null" => Ok(TableEngineType::Null),
+            _ => Err(ErrorCodes::UnknownTableEngine(format!(
+                "Unknown table engine '{}', the supported engines are: Parquet, JSONEachRaw, CSV, Null",
+                engine
+            ))),
+        }
+    }
+}
+
 pub type TableOptions = HashMap<String, String>;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
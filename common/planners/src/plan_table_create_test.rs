@@ -0,0 +1,30 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_table_engine_type_round_trip() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let engines = vec![
+        (TableEngineType::JsonEachRaw, "JSONEachRaw"),
+        (TableEngineType::Parquet, "Parquet"),
+        (TableEngineType::Csv, "CSV"),
+        (TableEngineType::Null, "Null"),
+    ];
+    for (engine, name) in engines {
+        assert_eq!(name, engine.to_string());
+        assert_eq!(engine, TableEngineType::from_str(&engine.to_string())?);
+    }
+
+    let result = TableEngineType::from_str("JSON");
+    assert_eq!(
+        "Code: 40, displayText = Unknown table engine 'JSON', the supported engines are: Parquet, JSONEachRaw, CSV, Null.",
+        result.unwrap_err().to_string()
+    );
+    Ok(())
+}
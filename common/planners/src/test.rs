@@ -30,6 +30,7 @@ impl Test {
         let statistics = Statistics {
             read_rows: total,
             read_bytes: total * 8,
+            null_counts: None,
         };
 
         Ok(PlanNode::ReadSource(ReadDataSourcePlan {
@@ -55,6 +56,8 @@ impl Test {
             partitions.push(Partition {
                 name: format!("{}-{}-{}", total, 0, total,),
                 version: 0,
+                rows: total,
+                bytes: 0,
             })
         } else {
             for part in 0..workers {
@@ -66,6 +69,8 @@ impl Test {
                 partitions.push(Partition {
                     name: format!("{}-{}-{}", total, part_begin, part_end,),
                     version: 0,
+                    rows: part_end - part_begin,
+                    bytes: 0,
                 })
             }
         }
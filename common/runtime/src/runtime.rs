@@ -77,6 +77,22 @@ impl Runtime {
     {
         self.handle.spawn(task)
     }
+
+    /// Runs the future on this runtime and blocks the current thread until it completes.
+    /// The future is not driven by the caller's runtime, so the synchronous code of an
+    /// asynchronous context can wait for it. Must not be called by this runtime's workers.
+    pub fn block_on<T>(&self, task: T) -> Result<T::Output>
+    where
+        T: Future + Send + 'static,
+        T::Output: Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        self.handle.spawn(async move {
+            let _ = tx.send(task.await);
+        });
+        rx.recv()
+            .map_err(|e| ErrorCodes::TokioError(format!("The task is not completed: {}", e)))
+    }
 }
 
 /// Dropping the dropper will cause runtime to shutdown.
@@ -19,3 +19,19 @@ async fn test_runtime() -> anyhow::Result<()> {
     });
     Ok(())
 }
+
+#[tokio::test]
+async fn test_runtime_block_on() -> anyhow::Result<()> {
+    use crate::*;
+
+    // Waited by the synchronous code in an asynchronous context.
+    let runtime = Runtime::with_worker_threads(1)?;
+    let value = runtime.block_on(async { 1 + 1 })?;
+    assert_eq!(2, value);
+
+    // The task is driven by the runtime, not by the caller's.
+    let value = runtime.block_on(async { tokio::spawn(async { 3 }).await.unwrap() })?;
+    assert_eq!(3, value);
+
+    Ok(())
+}
@@ -2,22 +2,43 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+mod stream_collect_test;
+
 #[cfg(test)]
 mod stream_datablock_test;
 
+#[cfg(test)]
+mod stream_filter_test;
+
+#[cfg(test)]
+mod stream_map_test;
+
+#[cfg(test)]
+mod stream_merge_sorted_test;
+
 #[cfg(test)]
 mod stream_progress_test;
 
 mod stream;
+mod stream_collect;
 mod stream_datablock;
+mod stream_filter;
 mod stream_limit;
+mod stream_map;
+mod stream_merge_sorted;
 mod stream_parquet;
 mod stream_progress;
 mod stream_sort;
 
+pub use stream::DataBlockStreamExt;
 pub use stream::SendableDataBlockStream;
+pub use stream_collect::collect_single;
 pub use stream_datablock::DataBlockStream;
+pub use stream_filter::FilterStream;
 pub use stream_limit::LimitStream;
+pub use stream_map::MapStream;
+pub use stream_merge_sorted::MergeSortedStream;
 pub use stream_parquet::ParquetStream;
 pub use stream_progress::ProgressStream;
 pub use stream_sort::SortStream;
@@ -3,7 +3,38 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use common_datablocks::DataBlock;
+use common_datavalues::BooleanArray;
 use common_exception::Result;
 
+use crate::FilterStream;
+use crate::MapStream;
+
 pub type SendableDataBlockStream =
     std::pin::Pin<Box<dyn futures::stream::Stream<Item = Result<DataBlock>> + Sync + Send>>;
+
+/// Combinators over the data block streams.
+pub trait DataBlockStreamExt {
+    /// Transform each block by `f`, the output schema may differ from the input.
+    /// An error from `f` terminates the stream with that error.
+    fn map_blocks<F>(self, f: F) -> SendableDataBlockStream
+    where F: FnMut(DataBlock) -> Result<DataBlock> + Unpin + Sync + Send + 'static;
+
+    /// Keep the rows of each block where the mask from `predicate` is true,
+    /// the blocks which are filtered out entirely are skipped.
+    fn filter_blocks<F>(self, predicate: F) -> SendableDataBlockStream
+    where F: FnMut(&DataBlock) -> Result<BooleanArray> + Unpin + Sync + Send + 'static;
+}
+
+impl<S> DataBlockStreamExt for S
+where S: futures::stream::Stream<Item = Result<DataBlock>> + Sync + Send + 'static
+{
+    fn map_blocks<F>(self, f: F) -> SendableDataBlockStream
+    where F: FnMut(DataBlock) -> Result<DataBlock> + Unpin + Sync + Send + 'static {
+        Box::pin(MapStream::create(Box::pin(self), f))
+    }
+
+    fn filter_blocks<F>(self, predicate: F) -> SendableDataBlockStream
+    where F: FnMut(&DataBlock) -> Result<BooleanArray> + Unpin + Sync + Send + 'static {
+        Box::pin(FilterStream::create(Box::pin(self), predicate))
+    }
+}
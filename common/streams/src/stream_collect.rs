@@ -0,0 +1,25 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Drain the stream into one block, the blocks are concatenated in the stream order
+/// and the schema is the one of the first block.
+/// An empty stream gives an empty block of the `empty_schema`.
+pub async fn collect_single(
+    stream: SendableDataBlockStream,
+    empty_schema: DataSchemaRef,
+) -> Result<DataBlock> {
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    match blocks.len() {
+        0 => Ok(DataBlock::empty_with_schema(empty_schema)),
+        1 => Ok(blocks[0].clone()),
+        _ => DataBlock::concat_blocks(&blocks),
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test]
+async fn test_collect_single() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let block = |a: Vec<i64>, b: Vec<&str>| {
+        DataBlock::create_by_array(schema.clone(), vec![
+            Arc::new(Int64Array::from(a)),
+            Arc::new(StringArray::from(b)),
+        ])
+    };
+
+    // Multiple blocks.
+    let stream = Box::pin(DataBlockStream::create(schema.clone(), None, vec![
+        block(vec![1, 2], vec!["x", "y"]),
+        block(vec![], vec![]),
+        block(vec![3], vec!["z"]),
+    ]));
+    let result = collect_single(stream, schema.clone()).await?;
+    assert_eq!(schema, result.schema().clone());
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | x |",
+        "| 2 | y |",
+        "| 3 | z |",
+        "+---+---+",
+    ];
+    assert_blocks_eq(expected, &[result]);
+
+    // Empty stream.
+    let stream = Box::pin(DataBlockStream::create(schema.clone(), None, vec![]));
+    let result = collect_single(stream, schema.clone()).await?;
+    assert_eq!(schema, result.schema().clone());
+    assert_eq!(2, result.num_columns());
+    assert_eq!(0, result.num_rows());
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::convert::TryInto;
+use std::task::Context;
+use std::task::Poll;
+
+use common_arrow::arrow;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_datablocks::DataBlock;
+use common_datavalues::BooleanArray;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Filter the rows of each block by the mask from the predicate, the empty results are skipped.
+/// The first error from the predicate terminates the stream.
+pub struct FilterStream<F> {
+    input: SendableDataBlockStream,
+    predicate: F,
+    finished: bool,
+}
+
+impl<F> FilterStream<F>
+where F: FnMut(&DataBlock) -> Result<BooleanArray>
+{
+    pub fn create(input: SendableDataBlockStream, predicate: F) -> Self {
+        FilterStream {
+            input,
+            predicate,
+            finished: false,
+        }
+    }
+
+    fn filter(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let mask = (self.predicate)(&block)?;
+        if mask.len() != block.num_rows() {
+            return Result::Err(ErrorCodes::BadArguments(format!(
+                "The filter mask must have {} rows, but got {}",
+                block.num_rows(),
+                mask.len()
+            )));
+        }
+
+        let batch: RecordBatch = block.try_into()?;
+        let batch = arrow::compute::filter_record_batch(&batch, &mask)?;
+        batch.try_into()
+    }
+}
+
+impl<F> Stream for FilterStream<F>
+where F: FnMut(&DataBlock) -> Result<BooleanArray> + Unpin
+{
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.finished {
+                return Poll::Ready(None);
+            }
+
+            match futures::ready!(self.input.poll_next_unpin(ctx)) {
+                Some(Ok(v)) => match self.filter(v) {
+                    Ok(block) if block.num_rows() == 0 => continue,
+                    Ok(block) => return Poll::Ready(Some(Ok(block))),
+                    Err(e) => {
+                        self.finished = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                },
+                other => return Poll::Ready(other),
+            }
+        }
+    }
+}
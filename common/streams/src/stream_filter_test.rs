@@ -0,0 +1,67 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test]
+async fn test_filter_blocks() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Arc::new(Int64Array::from(vec![
+        1, 2, 3, 4,
+    ]))]);
+    let input = || {
+        DataBlockStream::create(schema.clone(), None, vec![
+            block.clone(),
+            block.clone(),
+            block.clone(),
+        ])
+    };
+
+    // All-true mask.
+    {
+        let stream =
+            input().filter_blocks(|block| Ok(BooleanArray::from(vec![true; block.num_rows()])));
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(3, result.len());
+        assert_eq!(12, result.iter().map(|b| b.num_rows()).sum::<usize>());
+    }
+
+    // All-false mask, all the blocks are skipped.
+    {
+        let stream =
+            input().filter_blocks(|block| Ok(BooleanArray::from(vec![false; block.num_rows()])));
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(0, result.len());
+    }
+
+    // Mixed mask, the second block is filtered out entirely.
+    {
+        let mut count = 0;
+        let stream = input().filter_blocks(move |_block| {
+            count += 1;
+            Ok(match count {
+                2 => BooleanArray::from(vec![false, false, false, false]),
+                _ => BooleanArray::from(vec![true, false, true, false]),
+            })
+        });
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(2, result.len());
+        assert_eq!(2, result[0].num_rows());
+        assert_eq!(2, result[1].num_rows());
+
+        let expected = vec![
+            "+---+", "| a |", "+---+", "| 1 |", "| 3 |", "| 1 |", "| 3 |", "+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
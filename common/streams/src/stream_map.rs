@@ -0,0 +1,56 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Apply a per-block transform, the first error from the transform terminates the stream.
+pub struct MapStream<F> {
+    input: SendableDataBlockStream,
+    func: F,
+    finished: bool,
+}
+
+impl<F> MapStream<F>
+where F: FnMut(DataBlock) -> Result<DataBlock>
+{
+    pub fn create(input: SendableDataBlockStream, func: F) -> Self {
+        MapStream {
+            input,
+            func,
+            finished: false,
+        }
+    }
+}
+
+impl<F> Stream for MapStream<F>
+where F: FnMut(DataBlock) -> Result<DataBlock> + Unpin
+{
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        self.input.poll_next_unpin(ctx).map(|x| match x {
+            Some(Ok(v)) => {
+                let result = (self.func)(v);
+                self.finished = result.is_err();
+                Some(result)
+            }
+            other => other,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test]
+async fn test_map_blocks() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use common_exception::ErrorCodes;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Arc::new(Int64Array::from(vec![
+        1, 2, 3,
+    ]))]);
+
+    // Row-doubling transform.
+    {
+        let input =
+            DataBlockStream::create(schema.clone(), None, vec![block.clone(), block.clone()]);
+        let stream = input.map_blocks(|block| DataBlock::concat_blocks(&[block.clone(), block]));
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(2, result.len());
+        assert_eq!(6, result[0].num_rows());
+        assert_eq!(6, result[1].num_rows());
+    }
+
+    // The closure errors on the second block, and the stream terminates.
+    {
+        let input = DataBlockStream::create(schema.clone(), None, vec![
+            block.clone(),
+            block.clone(),
+            block,
+        ]);
+        let mut count = 0;
+        let mut stream = input.map_blocks(move |block| {
+            count += 1;
+            if count == 2 {
+                return Err(ErrorCodes::BadArguments("map error"));
+            }
+            Ok(block)
+        });
+
+        let mut results = vec![];
+        while let Some(result) = futures::StreamExt::next(&mut stream).await {
+            results.push(result);
+        }
+        assert_eq!(2, results.len());
+        assert_eq!(true, results[0].is_ok());
+        assert_eq!(
+            "Code: 6, displayText = map error.",
+            format!("{}", results[1].as_ref().unwrap_err())
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,346 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_arrow::arrow::array::*;
+use common_arrow::arrow::compute::SortOptions;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::DataType;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+struct BlockCursor {
+    block: DataBlock,
+    arrays: Vec<ArrayRef>,
+}
+
+trait SortValue {
+    fn sort_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! ord_sort_value {
+    ($($t: ty),*) => {
+        $(impl SortValue for $t {
+            fn sort_cmp(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+        })*
+    };
+}
+
+// NaN is greater than any other value and equal to itself, the same as the arrow comparators.
+macro_rules! float_sort_value {
+    ($($t: ty),*) => {
+        $(impl SortValue for $t {
+            fn sort_cmp(&self, other: &Self) -> Ordering {
+                self.partial_cmp(other)
+                    .unwrap_or_else(|| self.is_nan().cmp(&other.is_nan()))
+            }
+        })*
+    };
+}
+
+ord_sort_value!(bool, i8, i16, i32, i64, u8, u16, u32, u64, &str);
+float_sort_value!(f32, f64);
+
+macro_rules! typed_sort_array {
+    ($($variant: ident($array: ty) = $data_type: pat),* $(,)?) => {
+        /// The sort column of a block, downcast once when the block arrives
+        /// so that comparing the rows doesn't need to build a comparator each time.
+        enum TypedSortArray {
+            $($variant($array)),*
+        }
+
+        impl TypedSortArray {
+            fn try_create(array: &ArrayRef) -> Result<Self> {
+                match array.data_type() {
+                    $($data_type => Ok(TypedSortArray::$variant(<$array>::from(array.data().clone()))),)*
+                    other => Result::Err(ErrorCodes::BadDataValueType(format!(
+                        "Merge sorted streams can't compare the sort column of {:?}",
+                        other
+                    ))),
+                }
+            }
+
+            fn is_valid(&self, row: usize) -> bool {
+                match self {
+                    $(TypedSortArray::$variant(array) => array.is_valid(row)),*
+                }
+            }
+
+            // The sort arrays of the same column are checked of the same type when the block arrives.
+            fn compare(&self, row: usize, other: &Self, other_row: usize) -> Ordering {
+                match (self, other) {
+                    $((TypedSortArray::$variant(lhs), TypedSortArray::$variant(rhs)) => {
+                        lhs.value(row).sort_cmp(&rhs.value(other_row))
+                    })*
+                    _ => Ordering::Equal,
+                }
+            }
+        }
+    };
+}
+
+typed_sort_array!(
+    Boolean(BooleanArray) = DataType::Boolean,
+    Int8(Int8Array) = DataType::Int8,
+    Int16(Int16Array) = DataType::Int16,
+    Int32(Int32Array) = DataType::Int32,
+    Int64(Int64Array) = DataType::Int64,
+    UInt8(UInt8Array) = DataType::UInt8,
+    UInt16(UInt16Array) = DataType::UInt16,
+    UInt32(UInt32Array) = DataType::UInt32,
+    UInt64(UInt64Array) = DataType::UInt64,
+    Float32(Float32Array) = DataType::Float32,
+    Float64(Float64Array) = DataType::Float64,
+    Utf8(StringArray) = DataType::Utf8,
+    Date32(Date32Array) = DataType::Date32,
+    Date64(Date64Array) = DataType::Date64,
+    TimestampSecond(TimestampSecondArray) = DataType::Timestamp(TimeUnit::Second, _),
+    TimestampMillisecond(TimestampMillisecondArray) = DataType::Timestamp(TimeUnit::Millisecond, _),
+    TimestampMicrosecond(TimestampMicrosecondArray) = DataType::Timestamp(TimeUnit::Microsecond, _),
+    TimestampNanosecond(TimestampNanosecondArray) = DataType::Timestamp(TimeUnit::Nanosecond, _),
+);
+
+/// The current row of an input, ordered reversely so that the BinaryHeap pops the smallest row.
+struct HeapEntry {
+    input: usize,
+    row: usize,
+    sort_arrays: Vec<TypedSortArray>,
+    options: Arc<Vec<SortOptions>>,
+}
+
+impl HeapEntry {
+    fn compare(&self, other: &HeapEntry) -> Ordering {
+        for (c, option) in self.options.iter().enumerate() {
+            let (lhs, rhs) = (&self.sort_arrays[c], &other.sort_arrays[c]);
+            let mut result = match (lhs.is_valid(self.row), rhs.is_valid(other.row)) {
+                (true, true) => lhs.compare(self.row, rhs, other.row),
+                (false, true) if option.nulls_first => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (true, false) if option.nulls_first => Ordering::Greater,
+                (true, false) => Ordering::Less,
+                (false, false) => Ordering::Equal,
+            };
+            if option.descending {
+                result = result.reverse();
+            }
+            if result != Ordering::Equal {
+                return result;
+            }
+        }
+        // Keep the merge stable among the inputs.
+        self.input.cmp(&other.input)
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.compare(self)
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapEntry {}
+
+/// K-way merge of the sorted inputs into a globally sorted stream.
+/// Only one block per input is held at a time, an output block is produced
+/// each time the block of some input is drained.
+pub struct MergeSortedStream {
+    inputs: Vec<SendableDataBlockStream>,
+    finished: Vec<bool>,
+    cursors: Vec<Option<BlockCursor>>,
+    heap: BinaryHeap<HeapEntry>,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    sort_types: Option<Vec<DataType>>,
+    options: Arc<Vec<SortOptions>>,
+}
+
+impl MergeSortedStream {
+    pub fn try_create(
+        inputs: Vec<SendableDataBlockStream>,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+    ) -> Result<Self> {
+        if sort_columns_descriptions.is_empty() {
+            return Result::Err(ErrorCodes::BadArguments(
+                "Merge sorted streams requires at least one sort column",
+            ));
+        }
+
+        let options = sort_columns_descriptions
+            .iter()
+            .map(|f| SortOptions {
+                descending: !f.asc,
+                nulls_first: f.nulls_first,
+            })
+            .collect();
+
+        Ok(MergeSortedStream {
+            finished: vec![false; inputs.len()],
+            cursors: inputs.iter().map(|_| None).collect(),
+            inputs,
+            heap: BinaryHeap::new(),
+            sort_columns_descriptions,
+            sort_types: None,
+            options: Arc::new(options),
+        })
+    }
+
+    fn push_block(&mut self, input: usize, block: DataBlock) -> Result<()> {
+        let sort_arrays = self
+            .sort_columns_descriptions
+            .iter()
+            .map(|f| block.try_array_by_name(&f.column_name))
+            .collect::<Result<Vec<_>>>()?;
+        let sort_types = sort_arrays
+            .iter()
+            .map(|array| array.data_type().clone())
+            .collect::<Vec<_>>();
+
+        match &self.sort_types {
+            Some(types) if types != &sort_types => {
+                return Result::Err(ErrorCodes::BadDataValueType(format!(
+                    "It is impossible to merge sort columns of different data types: {:?} and {:?}",
+                    types, sort_types
+                )));
+            }
+            Some(_) => {}
+            None => self.sort_types = Some(sort_types),
+        }
+        let sort_arrays = sort_arrays
+            .iter()
+            .map(TypedSortArray::try_create)
+            .collect::<Result<Vec<_>>>()?;
+
+        let arrays = block
+            .columns()
+            .iter()
+            .map(|column| column.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        self.heap.push(HeapEntry {
+            input,
+            row: 0,
+            sort_arrays,
+            options: self.options.clone(),
+        });
+        self.cursors[input] = Some(BlockCursor { block, arrays });
+        Ok(())
+    }
+
+    fn num_rows(&self, input: usize) -> usize {
+        self.cursors[input]
+            .as_ref()
+            .map(|cursor| cursor.block.num_rows())
+            .unwrap_or(0)
+    }
+
+    // Pop the rows until the block of some input is drained, then it must be refilled before going on.
+    fn merge(&mut self) -> Result<DataBlock> {
+        let mut picks: Vec<(usize, usize)> = vec![];
+        let mut drained = None;
+        while let Some(mut entry) = self.heap.pop() {
+            picks.push((entry.input, entry.row));
+            if entry.row + 1 < self.num_rows(entry.input) {
+                entry.row += 1;
+                self.heap.push(entry);
+            } else {
+                drained = Some(entry.input);
+                break;
+            }
+        }
+
+        let mut sources = picks.iter().map(|(input, _)| *input).collect::<Vec<_>>();
+        sources.sort_unstable();
+        sources.dedup();
+
+        let cursors = sources
+            .iter()
+            .map(|input| {
+                self.cursors[*input].as_ref().ok_or_else(|| {
+                    ErrorCodes::LogicalError(format!("The block of input {} is missing", input))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let schema = cursors[0].block.schema().clone();
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for c in 0..schema.fields().len() {
+            let data = cursors
+                .iter()
+                .map(|cursor| cursor.arrays[c].data_ref())
+                .collect::<Vec<_>>();
+            let mut mutable = MutableArrayData::new(data, false, picks.len());
+
+            // Extend the consecutive rows of the same input at once.
+            let mut start = 0;
+            for end in 1..=picks.len() {
+                let (input, row) = picks[end - 1];
+                let continuous = end < picks.len() && picks[end] == (input, row + 1);
+                if !continuous {
+                    let source = sources.binary_search(&input).unwrap_or(0);
+                    mutable.extend(source, picks[start].1, row + 1);
+                    start = end;
+                }
+            }
+            columns.push(make_array(mutable.freeze()));
+        }
+
+        if let Some(input) = drained {
+            self.cursors[input] = None;
+        }
+        Ok(DataBlock::create_by_array(schema, columns))
+    }
+}
+
+impl Stream for MergeSortedStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // Every unfinished input must have a block before merging.
+        for i in 0..self.inputs.len() {
+            while self.cursors[i].is_none() && !self.finished[i] {
+                match futures::ready!(self.inputs[i].poll_next_unpin(ctx)) {
+                    Some(Ok(block)) if block.is_empty() => {}
+                    Some(Ok(block)) => {
+                        if let Err(e) = self.push_block(i, block) {
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                    Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                    None => self.finished[i] = true,
+                }
+            }
+        }
+
+        if self.heap.is_empty() {
+            return Poll::Ready(None);
+        }
+        Poll::Ready(Some(self.merge()))
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test]
+async fn test_merge_sorted_stream() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::array::Array;
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let block = |values: Vec<u64>| {
+        let names = values.iter().map(|v| format!("b{}", v)).collect::<Vec<_>>();
+        let names = names.iter().map(|v| v.as_str()).collect::<Vec<_>>();
+        DataBlock::create_by_array(schema.clone(), vec![
+            Arc::new(UInt64Array::from(values)),
+            Arc::new(StringArray::from(names)),
+        ])
+    };
+    let stream = |blocks: Vec<DataBlock>| -> SendableDataBlockStream {
+        Box::pin(DataBlockStream::create(schema.clone(), None, blocks))
+    };
+
+    let inputs = vec![
+        stream(vec![block(vec![1, 4, 7]), block(vec![10, 13])]),
+        stream(vec![block(vec![2, 5]), block(vec![8, 11, 14, 17])]),
+        stream(vec![block(vec![0, 3, 6, 9, 12])]),
+    ];
+    let merged = MergeSortedStream::try_create(inputs, vec![SortColumnDescription {
+        column_name: "a".to_string(),
+        asc: true,
+        nulls_first: false,
+    }])?;
+    let result = merged.try_collect::<Vec<_>>().await?;
+
+    let mut values = vec![];
+    for block in &result {
+        let column = block.try_array_by_name("a")?;
+        let column = column.as_any().downcast_ref::<UInt64Array>().unwrap();
+        values.extend(column.values().iter().cloned());
+    }
+    let expect: Vec<u64> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 17];
+    assert_eq!(expect, values);
+
+    // The other columns follow the sort column.
+    let expected = vec![
+        "+----+-----+",
+        "| a  | b   |",
+        "+----+-----+",
+        "| 0  | b0  |",
+        "| 1  | b1  |",
+        "| 2  | b2  |",
+        "| 3  | b3  |",
+        "| 4  | b4  |",
+        "| 5  | b5  |",
+        "| 6  | b6  |",
+        "| 7  | b7  |",
+        "| 8  | b8  |",
+        "| 9  | b9  |",
+        "| 10 | b10 |",
+        "| 11 | b11 |",
+        "| 12 | b12 |",
+        "| 13 | b13 |",
+        "| 14 | b14 |",
+        "| 17 | b17 |",
+        "+----+-----+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_merge_sorted_stream_float_nulls() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use futures::TryStreamExt;
+
+    use crate::*;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Float64, true)]);
+    let block = |values: Vec<Option<f64>>| {
+        DataBlock::create_by_array(schema.clone(), vec![Arc::new(Float64Array::from(values))])
+    };
+    let stream = |blocks: Vec<DataBlock>| -> SendableDataBlockStream {
+        Box::pin(DataBlockStream::create(schema.clone(), None, blocks))
+    };
+
+    // Descending with the nulls first, NaN is greater than the other values.
+    let inputs = vec![
+        stream(vec![block(vec![None, Some(f64::NAN), Some(2.5)])]),
+        stream(vec![block(vec![None, Some(3.0)]), block(vec![Some(-1.0)])]),
+    ];
+    let merged = MergeSortedStream::try_create(inputs, vec![SortColumnDescription {
+        column_name: "a".to_string(),
+        asc: false,
+        nulls_first: true,
+    }])?;
+    let result = merged.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+-----+", "| a   |", "+-----+", "|     |", "|     |", "| NaN |", "| 3   |", "| 2.5 |",
+        "| -1  |", "+-----+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -8,7 +8,11 @@ mod suites;
 
 criterion_main! {
     suites::bench_aggregate_query_sql::benches,
+    suites::bench_data_array_concat::benches,
+    suites::bench_data_block_concat::benches,
+    suites::bench_expression_typed::benches,
     suites::bench_filter_query_sql::benches,
+    suites::bench_function_factory::benches,
     suites::bench_limit_query_sql::benches,
     suites::bench_sort_query_sql::benches,
 }
@@ -0,0 +1,38 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::compute;
+use common_datavalues::BooleanArray;
+use common_datavalues::DataArrayConcat;
+use common_datavalues::DataArrayRef;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+fn criterion_benchmark_data_array_concat(c: &mut Criterion) {
+    // The odd lengths make the most of the arrays unaligned in the result.
+    let arrays = (0..64)
+        .map(|i| {
+            let values = (0..65537 + i).map(|v| v % 3 == 0).collect::<Vec<_>>();
+            Arc::new(BooleanArray::from(values)) as DataArrayRef
+        })
+        .collect::<Vec<_>>();
+    let array_refs = arrays
+        .iter()
+        .map(|a| a.as_ref())
+        .collect::<Vec<&dyn Array>>();
+
+    c.bench_function("concat 64 boolean arrays", |b| {
+        b.iter(|| DataArrayConcat::concat(&arrays).unwrap())
+    });
+    c.bench_function("concat 64 boolean arrays generic", |b| {
+        b.iter(|| compute::concat(&array_refs).unwrap())
+    });
+}
+
+criterion_group!(benches, criterion_benchmark_data_array_concat);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataColumnarValue;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+fn criterion_benchmark_data_block_concat(c: &mut Criterion) {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Utf8, false)]);
+    let value = DataValue::Utf8(Some("datafuse".to_string()));
+    let blocks = (0..1024)
+        .map(|_| {
+            DataBlock::create(schema.clone(), vec![DataColumnarValue::Constant(
+                value.clone(),
+                8192,
+            )])
+        })
+        .collect::<Vec<_>>();
+
+    c.bench_function("concat 1024 blocks of the same constant", |b| {
+        b.iter(|| DataBlock::concat_blocks(&blocks).unwrap())
+    });
+}
+
+criterion_group!(benches, criterion_benchmark_data_block_concat);
+criterion_main!(benches);
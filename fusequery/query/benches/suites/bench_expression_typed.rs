@@ -0,0 +1,50 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_planners::add;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
+use common_planners::TypedExpression;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+// Walks the expression asking the type of every node, like the planner does.
+fn uncached_types(expr: &Expression, schema: &DataSchemaRef) {
+    expr.to_data_type(schema).unwrap();
+    if let Expression::BinaryExpression { left, right, .. } = expr {
+        uncached_types(left, schema);
+        uncached_types(right, schema);
+    }
+}
+
+fn cached_types(typed: &TypedExpression) {
+    let _ = typed.data_type();
+    for child in typed.children() {
+        cached_types(child);
+    }
+}
+
+fn criterion_benchmark_expression_typed(c: &mut Criterion) {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let mut expr = col("a");
+    for i in 0..256 {
+        expr = add(expr, lit(i as i64));
+    }
+
+    c.bench_function("deep expression to_data_type of every node", |b| {
+        b.iter(|| uncached_types(&expr, &schema))
+    });
+    c.bench_function("deep expression typed", |b| {
+        b.iter(|| cached_types(&expr.typed(&schema).unwrap()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark_expression_typed);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_functions::FunctionFactory;
+use common_planners::add;
+use common_planners::col;
+use common_planners::lit;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+fn criterion_benchmark_function_factory(c: &mut Criterion) {
+    // Wide projection: c0 + 1, c1 + 1, ..., c255 + 1.
+    let fields = (0..256)
+        .map(|i| DataField::new(&format!("c{}", i), DataType::Int64, false))
+        .collect::<Vec<_>>();
+    let schema = DataSchemaRefExt::create(fields);
+    let exprs = (0..256)
+        .map(|i| add(col(&format!("c{}", i)), lit(1i64)))
+        .collect::<Vec<_>>();
+    let args = [DataType::Int64, DataType::Int64];
+
+    c.bench_function("wide projection function get", |b| {
+        b.iter(|| {
+            for _ in &exprs {
+                FunctionFactory::get("+")
+                    .unwrap()
+                    .return_type(&args)
+                    .unwrap();
+            }
+        })
+    });
+    c.bench_function("wide projection function get_cached", |b| {
+        b.iter(|| {
+            for _ in &exprs {
+                FunctionFactory::get_cached("+")
+                    .unwrap()
+                    .return_type(&args)
+                    .unwrap();
+            }
+        })
+    });
+    c.bench_function("wide projection to_data_type", |b| {
+        b.iter(|| {
+            for expr in &exprs {
+                expr.to_data_type(&schema).unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark_function_factory);
+criterion_main!(benches);
@@ -11,7 +11,11 @@ use fuse_query::sql::PlanParser;
 use futures::StreamExt;
 
 pub mod bench_aggregate_query_sql;
+pub mod bench_data_array_concat;
+pub mod bench_data_block_concat;
+pub mod bench_expression_typed;
 pub mod bench_filter_query_sql;
+pub mod bench_function_factory;
 pub mod bench_limit_query_sql;
 pub mod bench_sort_query_sql;
 
@@ -62,10 +62,13 @@ async fn test_cluster() -> Result<()> {
         let res = warp::test::request()
             .path("/v1/cluster/list")
             .reply(&filter);
-        assert_eq!(
-            "[{\"name\":\"9090\",\"priority\":8,\"address\":\"127.0.0.1:9090\",\"local\":true,\"sequence\":0}]",
-            res.await.body()
-        );
+        // The local node reports the start time of the server.
+        let res = res.await;
+        let body = String::from_utf8_lossy(res.body());
+        assert!(body.starts_with(
+            "[{\"name\":\"9090\",\"priority\":8,\"address\":\"127.0.0.1:9090\",\"local\":true,\"sequence\":0,\"started_at\":"
+        ));
+        assert!(body.ends_with("}]"));
     }
 
     Ok(())
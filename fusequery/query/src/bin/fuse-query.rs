@@ -6,6 +6,7 @@ use fuse_query::api::HttpService;
 use fuse_query::api::RpcService;
 use fuse_query::clusters::Cluster;
 use fuse_query::configs::Config;
+use fuse_query::logging::init_logger;
 use fuse_query::metrics::MetricService;
 use fuse_query::servers::ClickHouseHandler;
 use fuse_query::servers::MySQLHandler;
@@ -23,11 +24,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         info!("Config reload from {:?}", conf.config_file);
         conf = Config::load_from_toml(conf.config_file.as_str())?;
     }
+    conf.validate()?;
 
-    env_logger::Builder::from_env(
-        env_logger::Env::default().default_filter_or(conf.log_level.to_lowercase().as_str()),
-    )
-    .init();
+    init_logger(
+        conf.log_level.to_lowercase().as_str(),
+        conf.log_buffer_capacity as usize,
+    )?;
 
     info!("{:?}", conf);
     info!(
@@ -37,7 +39,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut tasks = vec![];
     let cluster = Cluster::create_global(conf.clone())?;
-    let session_manager = SessionManager::create();
+    let session_manager = SessionManager::from_conf(conf.clone());
 
     // MySQL handler.
     {
@@ -7,6 +7,8 @@ use std::collections::hash_map::Entry::Vacant;
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_exception::ErrorCodes;
 use common_exception::Result;
@@ -22,6 +24,8 @@ pub type ClusterRef = Arc<Cluster>;
 pub struct Cluster {
     local_port: u16,
     nodes: Mutex<HashMap<String, Arc<Node>>>,
+    // The start time of this server in seconds since the unix epoch.
+    started_at: u64,
 }
 
 impl Cluster {
@@ -29,6 +33,7 @@ impl Cluster {
         Ok(Arc::new(Cluster {
             nodes: Mutex::new(HashMap::new()),
             local_port: Address::create(&cfg.flight_api_address)?.port(),
+            started_at: now_secs(),
         }))
     }
 
@@ -36,6 +41,7 @@ impl Cluster {
         Arc::new(Cluster {
             local_port: 9090,
             nodes: Mutex::new(HashMap::new()),
+            started_at: now_secs(),
         })
     }
 
@@ -48,6 +54,11 @@ impl Cluster {
         let address_is_local = is_local(&address, self.local_port).await?;
         let mut nodes = self.nodes.lock();
         let new_node_sequence = nodes.len();
+        // Only the start time of the local node is known.
+        let started_at = match address_is_local {
+            true => self.started_at,
+            false => 0,
+        };
 
         match nodes.entry(name.to_string()) {
             Occupied(_) => Err(ErrorCodes::DuplicateClusterNode(format!(
@@ -61,6 +72,7 @@ impl Cluster {
                     address.clone(),
                     address_is_local,
                     new_node_sequence,
+                    started_at,
                 )?));
 
                 Ok(())
@@ -101,6 +113,22 @@ impl Cluster {
         nodes.sort_by(|left, right| left.sequence.cmp(&right.sequence));
         Ok(nodes)
     }
+
+    /// The nodes in the scheduling order: the higher priority first,
+    /// the nodes of the same priority are in the adding order.
+    pub fn get_nodes_by_priority(&self) -> Result<Vec<Arc<Node>>> {
+        let mut nodes = self.get_nodes()?;
+        // The sort is stable, the ties keep the sequence order.
+        nodes.sort_by(|left, right| right.priority.cmp(&left.priority));
+        Ok(nodes)
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
 async fn is_local(address: &Address, expect_port: u16) -> Result<bool> {
@@ -69,3 +69,30 @@ async fn test_add_node_with_clone() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_nodes_by_priority() -> Result<()> {
+    let cluster = Cluster::empty();
+
+    cluster.add_node("node1", 1, "127.0.0.1:9001").await?;
+    cluster.add_node("node2", 8, "127.0.0.1:9002").await?;
+    cluster.add_node("node3", 5, "127.0.0.1:9003").await?;
+    cluster.add_node("node4", 8, "127.0.0.1:9004").await?;
+
+    let names = cluster
+        .get_nodes_by_priority()?
+        .iter()
+        .map(|node| node.name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["node2", "node4", "node3", "node1"], names);
+
+    // The adding order is kept by get_nodes.
+    let names = cluster
+        .get_nodes()?
+        .iter()
+        .map(|node| node.name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["node1", "node2", "node3", "node4"], names);
+
+    Ok(())
+}
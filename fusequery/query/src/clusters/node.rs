@@ -2,6 +2,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
 use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
 use common_exception::Result;
 use common_flights::ConnectionFactory;
@@ -21,6 +24,8 @@ pub struct Node {
     pub address: Address,
     pub local: bool,
     pub sequence: usize,
+    // The start time of the node in seconds since the unix epoch, 0 if it's unknown.
+    pub started_at: u64,
 }
 
 impl PartialEq for Node {
@@ -39,6 +44,7 @@ impl Node {
         address: Address,
         local: bool,
         sequence: usize,
+        started_at: u64,
     ) -> Result<Node> {
         Ok(Node {
             name,
@@ -46,6 +52,7 @@ impl Node {
             address,
             local,
             sequence,
+            started_at,
         })
     }
 
@@ -53,6 +60,17 @@ impl Node {
         self.local
     }
 
+    /// Seconds since the node started, 0 if the start time is unknown.
+    pub fn uptime_secs(&self) -> u64 {
+        if self.started_at == 0 {
+            return 0;
+        }
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs().saturating_sub(self.started_at))
+            .unwrap_or(0)
+    }
+
     pub async fn get_flight_client(&self) -> Result<FlightClient> {
         let channel = ConnectionFactory::create_flight_channel(self.address.clone(), None).await;
         channel.map(|channel| FlightClient::new(FlightServiceClient::new(channel)))
@@ -69,6 +87,7 @@ impl serde::Serialize for Node {
             address: Address,
             local: bool,
             sequence: usize,
+            started_at: u64,
         }
 
         NodeSerializeView::serialize(
@@ -78,6 +97,7 @@ impl serde::Serialize for Node {
                 address: self.address.clone(),
                 local: self.local,
                 sequence: self.sequence,
+                started_at: self.started_at,
             },
             serializer,
         )
@@ -94,6 +114,8 @@ impl<'de> serde::Deserialize<'de> for Node {
             pub address: Address,
             pub local: bool,
             pub sequence: usize,
+            #[serde(default)]
+            pub started_at: u64,
         }
 
         let node_deserialize_view = NodeDeserializeView::deserialize(deserializer)?;
@@ -103,6 +125,7 @@ impl<'de> serde::Deserialize<'de> for Node {
             node_deserialize_view.address.clone(),
             node_deserialize_view.local,
             node_deserialize_view.sequence,
+            node_deserialize_view.started_at,
         );
 
         match deserialize_result {
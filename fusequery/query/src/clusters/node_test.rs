@@ -15,12 +15,17 @@ async fn test_serialize_node() -> Result<()> {
         Address::create(&String::from("localhost:9090"))?,
         true,
         2,
+        1624000000,
     )?;
 
-    let node_json = "{\"name\":\"name\",\"priority\":1,\"address\":\"localhost:9090\",\"local\":true,\"sequence\":2}";
+    let node_json = "{\"name\":\"name\",\"priority\":1,\"address\":\"localhost:9090\",\"local\":true,\"sequence\":2,\"started_at\":1624000000}";
 
     assert_eq!(serde_json::to_string(&node)?, node_json.clone());
     assert_eq!(serde_json::from_str::<Node>(node_json.clone())?, node);
 
+    // The start time is unknown for the nodes not reporting it.
+    let old_node_json = "{\"name\":\"name\",\"priority\":1,\"address\":\"localhost:9090\",\"local\":true,\"sequence\":2}";
+    assert_eq!(serde_json::from_str::<Node>(old_node_json)?.started_at, 0);
+
     Ok(())
 }
@@ -2,6 +2,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::net::SocketAddr;
+
 use common_exception::ErrorCodes;
 use common_exception::Result;
 use structopt::StructOpt;
@@ -15,6 +17,9 @@ pub struct Config {
     #[structopt(long, env = "FUSE_QUERY_LOG_LEVEL", default_value = "INFO")]
     pub log_level: String,
 
+    #[structopt(long, env = "FUSE_QUERY_LOG_BUFFER_CAPACITY", default_value = "1000")]
+    pub log_buffer_capacity: u64,
+
     #[structopt(long, env = "FUSE_QUERY_NUM_CPUS", default_value = "0")]
     pub num_cpus: u64,
 
@@ -77,6 +82,24 @@ pub struct Config {
     )]
     pub metric_api_address: String,
 
+    #[structopt(long, env = "FUSE_QUERY_DEFAULT_DATABASE", default_value = "default")]
+    pub default_database: String,
+
+    /// Overwrite the table function registered by an earlier database with a warning,
+    /// instead of failing the registration.
+    #[structopt(
+        long,
+        env = "FUSE_QUERY_ALLOW_DUPLICATE_TABLE_FUNCTIONS",
+        default_value = "false",
+        parse(try_from_str)
+    )]
+    pub allow_duplicate_table_functions: bool,
+
+    /// The CSV and Parquet files of the dir are loaded as the tables of the local database,
+    /// named by the file stems. Empty means none.
+    #[structopt(long, env = "FUSE_QUERY_LOCAL_DATA_DIR", default_value = "")]
+    pub local_data_dir: String,
+
     #[structopt(long, env = "STORE_API_ADDRESS", default_value = "127.0.0.1:9191")]
     pub store_api_address: String,
 
@@ -95,6 +118,7 @@ impl Config {
     pub fn default() -> Self {
         Config {
             log_level: "debug".to_string(),
+            log_buffer_capacity: 1000,
             num_cpus: 8,
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
@@ -105,6 +129,9 @@ impl Config {
             flight_api_address: "127.0.0.1:9090".to_string(),
             http_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
+            default_database: "default".to_string(),
+            allow_duplicate_table_functions: false,
+            local_data_dir: "".to_string(),
             store_api_address: "127.0.0.1:9191".to_string(),
             store_api_username: "root".to_string(),
             store_api_password: "root".to_string(),
@@ -132,4 +159,19 @@ impl Config {
         }
         Ok(cfg)
     }
+
+    /// Check the api addresses are valid socket addresses(ip:port).
+    pub fn validate(&self) -> Result<()> {
+        let addresses = [
+            ("flight_api_address", &self.flight_api_address),
+            ("http_api_address", &self.http_api_address),
+            ("metric_api_address", &self.metric_api_address),
+        ];
+        for (name, address) in addresses.iter() {
+            address.parse::<SocketAddr>().map_err(|e| {
+                ErrorCodes::BadAddressFormat(format!("Invalid {}: '{}', {}", name, address, e))
+            })?;
+        }
+        Ok(())
+    }
 }
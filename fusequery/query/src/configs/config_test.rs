@@ -12,6 +12,7 @@ fn test_config() -> common_exception::Result<()> {
     {
         let expect = Config {
             log_level: "debug".to_string(),
+            log_buffer_capacity: 1000,
             num_cpus: 8,
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
@@ -22,6 +23,9 @@ fn test_config() -> common_exception::Result<()> {
             flight_api_address: "127.0.0.1:9090".to_string(),
             http_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
+            default_database: "default".to_string(),
+            allow_duplicate_table_functions: false,
+            local_data_dir: "".to_string(),
             store_api_address: "127.0.0.1:9191".to_string(),
             store_api_username: "root".to_string(),
             store_api_password: "root".to_string(),
@@ -59,3 +63,42 @@ fn test_config() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_config_validate() -> common_exception::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+
+    let conf = Config::default();
+    conf.validate()?;
+
+    let conf = Config {
+        flight_api_address: "0.0.0.0:9090".to_string(),
+        http_api_address: "[::1]:8080".to_string(),
+        ..Config::default()
+    };
+    conf.validate()?;
+
+    // No port.
+    let conf = Config {
+        flight_api_address: "127.0.0.1".to_string(),
+        ..Config::default()
+    };
+    assert_eq!(
+        "Code: 36, displayText = Invalid flight_api_address: '127.0.0.1', invalid socket address syntax.",
+        conf.validate().unwrap_err().to_string()
+    );
+
+    // Bad port.
+    let conf = Config {
+        http_api_address: "127.0.0.1:80800".to_string(),
+        ..Config::default()
+    };
+    assert_eq!(
+        "Code: 36, displayText = Invalid http_api_address: '127.0.0.1:80800', invalid socket address syntax.",
+        conf.validate().unwrap_err().to_string()
+    );
+
+    Ok(())
+}
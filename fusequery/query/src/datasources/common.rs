@@ -6,6 +6,12 @@ use std::io;
 use std::io::BufRead;
 use std::io::BufReader;
 
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
+use common_exception::ErrorCodes;
+use common_exception::Result;
 use common_planners::Partition;
 use common_planners::Partitions;
 
@@ -21,6 +27,8 @@ impl Common {
             partitions.push(Partition {
                 name: format!("{}-{}-{}", total, start, total,),
                 version: 0,
+                rows: total.saturating_sub(start),
+                bytes: 0,
             })
         } else {
             for part in 0..workers {
@@ -35,15 +43,43 @@ impl Common {
                 partitions.push(Partition {
                     name: format!("{}-{}-{}", total, part_begin, part_end,),
                     version: 0,
+                    rows: part_end.saturating_sub(part_begin),
+                    bytes: 0,
                 })
             }
         }
         partitions
     }
 
+    /// Narrows `schema` to the column indices of the scan projection.
+    /// A `None` projection keeps all the columns.
+    pub fn project_schema(
+        schema: &DataSchemaRef,
+        projection: &Option<Vec<usize>>,
+    ) -> Result<DataSchemaRef> {
+        match projection {
+            None => Ok(schema.clone()),
+            Some(indices) => {
+                let fields = indices
+                    .iter()
+                    .map(|i| {
+                        schema.fields().get(*i).cloned().ok_or_else(|| {
+                            ErrorCodes::BadArguments(format!(
+                                "Projection index {} is out of range, schema has {} columns",
+                                i,
+                                schema.fields().len()
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataSchemaRefExt::create(fields))
+            }
+        }
+    }
+
     /// Counts lines in the source `handle`.
     /// count_lines(std::fs::File.open("foo.txt")
-    pub fn count_lines<R: io::Read>(handle: R) -> Result<usize, io::Error> {
+    pub fn count_lines<R: io::Read>(handle: R) -> std::result::Result<usize, io::Error> {
         let sep = b'\n';
         let mut reader = BufReader::new(handle);
         let mut count = 0;
@@ -59,4 +95,111 @@ impl Common {
         }
         Ok(count)
     }
+
+    /// The wrapping sum of the row hashes of the block, so it's independent of the row order.
+    /// Each row hash is the 64-bit FNV-1a of the row's values in the column order, each value
+    /// is encoded as: a type tag byte, a null flag byte(0 for null), then for the non-null
+    /// values the u64 length and the little-endian bytes(floats by their bits, strings UTF-8),
+    /// the lists and structs by the u64 count and the encoded nested values.
+    /// The checksum is stable across the builds and platforms.
+    pub fn block_checksum(block: &DataBlock) -> Result<u64> {
+        let mut checksum = 0u64;
+        for row in 0..block.num_rows() {
+            let mut hasher = Fnv1aHasher::create();
+            for column in block.columns() {
+                hasher.write_value(&DataValue::try_from_column(column, row)?);
+            }
+            checksum = checksum.wrapping_add(hasher.finish());
+        }
+        Ok(checksum)
+    }
+}
+
+// The 64-bit FNV-1a, with the offset basis and prime of the FNV specification.
+struct Fnv1aHasher {
+    hash: u64,
+}
+
+impl Fnv1aHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn create() -> Self {
+        Fnv1aHasher {
+            hash: Self::OFFSET_BASIS,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= *byte as u64;
+            self.hash = self.hash.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_bytes(&mut self, tag: u8, bytes: Option<&[u8]>) {
+        self.write(&[tag]);
+        match bytes {
+            None => self.write(&[0]),
+            Some(bytes) => {
+                self.write(&[1]);
+                self.write(&(bytes.len() as u64).to_le_bytes());
+                self.write(bytes);
+            }
+        }
+    }
+
+    // The nested values are counted by the length instead of the bytes.
+    fn write_values(&mut self, tag: u8, values: Option<&[DataValue]>) {
+        self.write(&[tag]);
+        match values {
+            None => self.write(&[0]),
+            Some(values) => {
+                self.write(&[1]);
+                self.write(&(values.len() as u64).to_le_bytes());
+                for value in values {
+                    self.write_value(value);
+                }
+            }
+        }
+    }
+
+    fn write_value(&mut self, value: &DataValue) {
+        macro_rules! le_bytes {
+            ($hasher: ident, $tag: expr, $v: expr) => {
+                $hasher.write_bytes($tag, $v.map(|v| v.to_le_bytes()).as_ref().map(|v| &v[..]))
+            };
+        }
+
+        match value {
+            DataValue::Null => self.write_bytes(0, None),
+            DataValue::Boolean(v) => le_bytes!(self, 1, v.map(|v| v as u8)),
+            DataValue::Int8(v) => le_bytes!(self, 2, v),
+            DataValue::Int16(v) => le_bytes!(self, 3, v),
+            DataValue::Int32(v) => le_bytes!(self, 4, v),
+            DataValue::Int64(v) => le_bytes!(self, 5, v),
+            DataValue::UInt8(v) => le_bytes!(self, 6, v),
+            DataValue::UInt16(v) => le_bytes!(self, 7, v),
+            DataValue::UInt32(v) => le_bytes!(self, 8, v),
+            DataValue::UInt64(v) => le_bytes!(self, 9, v),
+            DataValue::Float32(v) => le_bytes!(self, 10, v.map(|v| v.to_bits())),
+            DataValue::Float64(v) => le_bytes!(self, 11, v.map(|v| v.to_bits())),
+            DataValue::Binary(v) => self.write_bytes(12, v.as_deref()),
+            DataValue::Utf8(v) => self.write_bytes(13, v.as_ref().map(|v| v.as_bytes())),
+            DataValue::Date32(v) => le_bytes!(self, 14, v),
+            DataValue::Date64(v) => le_bytes!(self, 15, v),
+            DataValue::TimestampSecond(v) => le_bytes!(self, 16, v),
+            DataValue::TimestampMillisecond(v) => le_bytes!(self, 17, v),
+            DataValue::TimestampMicrosecond(v) => le_bytes!(self, 18, v),
+            DataValue::TimestampNanosecond(v) => le_bytes!(self, 19, v),
+            DataValue::IntervalYearMonth(v) => le_bytes!(self, 20, v),
+            DataValue::IntervalDayTime(v) => le_bytes!(self, 21, v),
+            DataValue::List(v, _) => self.write_values(22, v.as_deref()),
+            DataValue::Struct(v) => self.write_values(23, Some(v)),
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
 }
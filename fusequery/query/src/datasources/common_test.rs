@@ -19,21 +19,27 @@ fn test_util_generate_parts() -> Result<()> {
         assert_eq!(
             Partition {
                 name: "11-0-3".into(),
-                version: 0
+                version: 0,
+                rows: 3,
+                bytes: 0
             },
             ps[0]
         );
         assert_eq!(
             Partition {
                 name: "11-3-6".into(),
-                version: 0
+                version: 0,
+                rows: 3,
+                bytes: 0
             },
             ps[1]
         );
         assert_eq!(
             Partition {
                 name: "11-6-11".into(),
-                version: 0
+                version: 0,
+                rows: 5,
+                bytes: 0
             },
             ps[2]
         );
@@ -47,7 +53,9 @@ fn test_util_generate_parts() -> Result<()> {
         assert_eq!(
             Partition {
                 name: "0-0-0".into(),
-                version: 0
+                version: 0,
+                rows: 0,
+                bytes: 0
             },
             ps[0]
         );
@@ -60,7 +68,9 @@ fn test_util_generate_parts() -> Result<()> {
         assert_eq!(
             Partition {
                 name: "2-0-2".into(),
-                version: 0
+                version: 0,
+                rows: 2,
+                bytes: 0
             },
             ps[0]
         );
@@ -86,3 +96,39 @@ fn test_lines_count() -> anyhow::Result<()> {
     assert_eq!(6, lines);
     Ok(())
 }
+
+#[test]
+fn test_util_block_checksum() -> Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::DataBlock;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::Common;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Arc::new(Int64Array::from(vec![1, 2])),
+        Arc::new(StringArray::from(vec!["x", "y"])),
+    ]);
+    // The FNV-1a of the encoded values is fixed, it must not change between the builds.
+    assert_eq!(11351912649823785004, Common::block_checksum(&block)?);
+
+    // The same value of different types.
+    let int32_block = DataBlock::create_by_array(
+        DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]),
+        vec![Arc::new(Int32Array::from(vec![1]))],
+    );
+    let int64_block = DataBlock::create_by_array(
+        DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]),
+        vec![Arc::new(Int64Array::from(vec![1]))],
+    );
+    assert_eq!(14062651061849858691, Common::block_checksum(&int32_block)?);
+    assert_eq!(1133471405926626938, Common::block_checksum(&int64_block)?);
+
+    Ok(())
+}
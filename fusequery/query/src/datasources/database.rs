@@ -4,12 +4,14 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCodes;
 use common_exception::Result;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 
 use crate::datasources::ITable;
 use crate::datasources::ITableFunction;
+use crate::datasources::TableMeta;
 
 #[async_trait::async_trait]
 pub trait IDatabase: Sync + Send {
@@ -24,10 +26,37 @@ pub trait IDatabase: Sync + Send {
     /// Get all tables.
     fn get_tables(&self) -> Result<Vec<Arc<dyn ITable>>>;
 
+    /// Get all table names, without constructing the tables if the database can.
+    fn get_table_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .get_tables()?
+            .iter()
+            .map(|table| table.name().to_string())
+            .collect())
+    }
+
+    /// Get the metadata of one table, from the cached metadata if the database has.
+    fn get_table_meta(&self, table_name: &str) -> Result<TableMeta> {
+        let table = self.get_table(table_name)?;
+        TableMeta::try_create(table.as_ref())
+    }
+
     /// Get database table functions.
     fn get_table_functions(&self) -> Result<Vec<Arc<dyn ITableFunction>>>;
 
+    /// Evict the cached tables if the database caches them, e.g. after it's dropped,
+    /// the holders of the database then go to the storage instead of the stale tables.
+    fn invalidate_cache(&self) {}
+
     /// DDL
     async fn create_table(&self, plan: CreateTablePlan) -> Result<()>;
     async fn drop_table(&self, plan: DropTablePlan) -> Result<()>;
+
+    /// Rename the table, the databases can't rename tables by default.
+    async fn rename_table(&self, _table_name: &str, _new_table_name: &str) -> Result<()> {
+        Err(ErrorCodes::UnImplement(format!(
+            "Cannot rename table for {} database",
+            self.engine()
+        )))
+    }
 }
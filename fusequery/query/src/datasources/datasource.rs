@@ -9,28 +9,60 @@ use common_exception::ErrorCodes;
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_planners::CreateDatabasePlan;
+use common_planners::CreateTablePlan;
 use common_planners::DatabaseEngineType;
 use common_planners::DropDatabasePlan;
+use common_planners::DropTablePlan;
+use log::error;
+use log::warn;
+use metrics::counter;
 
 use crate::configs::Config;
 use crate::datasources::local::LocalDatabase;
 use crate::datasources::local::LocalFactory;
+use crate::datasources::metrics::METRIC_DATASOURCE_LOOKUP_HITS;
+use crate::datasources::metrics::METRIC_DATASOURCE_LOOKUP_MISSES;
 use crate::datasources::remote::RemoteDatabase;
 use crate::datasources::remote::RemoteFactory;
 use crate::datasources::system::SystemFactory;
+use crate::datasources::DataSourceSnapshot;
 use crate::datasources::IDatabase;
+use crate::datasources::IDdlListener;
 use crate::datasources::ITable;
 use crate::datasources::ITableFunction;
+use crate::datasources::TableFunctionInfo;
+use crate::datasources::TableMeta;
 
 #[async_trait::async_trait]
 pub trait IDataSource: Sync + Send {
     fn get_database(&self, db_name: &str) -> Result<Arc<dyn IDatabase>>;
     fn get_databases(&self) -> Result<Vec<String>>;
     fn get_table(&self, db_name: &str, table_name: &str) -> Result<Arc<dyn ITable>>;
+    fn get_table_meta(&self, db_name: &str, table_name: &str) -> Result<TableMeta>;
+    fn get_tables(&self, db_name: &str) -> Result<Vec<Arc<dyn ITable>>>;
     fn get_all_tables(&self) -> Result<Vec<(String, Arc<dyn ITable>)>>;
     fn get_table_function(&self, name: &str) -> Result<Arc<dyn ITableFunction>>;
+    /// The registered table functions with their output schemas, sorted by name.
+    fn list_table_functions(&self) -> Result<Vec<TableFunctionInfo>>;
     async fn create_database(&self, plan: CreateDatabasePlan) -> Result<()>;
     async fn drop_database(&self, plan: DropDatabasePlan) -> Result<()>;
+    /// Drop the database if exists and create the new one, the old database is kept
+    /// if the creation fails. A remote database can't be replaced by a remote one.
+    async fn replace_database(&self, plan: CreateDatabasePlan) -> Result<()>;
+    async fn create_table(&self, plan: CreateTablePlan) -> Result<()>;
+    async fn drop_table(&self, plan: DropTablePlan) -> Result<()>;
+    async fn rename_table(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        new_table_name: &str,
+    ) -> Result<()>;
+
+    /// Register a listener notified after each successful DDL.
+    fn register_ddl_listener(&self, listener: Arc<dyn IDdlListener>);
+
+    /// An immutable view of current databases, not affected by later DDL.
+    fn snapshot(&self) -> Result<DataSourceSnapshot>;
 }
 
 // Maintain all the databases of user.
@@ -38,7 +70,14 @@ pub struct DataSource {
     // conf: Config,
     databases: RwLock<HashMap<String, Arc<dyn IDatabase>>>,
     table_functions: RwLock<HashMap<String, Arc<dyn ITableFunction>>>,
+    ddl_listeners: RwLock<Vec<Arc<dyn IDdlListener>>>,
+    // Serializes the database DDLs, their checks and store calls are not interleaved.
+    database_ddl_lock: tokio::sync::Mutex<()>,
     remote_factory: RemoteFactory,
+    // The databases registered on creation, such as system and default.
+    builtin_databases: Vec<String>,
+    // Overwrite the table function of the same name with a warning, instead of an error.
+    allow_duplicate_table_functions: bool,
 }
 
 impl DataSource {
@@ -48,42 +87,96 @@ impl DataSource {
     }
 
     pub fn try_create_with_config(conf: &Config) -> Result<Self> {
+        DataSource::try_create_with_system_factory(conf, SystemFactory::create())
+    }
+
+    /// Create with the system tables of the `system_factory`,
+    /// which may have the tables registered by the embedders.
+    pub fn try_create_with_system_factory(
+        conf: &Config,
+        system_factory: SystemFactory,
+    ) -> Result<Self> {
+        DataSource::try_create_with_factories(conf, system_factory, RemoteFactory::new(conf))
+    }
+
+    /// Create with the remote databases of the `remote_factory`,
+    /// which may talk to a mock store in the tests.
+    pub fn try_create_with_remote_factory(
+        conf: &Config,
+        remote_factory: RemoteFactory,
+    ) -> Result<Self> {
+        DataSource::try_create_with_factories(conf, SystemFactory::create(), remote_factory)
+    }
+
+    fn try_create_with_factories(
+        conf: &Config,
+        system_factory: SystemFactory,
+        remote_factory: RemoteFactory,
+    ) -> Result<Self> {
         let mut datasource = DataSource {
             databases: Default::default(),
             table_functions: Default::default(),
-            remote_factory: RemoteFactory::new(conf),
+            ddl_listeners: Default::default(),
+            database_ddl_lock: Default::default(),
+            remote_factory,
+            builtin_databases: vec![],
+            allow_duplicate_table_functions: conf.allow_duplicate_table_functions,
         };
 
-        datasource.register_system_database()?;
-        datasource.register_local_database()?;
+        datasource.register_system_database(system_factory)?;
+        datasource.register_local_database(conf)?;
         datasource.register_default_database()?;
         datasource.register_remote_database()?;
+        datasource.builtin_databases = datasource.get_databases()?;
         Ok(datasource)
     }
 
-    fn insert_databases(&mut self, databases: Vec<Arc<dyn IDatabase>>) -> Result<()> {
+    /// Remove all the databases created after the datasource(and their table functions),
+    /// the builtin databases such as system and default are kept.
+    /// Gives the tests a clean datasource without recreating it.
+    pub fn clear_user_databases(&self) {
+        let mut databases = self.databases.write();
+        let mut table_functions = self.table_functions.write();
+
+        databases.retain(|name, _| self.builtin_databases.contains(name));
+        table_functions.retain(|_, table_function| databases.contains_key(table_function.db()));
+    }
+
+    /// Register the databases and their table functions, a table function of the
+    /// registered name fails the registration unless `allow_duplicate_table_functions`.
+    pub(crate) fn insert_databases(&mut self, databases: Vec<Arc<dyn IDatabase>>) -> Result<()> {
         let mut db_lock = self.databases.write();
+        let mut table_functions = self.table_functions.write();
         for database in databases {
             db_lock.insert(database.name().to_lowercase(), database.clone());
             for tbl_func in database.get_table_functions()? {
-                self.table_functions
-                    .write()
-                    .insert(tbl_func.name().to_string(), tbl_func.clone());
+                if let Some(registered) = table_functions.get(tbl_func.name()) {
+                    let message = format!(
+                        "Table function '{}' of database '{}' is already registered by database '{}'",
+                        tbl_func.name(),
+                        database.name(),
+                        registered.db()
+                    );
+                    if !self.allow_duplicate_table_functions {
+                        return Err(ErrorCodes::DuplicateTableFunction(message));
+                    }
+                    warn!("{}, it is overwritten", message);
+                }
+                table_functions.insert(tbl_func.name().to_string(), tbl_func.clone());
             }
         }
         Ok(())
     }
 
     // Register local database with System engine.
-    fn register_system_database(&mut self) -> Result<()> {
-        let factory = SystemFactory::create();
+    fn register_system_database(&mut self, factory: SystemFactory) -> Result<()> {
         let databases = factory.load_databases()?;
         self.insert_databases(databases)
     }
 
     // Register local database with Local engine.
-    fn register_local_database(&mut self) -> Result<()> {
-        let factory = LocalFactory::create();
+    fn register_local_database(&mut self, conf: &Config) -> Result<()> {
+        let factory = LocalFactory::create(conf);
         let databases = factory.load_databases()?;
         self.insert_databases(databases)
     }
@@ -102,16 +195,82 @@ impl DataSource {
             .insert("default".to_string(), Arc::new(default_db));
         Ok(())
     }
+
+    // Create the database object, the remote database is created in the store first.
+    async fn build_database(&self, plan: &CreateDatabasePlan) -> Result<Arc<dyn IDatabase>> {
+        match plan.engine {
+            DatabaseEngineType::Local => Ok(Arc::new(LocalDatabase::create())),
+            DatabaseEngineType::Remote => {
+                let provider = self.remote_factory.store_client_provider();
+                let create = || {
+                    let provider = provider.clone();
+                    let plan = plan.clone();
+                    async move {
+                        let mut client = provider.try_get_client().await?;
+                        client.create_database(plan).await
+                    }
+                };
+                // Only the creation of if_not_exists can be repeated.
+                let retry_policy = self.remote_factory.retry_policy();
+                if plan.if_not_exists {
+                    retry_policy.retry(create).await?;
+                } else {
+                    retry_policy.retry_non_idempotent(create).await?;
+                }
+                Ok(Arc::new(RemoteDatabase::create(provider, plan.db.clone())))
+            }
+        }
+    }
+
+    async fn drop_remote_database(&self, plan: &DropDatabasePlan) -> Result<()> {
+        let provider = self.remote_factory.store_client_provider();
+        let drop_database = || {
+            let provider = provider.clone();
+            let plan = plan.clone();
+            async move {
+                let mut client = provider.try_get_client().await?;
+                client.drop_database(plan).await
+            }
+        };
+        // Only the drop of if_exists can be repeated.
+        let retry_policy = self.remote_factory.retry_policy();
+        if plan.if_exists {
+            retry_policy.retry(drop_database).await?;
+        } else {
+            retry_policy.retry_non_idempotent(drop_database).await?;
+        }
+        Ok(())
+    }
+
+    // Count the lookup as a hit if found, labeled by the method.
+    fn record_lookup<T>(&self, method: &'static str, result: Result<T>) -> Result<T> {
+        if result.is_ok() {
+            counter!(METRIC_DATASOURCE_LOOKUP_HITS, 1, "method" => method);
+        } else {
+            counter!(METRIC_DATASOURCE_LOOKUP_MISSES, 1, "method" => method);
+        }
+        result
+    }
+
+    // The listener errors are only logged, the DDL has been done.
+    fn notify_ddl_listeners<F>(&self, ddl: &str, notify: F)
+    where F: Fn(&dyn IDdlListener) -> Result<()> {
+        for listener in self.ddl_listeners.read().iter() {
+            if let Err(e) = notify(listener.as_ref()) {
+                error!("DDL listener {} failed on {}: {}", listener.name(), ddl, e);
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
 impl IDataSource for DataSource {
     fn get_database(&self, db_name: &str) -> Result<Arc<dyn IDatabase>> {
-        let db_lock = self.databases.read();
-        let database = db_lock.get(db_name).ok_or_else(|| {
-            ErrorCodes::UnknownDatabase(format!("Unknown database: '{}'", db_name))
-        })?;
-        Ok(database.clone())
+        let database =
+            self.databases.read().get(db_name).cloned().ok_or_else(|| {
+                ErrorCodes::UnknownDatabase(format!("Unknown database: '{}'", db_name))
+            });
+        self.record_lookup("get_database", database)
     }
 
     fn get_databases(&self) -> Result<Vec<String>> {
@@ -123,13 +282,22 @@ impl IDataSource for DataSource {
     }
 
     fn get_table(&self, db_name: &str, table_name: &str) -> Result<Arc<dyn ITable>> {
-        let db_lock = self.databases.read();
-        let database = db_lock.get(db_name).ok_or_else(|| {
-            ErrorCodes::UnknownDatabase(format!("Unknown database: '{}'", db_name))
-        })?;
+        let database =
+            self.databases.read().get(db_name).cloned().ok_or_else(|| {
+                ErrorCodes::UnknownDatabase(format!("Unknown database: '{}'", db_name))
+            });
+        let table = database.and_then(|database| database.get_table(table_name));
+        self.record_lookup("get_table", table)
+    }
 
-        let table = database.get_table(table_name)?;
-        Ok(table.clone())
+    fn get_table_meta(&self, db_name: &str, table_name: &str) -> Result<TableMeta> {
+        let database = self.get_database(db_name)?;
+        database.get_table_meta(table_name)
+    }
+
+    fn get_tables(&self, db_name: &str) -> Result<Vec<Arc<dyn ITable>>> {
+        let database = self.get_database(db_name)?;
+        database.get_tables()
     }
 
     fn get_all_tables(&self) -> Result<Vec<(String, Arc<dyn ITable>)>> {
@@ -144,15 +312,35 @@ impl IDataSource for DataSource {
     }
 
     fn get_table_function(&self, name: &str) -> Result<Arc<dyn ITableFunction>> {
-        let table_func_lock = self.table_functions.read();
-        let table = table_func_lock.get(name).ok_or_else(|| {
-            ErrorCodes::UnknownTableFunction(format!("Unknown table function: '{}'", name))
-        })?;
+        let table_function = self
+            .table_functions
+            .read()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCodes::UnknownTableFunction(format!("Unknown table function: '{}'", name))
+            });
+        self.record_lookup("get_table_function", table_function)
+    }
 
-        Ok(table.clone())
+    fn list_table_functions(&self) -> Result<Vec<TableFunctionInfo>> {
+        let mut infos = self
+            .table_functions
+            .read()
+            .iter()
+            .map(|(name, table_function)| {
+                Ok(TableFunctionInfo {
+                    name: name.clone(),
+                    schema: table_function.schema()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(infos)
     }
 
     async fn create_database(&self, plan: CreateDatabasePlan) -> Result<()> {
+        let _guard = self.database_ddl_lock.lock().await;
         let db_name = plan.db.as_str();
         if self.databases.read().get(db_name).is_some() {
             return if plan.if_not_exists {
@@ -165,32 +353,17 @@ impl IDataSource for DataSource {
             };
         }
 
-        match plan.engine {
-            DatabaseEngineType::Local => {
-                let database = LocalDatabase::create();
-                self.databases.write().insert(plan.db, Arc::new(database));
-            }
-            DatabaseEngineType::Remote => {
-                let mut client = self
-                    .remote_factory
-                    .store_client_provider()
-                    .try_get_client()
-                    .await?;
-                client.create_database(plan.clone()).await.map(|_| {
-                    let database = RemoteDatabase::create(
-                        self.remote_factory.store_client_provider(),
-                        plan.db.clone(),
-                    );
-                    self.databases
-                        .write()
-                        .insert(plan.db.clone(), Arc::new(database));
-                })?;
-            }
-        }
+        let database = self.build_database(&plan).await?;
+        self.databases.write().insert(plan.db.clone(), database);
+
+        self.notify_ddl_listeners("create database", |listener| {
+            listener.on_create_database(&plan)
+        });
         Ok(())
     }
 
     async fn drop_database(&self, plan: DropDatabasePlan) -> Result<()> {
+        let _guard = self.database_ddl_lock.lock().await;
         let db_name = plan.db.as_str();
         if self.databases.read().get(db_name).is_none() {
             return if plan.if_exists {
@@ -207,16 +380,92 @@ impl IDataSource for DataSource {
         if database.is_local() {
             self.databases.write().remove(db_name);
         } else {
-            let mut client = self
-                .remote_factory
-                .store_client_provider()
-                .try_get_client()
-                .await?;
-            client.drop_database(plan.clone()).await.map(|_| {
-                self.databases.write().remove(plan.db.as_str());
-            })?;
+            self.drop_remote_database(&plan).await?;
+            self.databases.write().remove(plan.db.as_str());
+            database.invalidate_cache();
         };
 
+        self.notify_ddl_listeners("drop database", |listener| listener.on_drop_database(&plan));
+        Ok(())
+    }
+
+    async fn replace_database(&self, plan: CreateDatabasePlan) -> Result<()> {
+        let _guard = self.database_ddl_lock.lock().await;
+        let old = self.databases.read().get(plan.db.as_str()).cloned();
+        let drop_plan = DropDatabasePlan {
+            if_exists: true,
+            db: plan.db.clone(),
+        };
+
+        // The store has one database of the name, the new remote database can't be created
+        // before the old one is dropped, and the old one is lost if the creation fails.
+        let old_remote = old.as_ref().map_or(false, |database| !database.is_local());
+        if old_remote && plan.engine == DatabaseEngineType::Remote {
+            return Err(ErrorCodes::UnImplement(format!(
+                "Cannot replace remote database '{}' with a remote one, drop it first",
+                plan.db
+            )));
+        }
+
+        // The old database is dropped only after the new one is created,
+        // a failure of either keeps the old one untouched.
+        let database = self.build_database(&plan).await?;
+        if let Some(old) = old.as_ref().filter(|database| !database.is_local()) {
+            self.drop_remote_database(&drop_plan).await?;
+            old.invalidate_cache();
+        }
+        self.databases.write().insert(plan.db.clone(), database);
+
+        if old.is_some() {
+            self.notify_ddl_listeners("drop database", |listener| {
+                listener.on_drop_database(&drop_plan)
+            });
+        }
+        self.notify_ddl_listeners("create database", |listener| {
+            listener.on_create_database(&plan)
+        });
+        Ok(())
+    }
+
+    async fn create_table(&self, plan: CreateTablePlan) -> Result<()> {
+        let database = self.get_database(plan.db.as_str())?;
+        database.create_table(plan.clone()).await?;
+
+        self.notify_ddl_listeners("create table", |listener| listener.on_create_table(&plan));
+        Ok(())
+    }
+
+    async fn drop_table(&self, plan: DropTablePlan) -> Result<()> {
+        let database = self.get_database(plan.db.as_str())?;
+        database.drop_table(plan.clone()).await?;
+
+        self.notify_ddl_listeners("drop table", |listener| listener.on_drop_table(&plan));
         Ok(())
     }
+
+    async fn rename_table(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        new_table_name: &str,
+    ) -> Result<()> {
+        let database = self.get_database(db_name)?;
+        database.rename_table(table_name, new_table_name).await?;
+
+        self.notify_ddl_listeners("rename table", |listener| {
+            listener.on_rename_table(db_name, table_name, new_table_name)
+        });
+        Ok(())
+    }
+
+    fn register_ddl_listener(&self, listener: Arc<dyn IDdlListener>) {
+        self.ddl_listeners.write().push(listener);
+    }
+
+    fn snapshot(&self) -> Result<DataSourceSnapshot> {
+        Ok(DataSourceSnapshot::create(
+            self.databases.read().clone(),
+            self.table_functions.read().clone(),
+        ))
+    }
 }
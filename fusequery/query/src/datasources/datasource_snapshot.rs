@@ -0,0 +1,66 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCodes;
+use common_exception::Result;
+
+use crate::datasources::IDatabase;
+use crate::datasources::ITable;
+use crate::datasources::ITableFunction;
+
+/// An immutable view of the databases and table functions of a DataSource,
+/// so that one query planning pass sees a stable catalog while DDL runs concurrently.
+/// Note: the tables are still resolved from the databases at lookup time.
+#[derive(Clone)]
+pub struct DataSourceSnapshot {
+    databases: HashMap<String, Arc<dyn IDatabase>>,
+    table_functions: HashMap<String, Arc<dyn ITableFunction>>,
+}
+
+impl DataSourceSnapshot {
+    pub fn create(
+        databases: HashMap<String, Arc<dyn IDatabase>>,
+        table_functions: HashMap<String, Arc<dyn ITableFunction>>,
+    ) -> Self {
+        DataSourceSnapshot {
+            databases,
+            table_functions,
+        }
+    }
+
+    pub fn get_database(&self, db_name: &str) -> Result<Arc<dyn IDatabase>> {
+        let database = self.databases.get(db_name).ok_or_else(|| {
+            ErrorCodes::UnknownDatabase(format!("Unknown database: '{}'", db_name))
+        })?;
+        Ok(database.clone())
+    }
+
+    pub fn get_databases(&self) -> Result<Vec<String>> {
+        Ok(self.databases.keys().cloned().collect())
+    }
+
+    pub fn get_table(&self, db_name: &str, table_name: &str) -> Result<Arc<dyn ITable>> {
+        self.get_database(db_name)?.get_table(table_name)
+    }
+
+    pub fn get_all_tables(&self) -> Result<Vec<(String, Arc<dyn ITable>)>> {
+        let mut results = vec![];
+        for (k, v) in self.databases.iter() {
+            for table in v.get_tables()? {
+                results.push((k.clone(), table));
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn get_table_function(&self, name: &str) -> Result<Arc<dyn ITableFunction>> {
+        let table = self.table_functions.get(name).ok_or_else(|| {
+            ErrorCodes::UnknownTableFunction(format!("Unknown table function: '{}'", name))
+        })?;
+        Ok(table.clone())
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+use common_planners::CreateDatabasePlan;
+use common_planners::CreateTablePlan;
+use common_planners::DropDatabasePlan;
+use common_planners::DropTablePlan;
+
+/// Listener of the catalog changes, such as metrics or cache invalidation.
+/// The callbacks are invoked after the DDL succeeded, a failed callback is logged
+/// and never fails the DDL.
+pub trait IDdlListener: Sync + Send {
+    fn name(&self) -> &str;
+
+    fn on_create_database(&self, _plan: &CreateDatabasePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_drop_database(&self, _plan: &DropDatabasePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_create_table(&self, _plan: &CreateTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_drop_table(&self, _plan: &DropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_rename_table(&self, _db: &str, _table: &str, _new_table: &str) -> Result<()> {
+        Ok(())
+    }
+}
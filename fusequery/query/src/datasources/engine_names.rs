@@ -0,0 +1,56 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+//! The names reported by the `engine()` of the databases and tables,
+//! system.engines lists the database and table engines from here.
+
+// Database engines, the same as `DatabaseEngineType::to_string`.
+pub const LOCAL_DATABASE: &str = "Local";
+pub const REMOTE_DATABASE: &str = "Remote";
+pub const SYSTEM_DATABASE: &str = "System";
+
+// Table engines.
+pub const NULL: &str = "Null";
+pub const CSV: &str = "CSV";
+pub const PARQUET: &str = "Parquet";
+pub const REMOTE: &str = "remote";
+pub const SYSTEM_CLUSTERS: &str = "SystemClusters";
+pub const SYSTEM_CONTRIBUTORS: &str = "SystemContributors";
+pub const SYSTEM_DATABASES: &str = "SystemDatabases";
+pub const SYSTEM_ENGINES: &str = "SystemEngines";
+pub const SYSTEM_FUNCTIONS: &str = "SystemFunctions";
+pub const SYSTEM_GENERATE_SERIES: &str = "SystemGenerateSeries";
+pub const SYSTEM_NUMBERS: &str = "SystemNumbers";
+pub const SYSTEM_NUMBERS_MT: &str = "SystemNumbersMt";
+pub const SYSTEM_NUMBERS_LOCAL: &str = "SystemNumbersLocal";
+pub const SYSTEM_ONE: &str = "SystemOne";
+pub const SYSTEM_SESSIONS: &str = "SystemSessions";
+pub const SYSTEM_SETTINGS: &str = "SystemSettings";
+pub const SYSTEM_TABLE_FUNCTIONS: &str = "SystemTableFunctions";
+pub const SYSTEM_TABLES: &str = "SystemTables";
+pub const SYSTEM_TRACING_LOG: &str = "SystemTracingLog";
+
+pub const DATABASE_ENGINES: &[&str] = &[LOCAL_DATABASE, REMOTE_DATABASE, SYSTEM_DATABASE];
+
+pub const TABLE_ENGINES: &[&str] = &[
+    NULL,
+    CSV,
+    PARQUET,
+    REMOTE,
+    SYSTEM_CLUSTERS,
+    SYSTEM_CONTRIBUTORS,
+    SYSTEM_DATABASES,
+    SYSTEM_ENGINES,
+    SYSTEM_FUNCTIONS,
+    SYSTEM_GENERATE_SERIES,
+    SYSTEM_NUMBERS,
+    SYSTEM_NUMBERS_MT,
+    SYSTEM_NUMBERS_LOCAL,
+    SYSTEM_ONE,
+    SYSTEM_SESSIONS,
+    SYSTEM_SETTINGS,
+    SYSTEM_TABLE_FUNCTIONS,
+    SYSTEM_TABLES,
+    SYSTEM_TRACING_LOG,
+];
@@ -16,6 +16,7 @@ use common_planners::Statistics;
 use common_planners::TableOptions;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::local::CsvTableStream;
 use crate::datasources::Common;
 use crate::datasources::ITable;
@@ -63,7 +64,7 @@ impl ITable for CsvTable {
     }
 
     fn engine(&self) -> &str {
-        "CSV"
+        engine_names::CSV
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -80,9 +81,9 @@ impl ITable for CsvTable {
 
     fn read_plan(
         &self,
-        ctx: FuseQueryContextRef,
+        _ctx: FuseQueryContextRef,
         scan: &ScanPlan,
-        _partitions: usize,
+        partitions: usize,
     ) -> Result<ReadDataSourcePlan> {
         let start_line: usize = if self.has_header { 1 } else { 0 };
         let file = &self.file;
@@ -92,16 +93,14 @@ impl ITable for CsvTable {
                 .map_err(ErrorCodes::from)?,
         )
         .map_err(|e| ErrorCodes::CannotReadFile(e.to_string()))?;
+        // Split the lines into up to `partitions` ranges, one per reader.
+        let workers = std::cmp::max(partitions, 1) as u64;
 
         Ok(ReadDataSourcePlan {
             db: self.db.clone(),
             table: self.name().to_string(),
-            schema: self.schema.clone(),
-            partitions: Common::generate_parts(
-                start_line as u64,
-                ctx.get_max_threads()?,
-                lines_count as u64,
-            ),
+            schema: Common::project_schema(&self.schema, &scan.projection)?,
+            partitions: Common::generate_parts(start_line as u64, workers, lines_count as u64),
             statistics: Statistics::default(),
             description: format!("(Read from CSV Engine table  {}.{})", self.db, self.name),
             scan_plan: Arc::new(scan.clone()),
@@ -132,3 +132,56 @@ async fn test_csv_table_parse_error() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_csv_table_read_plan_partitions() -> anyhow::Result<()> {
+    use std::env;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    let options: TableOptions = [(
+        "location".to_string(),
+        env::current_dir()?
+            .join("../../tests/data/sample.csv")
+            .display()
+            .to_string(),
+    )]
+    .iter()
+    .cloned()
+    .collect();
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = CsvTable::try_create(
+        "default".into(),
+        "test_csv".into(),
+        DataSchemaRefExt::create(vec![DataField::new("column1", DataType::UInt64, false)]).into(),
+        options,
+    )?;
+
+    // 6 lines split into up to 4 partitions.
+    let source_plan = table.read_plan(ctx.clone(), &ScanPlan::empty(), 4)?;
+    assert_eq!(4, source_plan.partitions.len());
+    let part_rows: Vec<u64> = source_plan.partitions.iter().map(|p| p.rows).collect();
+    assert_eq!(vec![1, 1, 1, 3], part_rows);
+    ctx.try_set_partitions(source_plan.partitions)?;
+
+    let stream = table.read(ctx.clone()).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(6, rows);
+
+    // Fewer lines than partitions falls back to a single partition.
+    let source_plan = table.read_plan(ctx.clone(), &ScanPlan::empty(), 16)?;
+    assert_eq!(1, source_plan.partitions.len());
+
+    // Zero partitions is treated as one.
+    let source_plan = table.read_plan(ctx, &ScanPlan::empty(), 0)?;
+    assert_eq!(1, source_plan.partitions.len());
+
+    Ok(())
+}
@@ -12,6 +12,7 @@ use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 use common_planners::TableEngineType;
 
+use crate::datasources::engine_names;
 use crate::datasources::local::CsvTable;
 use crate::datasources::local::NullTable;
 use crate::datasources::local::ParquetTable;
@@ -29,6 +30,11 @@ impl LocalDatabase {
             tables: RwLock::new(HashMap::default()),
         }
     }
+
+    /// Add a table, it replaces the table of the same name.
+    pub fn register_table(&self, table: Arc<dyn ITable>) {
+        self.tables.write().insert(table.name().to_string(), table);
+    }
 }
 
 #[async_trait::async_trait]
@@ -38,7 +44,7 @@ impl IDatabase for LocalDatabase {
     }
 
     fn engine(&self) -> &str {
-        "local"
+        engine_names::LOCAL_DATABASE
     }
 
     fn is_local(&self) -> bool {
@@ -57,6 +63,10 @@ impl IDatabase for LocalDatabase {
         Ok(self.tables.read().values().cloned().collect())
     }
 
+    fn get_table_names(&self) -> Result<Vec<String>> {
+        Ok(self.tables.read().keys().cloned().collect())
+    }
+
     fn get_table_functions(&self) -> Result<Vec<Arc<dyn ITableFunction>>> {
         Ok(vec![])
     }
@@ -76,7 +86,7 @@ impl IDatabase for LocalDatabase {
             };
         }
 
-        let table = match &plan.engine {
+        let table = match plan.engine {
             TableEngineType::Parquet => {
                 ParquetTable::try_create(plan.db, plan.table, plan.schema, plan.options)?
             }
@@ -86,10 +96,11 @@ impl IDatabase for LocalDatabase {
             TableEngineType::Null => {
                 NullTable::try_create(plan.db, plan.table, plan.schema, plan.options)?
             }
-            _ => {
-                return Result::Err(ErrorCodes::UnImplement(format!(
-                    "Local database does not support '{:?}' table engine",
-                    plan.engine
+            TableEngineType::JsonEachRaw => {
+                return Result::Err(ErrorCodes::UnknownTableEngine(format!(
+                    "Local database does not support '{}' table engine, the supported engines are: {}",
+                    plan.engine.to_string(),
+                    [engine_names::NULL, engine_names::CSV, engine_names::PARQUET].join(", ")
                 )));
             }
         };
@@ -0,0 +1,92 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test]
+async fn test_local_database_get_table_names() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+
+    use crate::datasources::local::*;
+    use crate::datasources::*;
+
+    let database = LocalDatabase::create();
+    assert!(database.get_table_names()?.is_empty());
+
+    for name in &["t1", "t2", "t3"] {
+        database
+            .create_table(CreateTablePlan {
+                if_not_exists: false,
+                db: "local".to_string(),
+                table: name.to_string(),
+                schema: DataSchemaRefExt::create(vec![DataField::new(
+                    "a",
+                    DataType::UInt64,
+                    false,
+                )]),
+                engine: TableEngineType::Null,
+                options: TableOptions::default(),
+            })
+            .await?;
+    }
+
+    let mut names = database.get_table_names()?;
+    names.sort();
+    assert_eq!(names, vec!["t1", "t2", "t3"]);
+
+    let mut table_names = database
+        .get_tables()?
+        .iter()
+        .map(|table| table.name().to_string())
+        .collect::<Vec<_>>();
+    table_names.sort();
+    assert_eq!(names, table_names);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_local_database_create_table_engines() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+    use crate::datasources::*;
+
+    let database = LocalDatabase::create();
+    let plan = |table: &str, engine: TableEngineType| CreateTablePlan {
+        if_not_exists: false,
+        db: "local".to_string(),
+        table: table.to_string(),
+        schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+        engine,
+        options: TableOptions::default(),
+    };
+
+    // Null engine.
+    {
+        database
+            .create_table(plan("t_null", TableEngineType::Null))
+            .await?;
+        let table = database.get_table("t_null")?;
+        assert_eq!(table.engine(), "Null");
+        assert!(table.downcast_ref::<NullTable>().is_some());
+    }
+
+    // Unsupported engine.
+    {
+        let result = database
+            .create_table(plan("t_json", TableEngineType::JsonEachRaw))
+            .await;
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), 40);
+        assert_eq!(
+            err.to_string(),
+            "Code: 40, displayText = Local database does not support 'JSONEachRaw' table engine, the supported engines are: Null, CSV, Parquet."
+        );
+        assert!(database.get_table("t_json").is_err());
+    }
+
+    Ok(())
+}
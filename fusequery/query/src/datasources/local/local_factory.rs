@@ -2,22 +2,119 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::fs::File;
 use std::sync::Arc;
 
+use common_arrow::arrow::csv;
+use common_arrow::parquet::arrow::ArrowReader;
+use common_arrow::parquet::arrow::ParquetFileArrowReader;
+use common_arrow::parquet::file::reader::SerializedFileReader;
+use common_exception::ErrorCodes;
 use common_exception::Result;
+use common_planners::TableOptions;
+use log::warn;
 
+use crate::configs::Config;
+use crate::datasources::local::CsvTable;
 use crate::datasources::local::LocalDatabase;
+use crate::datasources::local::ParquetTable;
 use crate::datasources::IDatabase;
+use crate::datasources::ITable;
 
-pub struct LocalFactory;
+pub struct LocalFactory {
+    // The CSV and Parquet files of the dir are loaded as the tables, empty means none.
+    data_dir: String,
+}
 
 impl LocalFactory {
-    pub fn create() -> Self {
-        Self
+    pub fn create(conf: &Config) -> Self {
+        LocalFactory {
+            data_dir: conf.local_data_dir.clone(),
+        }
     }
 
     pub fn load_databases(&self) -> Result<Vec<Arc<dyn IDatabase>>> {
-        let databases: Vec<Arc<dyn IDatabase>> = vec![Arc::new(LocalDatabase::create())];
+        let database = LocalDatabase::create();
+        if !self.data_dir.is_empty() {
+            for table in Self::load_tables(database.name(), &self.data_dir)? {
+                database.register_table(table);
+            }
+        }
+
+        let databases: Vec<Arc<dyn IDatabase>> = vec![Arc::new(database)];
         Ok(databases)
     }
+
+    // The CSV(with the header) and Parquet files become the tables named by the file stems,
+    // the other files are skipped with a warning.
+    fn load_tables(db: &str, data_dir: &str) -> Result<Vec<Arc<dyn ITable>>> {
+        let entries = std::fs::read_dir(data_dir)
+            .map_err(|e| ErrorCodes::CannotReadFile(format!("Dir: {}, err: {:?}", data_dir, e)))?;
+
+        let mut tables = vec![];
+        for entry in entries {
+            let path = entry
+                .map_err(|e| {
+                    ErrorCodes::CannotReadFile(format!("Dir: {}, err: {:?}", data_dir, e))
+                })?
+                .path();
+            if !path.is_file() {
+                continue;
+            }
+            let location = path.display().to_string();
+
+            let name = path.file_stem().and_then(|stem| stem.to_str());
+            let extension = path.extension().and_then(|extension| extension.to_str());
+            let table = match (name, extension.map(|extension| extension.to_lowercase())) {
+                (Some(name), Some(extension)) if extension == "csv" => {
+                    Self::load_csv_table(db, name, location)?
+                }
+                (Some(name), Some(extension)) if extension == "parquet" => {
+                    Self::load_parquet_table(db, name, location)?
+                }
+                _ => {
+                    warn!(
+                        "Skip the unsupported file of the local data dir: {}",
+                        location
+                    );
+                    continue;
+                }
+            };
+            tables.push(Arc::from(table));
+        }
+        Ok(tables)
+    }
+
+    fn load_csv_table(db: &str, name: &str, location: String) -> Result<Box<dyn ITable>> {
+        let mut file = File::open(&location)
+            .map_err(|e| ErrorCodes::CannotReadFile(format!("File: {}, err: {:?}", location, e)))?;
+        let (schema, _) = csv::reader::infer_file_schema(&mut file, b',', Some(1000), true)
+            .map_err(|e| ErrorCodes::CannotReadFile(format!("File: {}, err: {}", location, e)))?;
+
+        let options: TableOptions = [
+            ("location".to_string(), location),
+            ("has_header".to_string(), "true".to_string()),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        CsvTable::try_create(db.to_string(), name.to_string(), Arc::new(schema), options)
+    }
+
+    fn load_parquet_table(db: &str, name: &str, location: String) -> Result<Box<dyn ITable>> {
+        let file = File::open(&location)
+            .map_err(|e| ErrorCodes::CannotReadFile(format!("File: {}, err: {:?}", location, e)))?;
+        let file_reader =
+            SerializedFileReader::new(file).map_err(|e| ErrorCodes::ParquetError(e.to_string()))?;
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        let schema = arrow_reader
+            .get_schema()
+            .map_err(|e| ErrorCodes::ParquetError(e.to_string()))?;
+
+        let options: TableOptions = [("location".to_string(), location)]
+            .iter()
+            .cloned()
+            .collect();
+        ParquetTable::try_create(db.to_string(), name.to_string(), Arc::new(schema), options)
+    }
 }
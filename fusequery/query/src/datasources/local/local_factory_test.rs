@@ -0,0 +1,47 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_local_factory_data_dir() -> anyhow::Result<()> {
+    use std::fs;
+
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::datasources::local::*;
+    use crate::datasources::*;
+
+    let dir = tempfile::tempdir()?;
+    fs::write(dir.path().join("people.csv"), "id,name\n1,alice\n2,bob\n")?;
+    fs::write(dir.path().join("notes.txt"), "not a table")?;
+
+    let mut conf = Config::default();
+    conf.local_data_dir = dir.path().display().to_string();
+    let databases = LocalFactory::create(&conf).load_databases()?;
+    assert_eq!(1, databases.len());
+
+    // The unsupported notes.txt is skipped.
+    let database = &databases[0];
+    assert_eq!(vec!["people".to_string()], database.get_table_names()?);
+    let table = database.get_table("people")?;
+    assert_eq!("CSV", table.engine());
+    assert_eq!(
+        DataSchemaRefExt::create(vec![
+            DataField::new("id", DataType::Int64, true),
+            DataField::new("name", DataType::Utf8, true),
+        ]),
+        table.schema()?
+    );
+
+    // No data dir.
+    let databases = LocalFactory::create(&Config::default()).load_databases()?;
+    assert!(databases[0].get_tables()?.is_empty());
+
+    // Missing data dir.
+    conf.local_data_dir = dir.path().join("missing").display().to_string();
+    assert!(LocalFactory::create(&conf).load_databases().is_err());
+
+    Ok(())
+}
@@ -5,6 +5,10 @@
 #[cfg(test)]
 mod csv_table_test;
 #[cfg(test)]
+mod local_database_test;
+#[cfg(test)]
+mod local_factory_test;
+#[cfg(test)]
 mod null_table_test;
 #[cfg(test)]
 mod parquet_table_test;
@@ -7,7 +7,9 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCodes;
 use common_exception::Result;
+use common_planners::InsertIntoPlan;
 use common_planners::Partition;
 use common_planners::ReadDataSourcePlan;
 use common_planners::ScanPlan;
@@ -16,6 +18,8 @@ use common_planners::TableOptions;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
+use crate::datasources::Common;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -44,7 +48,7 @@ impl ITable for NullTable {
     }
 
     fn engine(&self) -> &str {
-        "Null"
+        engine_names::NULL
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -59,6 +63,10 @@ impl ITable for NullTable {
         true
     }
 
+    fn is_writable(&self) -> bool {
+        true
+    }
+
     fn read_plan(
         &self,
         _ctx: FuseQueryContextRef,
@@ -68,10 +76,12 @@ impl ITable for NullTable {
         Ok(ReadDataSourcePlan {
             db: self.db.clone(),
             table: self.name().to_string(),
-            schema: self.schema.clone(),
+            schema: Common::project_schema(&self.schema, &scan.projection)?,
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: format!("(Read from Null Engine table  {}.{})", self.db, self.name),
@@ -88,4 +98,19 @@ impl ITable for NullTable {
             vec![block],
         )))
     }
+
+    async fn checksum(&self, _ctx: FuseQueryContextRef) -> Result<u64> {
+        // Null engine holds no rows.
+        Ok(0)
+    }
+
+    async fn append_data(&self, _ctx: FuseQueryContextRef, plan: InsertIntoPlan) -> Result<()> {
+        // Null engine discards all the inserted data.
+        let opt_stream = {
+            let mut inner = plan.input_stream.lock().unwrap();
+            (*inner).take()
+        };
+        opt_stream.ok_or_else(|| ErrorCodes::EmptyData("input stream consumed"))?;
+        Ok(())
+    }
 }
@@ -23,6 +23,8 @@ async fn test_null_table() -> anyhow::Result<()> {
         ctx.get_max_threads()? as usize,
     )?;
     assert_eq!(table.engine(), "Null");
+    assert!(table.is_writable());
+    assert!(table.cluster_keys().is_empty());
 
     let stream = table.read(ctx).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
@@ -31,3 +33,75 @@ async fn test_null_table() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_null_table_read_plan_projection() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = NullTable::try_create(
+        "default".into(),
+        "a".into(),
+        DataSchemaRefExt::create(vec![
+            DataField::new("a", DataType::UInt64, false),
+            DataField::new("b", DataType::Utf8, false),
+            DataField::new("c", DataType::Boolean, false),
+        ]),
+        TableOptions::default(),
+    )?;
+
+    // No projection keeps all the columns.
+    let plan = table.read_plan(ctx.clone(), &ScanPlan::empty(), 1)?;
+    assert_eq!(3, plan.schema.fields().len());
+
+    // Projection narrows the schema.
+    let scan = ScanPlan {
+        projection: Some(vec![2, 0]),
+        ..ScanPlan::empty()
+    };
+    let plan = table.read_plan(ctx.clone(), &scan, 1)?;
+    assert_eq!(
+        DataSchemaRefExt::create(vec![
+            DataField::new("c", DataType::Boolean, false),
+            DataField::new("a", DataType::UInt64, false),
+        ]),
+        plan.schema
+    );
+
+    // Out of range projection.
+    let scan = ScanPlan {
+        projection: Some(vec![3]),
+        ..ScanPlan::empty()
+    };
+    let result = table.read_plan(ctx, &scan, 1);
+    assert_eq!(
+        "Code: 6, displayText = Projection index 3 is out of range, schema has 3 columns.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_null_table_downcast() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+
+    use crate::datasources::local::*;
+
+    let table = NullTable::try_create(
+        "default".into(),
+        "a".into(),
+        DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+        TableOptions::default(),
+    )?;
+
+    assert!(table.downcast_ref::<NullTable>().is_some());
+    assert!(table.downcast_ref::<CsvTable>().is_none());
+
+    Ok(())
+}
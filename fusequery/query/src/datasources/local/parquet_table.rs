@@ -3,12 +3,14 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fs::File;
 use std::sync::Arc;
 
 use common_arrow::parquet::arrow::ArrowReader;
 use common_arrow::parquet::arrow::ParquetFileArrowReader;
+use common_arrow::parquet::file::reader::FileReader;
 use common_arrow::parquet::file::reader::SerializedFileReader;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
@@ -26,6 +28,8 @@ use crossbeam::channel::Receiver;
 use crossbeam::channel::Sender;
 use tokio::task;
 
+use crate::datasources::engine_names;
+use crate::datasources::Common;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -61,6 +65,35 @@ impl ParquetTable {
     }
 }
 
+// The null counts of the columns from the statistics of the row groups,
+// a column is left out if any of its row groups has no statistics.
+fn read_null_counts(file: &str, schema: &DataSchemaRef) -> Result<HashMap<String, u64>> {
+    let file_reader = File::open(file).map_err(|e| ErrorCodes::CannotReadFile(e.to_string()))?;
+    let file_reader = SerializedFileReader::new(file_reader)
+        .map_err(|e| ErrorCodes::ParquetError(e.to_string()))?;
+
+    let mut null_counts = HashMap::new();
+    let mut unknown = vec![];
+    for row_group in file_reader.metadata().row_groups() {
+        for column in row_group.columns() {
+            let name = column.column_descr().name().to_string();
+            if schema.field_with_name(&name).is_err() || unknown.contains(&name) {
+                continue;
+            }
+            match column.statistics() {
+                Some(statistics) => {
+                    *null_counts.entry(name).or_insert(0) += statistics.null_count();
+                }
+                None => {
+                    null_counts.remove(&name);
+                    unknown.push(name);
+                }
+            }
+        }
+    }
+    Ok(null_counts)
+}
+
 fn read_file(
     file: &str,
     tx: Sender<Option<Result<DataBlock>>>,
@@ -108,7 +141,7 @@ impl ITable for ParquetTable {
     }
 
     fn engine(&self) -> &str {
-        "Parquet"
+        engine_names::PARQUET
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -129,15 +162,22 @@ impl ITable for ParquetTable {
         scan: &ScanPlan,
         _partitions: usize,
     ) -> Result<ReadDataSourcePlan> {
+        let statistics = Statistics {
+            null_counts: Some(read_null_counts(&self.file, &self.schema)?),
+            ..Statistics::default()
+        };
+
         Ok(ReadDataSourcePlan {
             db: self.db.clone(),
             table: self.name().to_string(),
-            schema: self.schema.clone(),
+            schema: Common::project_schema(&self.schema, &scan.projection)?,
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
-            statistics: Statistics::default(),
+            statistics,
             description: format!(
                 "(Read from Parquet Engine table  {}.{})",
                 self.db, self.name
@@ -43,3 +43,61 @@ async fn test_parquet_table() -> anyhow::Result<()> {
     assert_eq!(rows, 8);
     Ok(())
 }
+
+#[test]
+fn test_parquet_table_null_counts() -> anyhow::Result<()> {
+    use std::convert::TryFrom;
+    use std::fs::File;
+    use std::sync::Arc;
+
+    use common_arrow::arrow::record_batch::RecordBatch;
+    use common_arrow::parquet::arrow::ArrowWriter;
+    use common_datablocks::DataBlock;
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Utf8, true),
+    ]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Arc::new(Int64Array::from(vec![Some(1), None, Some(3), None, None])),
+        Arc::new(StringArray::from(vec![
+            Some("x"),
+            Some("y"),
+            None,
+            Some("z"),
+            Some("w"),
+        ])),
+    ]);
+
+    let dir = tempfile::tempdir()?;
+    let location = dir.path().join("nulls.parquet").display().to_string();
+    {
+        let batch = RecordBatch::try_from(block)?;
+        let mut writer = ArrowWriter::try_new(File::create(&location)?, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+    }
+
+    let options: TableOptions = [("location".to_string(), location)]
+        .iter()
+        .cloned()
+        .collect();
+    let ctx = crate::tests::try_create_context()?;
+    let table = ParquetTable::try_create(
+        "default".into(),
+        "test_parquet_nulls".into(),
+        schema,
+        options,
+    )?;
+    let plan = table.read_plan(ctx, &ScanPlan::empty(), 1)?;
+
+    assert_eq!(Some(3), plan.statistics.null_count("a"));
+    assert_eq!(Some(1), plan.statistics.null_count("b"));
+    assert_eq!(None, plan.statistics.null_count("c"));
+    Ok(())
+}
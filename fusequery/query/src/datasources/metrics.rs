@@ -0,0 +1,7 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+// Labeled by the lookup method, e.g. get_table.
+pub static METRIC_DATASOURCE_LOOKUP_HITS: &str = "datasource.lookup_hits";
+pub static METRIC_DATASOURCE_LOOKUP_MISSES: &str = "datasource.lookup_misses";
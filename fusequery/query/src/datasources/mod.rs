@@ -5,20 +5,32 @@
 #[cfg(test)]
 mod common_test;
 #[cfg(test)]
+mod table_test;
+#[cfg(test)]
 mod tests;
 
 mod common;
 mod database;
 mod datasource;
+mod datasource_snapshot;
+mod ddl_listener;
+pub mod engine_names;
 mod local;
+mod metrics;
 mod remote;
 mod system;
 mod table;
 mod table_function;
+mod table_meta;
 
 pub use common::Common;
 pub use database::IDatabase;
 pub use datasource::DataSource;
 pub use datasource::IDataSource;
+pub use datasource_snapshot::DataSourceSnapshot;
+pub use ddl_listener::IDdlListener;
+pub use system::SystemFactory;
 pub use table::ITable;
 pub use table_function::ITableFunction;
+pub use table_function::TableFunctionInfo;
+pub use table_meta::TableMeta;
@@ -1,10 +1,26 @@
 // Copyright 2020-2021 The Datafuse Authors.
 //
 // SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod remote_database_test;
+#[cfg(test)]
+mod remote_table_cache_test;
+#[cfg(test)]
+mod retry_policy_test;
+#[cfg(test)]
+pub(crate) mod store_client_mock;
+
 mod remote_database;
 mod remote_factory;
 mod remote_table;
+mod remote_table_cache;
+mod retry_policy;
 mod store_client_provider;
 
 pub use remote_database::RemoteDatabase;
 pub use remote_factory::RemoteFactory;
+pub use retry_policy::RetryPolicy;
+pub use store_client_provider::IStoreClient;
+pub use store_client_provider::IStoreClientProvider;
+pub use store_client_provider::StoreClientProvider;
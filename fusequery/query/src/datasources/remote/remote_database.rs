@@ -2,35 +2,92 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::ErrorCodes;
 use common_exception::Result;
-use common_infallible::RwLock;
+use common_flights::StatusError;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
+use common_planners::TableOptions;
+use common_runtime::Runtime;
+use lazy_static::lazy_static;
+use tonic::Code;
 
+use crate::datasources::engine_names;
 use crate::datasources::remote::remote_table::RemoteTable;
+use crate::datasources::remote::remote_table_cache::RemoteTableCache;
+use crate::datasources::remote::remote_table_cache::DEFAULT_TABLE_CACHE_TTL;
 use crate::datasources::remote::store_client_provider::StoreClientProvider;
 use crate::datasources::IDatabase;
 use crate::datasources::ITable;
 use crate::datasources::ITableFunction;
 
+lazy_static! {
+    // Runs the store calls of the synchronous lookups, such as get_table.
+    // The caller's thread is blocked until the call completes, up to the store client timeout,
+    // even if it's a worker of the query runtime, as the planner looks up the tables synchronously.
+    // The single worker is shared by all the lookups of the process, their calls interleave on it,
+    // so a slow store delays every lookup. The cached tables are served without blocking.
+    // tokio::task::block_in_place can't be used, it panics on the current thread runtimes
+    // and tokio 1.6 can't tell the flavor of the caller's runtime.
+    static ref STORE_RUNTIME: Result<Runtime> = Runtime::with_worker_threads(1);
+}
+
 pub struct RemoteDatabase {
     name: String,
     store_client_provider: StoreClientProvider,
-    tables: RwLock<HashMap<String, Arc<dyn ITable>>>,
+    tables: RemoteTableCache,
 }
 
 impl RemoteDatabase {
     pub fn create(store_client_provider: StoreClientProvider, name: String) -> Self {
+        Self::create_with_table_cache_ttl(store_client_provider, name, DEFAULT_TABLE_CACHE_TTL)
+    }
+
+    /// The tables fetched from the store are served from the cache for `ttl`.
+    pub fn create_with_table_cache_ttl(
+        store_client_provider: StoreClientProvider,
+        name: String,
+        ttl: Duration,
+    ) -> Self {
         RemoteDatabase {
             name,
             store_client_provider,
-            tables: RwLock::new(HashMap::default()),
+            tables: RemoteTableCache::create(ttl),
         }
     }
+
+    /// Evict the table from the cache, the next get_table will go to the store.
+    pub fn invalidate(&self, table_name: &str) {
+        self.tables.invalidate(table_name);
+    }
+
+    async fn fetch_table(
+        store_client_provider: StoreClientProvider,
+        db_name: String,
+        table_name: String,
+    ) -> Result<Arc<dyn ITable>> {
+        let mut client = store_client_provider.try_get_client().await?;
+        let rst = client
+            .get_table(db_name, table_name.clone())
+            .await
+            .map_err(|e| match e.downcast_cause_ref::<StatusError>() {
+                Some(status) if status.code() == Code::NotFound => {
+                    ErrorCodes::UnknownTable(format!("Unknown table: '{}'", table_name))
+                }
+                _ => e,
+            })?;
+        let table = RemoteTable::try_create(
+            rst.db,
+            rst.name,
+            rst.schema,
+            store_client_provider,
+            TableOptions::default(),
+        )?;
+        Ok(Arc::from(table))
+    }
 }
 
 #[async_trait::async_trait]
@@ -40,36 +97,48 @@ impl IDatabase for RemoteDatabase {
     }
 
     fn engine(&self) -> &str {
-        "remote"
+        engine_names::REMOTE_DATABASE
     }
 
     fn is_local(&self) -> bool {
         false
     }
 
-    fn get_table(&self, _table_name: &str) -> Result<Arc<dyn ITable>> {
-        match self.tables.read().get(_table_name) {
-            Some(tbl) => Ok(tbl.clone()),
-            None =>
-            // Depends on the degree of staleness we can tolerate ...
-            {
-                Err(ErrorCodes::UnknownTable(_table_name))
-            }
+    fn get_table(&self, table_name: &str) -> Result<Arc<dyn ITable>> {
+        if let Some(table) = self.tables.get_fresh(table_name) {
+            return Ok(table);
         }
+
+        // The table is missing or stale, fetch it by the store runtime,
+        // the lookups are synchronous but may be called in an asynchronous context.
+        let runtime = STORE_RUNTIME
+            .as_ref()
+            .map_err(|e| ErrorCodes::TokioError(e.message()))?;
+        let table = runtime.block_on(Self::fetch_table(
+            self.store_client_provider.clone(),
+            self.name.clone(),
+            table_name.to_string(),
+        ))??;
+        self.tables.insert(table.clone());
+        Ok(table)
     }
 
     fn get_tables(&self) -> Result<Vec<Arc<dyn ITable>>> {
-        Ok(self.tables.read().values().cloned().collect())
+        Ok(self.tables.tables())
     }
 
     fn get_table_functions(&self) -> Result<Vec<Arc<dyn ITableFunction>>> {
         Ok(vec![])
     }
 
+    fn invalidate_cache(&self) {
+        self.tables.invalidate_all();
+    }
+
     async fn create_table(&self, plan: CreateTablePlan) -> Result<()> {
         let db_name = plan.db.as_str();
         let table_name = plan.table.as_str();
-        if self.tables.read().get(table_name).is_some() {
+        if self.tables.contains(table_name) {
             return if plan.if_not_exists {
                 Ok(())
             } else {
@@ -91,16 +160,16 @@ impl IDatabase for RemoteDatabase {
             plan.options,
         )?;
         let mut client = provider.try_get_client().await?;
-        client.create_table(clone).await.map(|_| {
-            let mut tables = self.tables.write();
-            tables.insert(table.name().to_string(), Arc::from(table));
-        })?;
+        client
+            .create_table(clone)
+            .await
+            .map(|_| self.tables.insert(Arc::from(table)))?;
         Ok(())
     }
 
     async fn drop_table(&self, plan: DropTablePlan) -> Result<()> {
         let table_name = plan.table.as_str();
-        if self.tables.read().get(table_name).is_none() {
+        if !self.tables.contains(table_name) {
             return if plan.if_exists {
                 Ok(())
             } else {
@@ -113,10 +182,34 @@ impl IDatabase for RemoteDatabase {
 
         // Call remote create.
         let mut client = self.store_client_provider.try_get_client().await?;
-        client.drop_table(plan.clone()).await.map(|_| {
-            let mut tables = self.tables.write();
-            tables.remove(table_name);
-        })?;
+        client
+            .drop_table(plan.clone())
+            .await
+            .map(|_| self.invalidate(table_name))?;
+        Ok(())
+    }
+
+    async fn rename_table(&self, table_name: &str, new_table_name: &str) -> Result<()> {
+        let mut client = self.store_client_provider.try_get_client().await?;
+        client
+            .rename_table(
+                self.name.clone(),
+                table_name.to_string(),
+                new_table_name.to_string(),
+            )
+            .await?;
+
+        // The store has renamed the table, move the cached one with its options.
+        let cached = self.tables.get(table_name);
+        let renamed = cached
+            .as_ref()
+            .and_then(|table| table.as_any().downcast_ref::<RemoteTable>())
+            .map(|table| table.renamed(new_table_name));
+        match renamed {
+            Some(table) => self.tables.rename(table_name, Arc::new(table)),
+            // The stale entry of the new name must not be served.
+            None => self.tables.invalidate(new_table_name),
+        }
         Ok(())
     }
 }
@@ -0,0 +1,172 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test]
+async fn test_remote_database_rename_table() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+    use tonic::Code;
+
+    use crate::datasources::remote::remote_table::RemoteTable;
+    use crate::datasources::remote::store_client_mock::MockStore;
+    use crate::datasources::remote::IStoreClient;
+    use crate::datasources::remote::RemoteDatabase;
+    use crate::datasources::*;
+
+    let store = MockStore::create();
+    store
+        .clone()
+        .create_database(CreateDatabasePlan {
+            if_not_exists: false,
+            db: "db".into(),
+            engine: DatabaseEngineType::Remote,
+            options: Default::default(),
+        })
+        .await?;
+    let db = RemoteDatabase::create(Arc::new(store.clone()), "db".into());
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]);
+    let mut options = TableOptions::default();
+    options.insert("opt".into(), "1".into());
+    db.create_table(CreateTablePlan {
+        if_not_exists: false,
+        db: "db".into(),
+        table: "a".into(),
+        schema: schema.clone(),
+        engine: TableEngineType::JsonEachRaw,
+        options: options.clone(),
+    })
+    .await?;
+
+    // The store fails the rename, the store and the cache are unchanged.
+    store.fail_next("rename_table", Code::Unavailable);
+    let result = db.rename_table("a", "b").await;
+    assert!(result.is_err());
+    assert_eq!(vec!["a".to_string()], store.tables("db"));
+    assert_eq!("a", db.get_table("a")?.name());
+    assert_eq!(0, store.calls("get_table"));
+
+    // The store succeeds, the cache has the new name only, with the options kept.
+    db.rename_table("a", "b").await?;
+    assert_eq!(vec!["b".to_string()], store.tables("db"));
+    let renamed = db.get_table("b")?;
+    assert_eq!(0, store.calls("get_table"));
+    assert_eq!("b", renamed.name());
+    assert_eq!(schema, renamed.schema()?);
+    let remote_table = renamed.as_any().downcast_ref::<RemoteTable>().unwrap();
+    assert_eq!(&options, remote_table.options());
+    assert_eq!(1, db.get_tables()?.len());
+
+    // The old name is gone from the store too.
+    let result = db.get_table("a");
+    let actual = format!("{}", result.err().unwrap());
+    assert_eq!("Code: 25, displayText = Unknown table: 'a'.", actual);
+
+    // The store rejects the rename of a missing table.
+    let result = db.rename_table("a", "c").await;
+    assert!(result.is_err());
+    assert_eq!(vec!["b".to_string()], store.tables("db"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remote_database_get_table() -> anyhow::Result<()> {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::remote::store_client_mock::MockStore;
+    use crate::datasources::remote::IStoreClient;
+    use crate::datasources::remote::RemoteDatabase;
+    use crate::datasources::*;
+
+    let store = MockStore::create();
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]);
+    let create_table_plan = |table: &str, schema: DataSchemaRef| CreateTablePlan {
+        if_not_exists: false,
+        db: "db".into(),
+        table: table.into(),
+        schema,
+        engine: TableEngineType::JsonEachRaw,
+        options: TableOptions::default(),
+    };
+    store
+        .clone()
+        .create_database(CreateDatabasePlan {
+            if_not_exists: false,
+            db: "db".into(),
+            engine: DatabaseEngineType::Remote,
+            options: Default::default(),
+        })
+        .await?;
+    // Created by the other query nodes, not cached yet.
+    store
+        .clone()
+        .create_table(create_table_plan("a", schema.clone()))
+        .await?;
+
+    // The ttl is long enough to keep the tables fresh during the test.
+    let ttl = Duration::from_secs(3600);
+    let db = RemoteDatabase::create_with_table_cache_ttl(Arc::new(store.clone()), "db".into(), ttl);
+
+    // Missing in the cache, fetched from the store.
+    let table = db.get_table("a")?;
+    assert_eq!("a", table.name());
+    assert_eq!(schema, table.schema()?);
+    assert_eq!(1, store.calls("get_table"));
+
+    // Fresh in the cache, served without the store.
+    db.get_table("a")?;
+    assert_eq!(1, store.calls("get_table"));
+
+    // Always stale with the zero ttl, refetched from the store each time.
+    let stale_db = RemoteDatabase::create_with_table_cache_ttl(
+        Arc::new(store.clone()),
+        "db".into(),
+        Duration::from_secs(0),
+    );
+    stale_db.get_table("a")?;
+    assert_eq!(2, store.calls("get_table"));
+    stale_db.get_table("a")?;
+    assert_eq!(3, store.calls("get_table"));
+
+    // Changed by the other query nodes, refetched after the invalidation.
+    let new_schema = DataSchemaRefExt::create(vec![DataField::new("b", DataType::Utf8, false)]);
+    store
+        .clone()
+        .drop_table(DropTablePlan {
+            if_exists: false,
+            db: "db".into(),
+            table: "a".into(),
+        })
+        .await?;
+    store
+        .clone()
+        .create_table(create_table_plan("a", new_schema.clone()))
+        .await?;
+    assert_eq!(schema, db.get_table("a")?.schema()?);
+    db.invalidate("a");
+    assert_eq!(new_schema, db.get_table("a")?.schema()?);
+    assert_eq!(4, store.calls("get_table"));
+
+    // Dropped databases evict all the cached tables.
+    db.invalidate_cache();
+    db.get_table("a")?;
+    assert_eq!(5, store.calls("get_table"));
+
+    // Missing in the store.
+    let result = db.get_table("missing");
+    let actual = format!("{}", result.err().unwrap());
+    assert_eq!("Code: 25, displayText = Unknown table: 'missing'.", actual);
+    assert_eq!(6, store.calls("get_table"));
+
+    Ok(())
+}
@@ -6,22 +6,28 @@ use std::sync::Arc;
 
 use common_exception::ErrorCodes;
 use common_exception::Result;
+use common_flights::PingActionResult;
+use common_flights::StatusError;
 use common_flights::StoreClient;
 
 use crate::configs::Config;
+use crate::datasources::remote::store_client_provider::IStoreClient;
 use crate::datasources::remote::store_client_provider::IStoreClientProvider;
 use crate::datasources::remote::store_client_provider::StoreClientProvider;
 use crate::datasources::remote::RemoteDatabase;
+use crate::datasources::remote::RetryPolicy;
 use crate::datasources::IDatabase;
 
 pub struct RemoteFactory {
     store_client_provider: StoreClientProvider,
+    retry_policy: RetryPolicy,
 }
 
 impl RemoteFactory {
     pub fn new(conf: &Config) -> Self {
         RemoteFactory {
             store_client_provider: Arc::new(ClientProvider::new(conf)),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -37,6 +43,26 @@ impl RemoteFactory {
     pub fn store_client_provider(&self) -> StoreClientProvider {
         self.store_client_provider.clone()
     }
+
+    /// Talk to the store by the provider's clients, e.g. of a mock store in the tests.
+    pub fn set_store_client_provider(&mut self, store_client_provider: StoreClientProvider) {
+        self.store_client_provider = store_client_provider;
+    }
+
+    /// The retry policy of the remote DDL calls.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Checks the store is alive, returns its version and time.
+    pub async fn health_check(&self) -> Result<PingActionResult> {
+        let mut client = self.store_client_provider.try_get_client().await?;
+        client.ping().await
+    }
 }
 struct ClientProvider {
     conf: Config,
@@ -50,14 +76,23 @@ impl ClientProvider {
 
 #[async_trait::async_trait]
 impl IStoreClientProvider for ClientProvider {
-    async fn try_get_client(&self) -> Result<StoreClient> {
+    async fn try_get_client(&self) -> Result<Box<dyn IStoreClient>> {
         let client = StoreClient::try_create(
             &self.conf.store_api_address,
             &self.conf.store_api_username,
             &self.conf.store_api_password,
         )
         .await
-        .map_err(ErrorCodes::from)?;
-        Ok(client)
+        .map_err(|e| {
+            // The store responds with a status, e.g. rejects the handshake.
+            if e.downcast_ref::<StatusError>().is_some() {
+                return ErrorCodes::from(e);
+            }
+            ErrorCodes::CannotConnectNode(format!(
+                "Cannot connect to store {}: {}",
+                self.conf.store_api_address, e
+            ))
+        })?;
+        Ok(Box::new(client))
     }
 }
@@ -13,35 +13,52 @@ use common_planners::ScanPlan;
 use common_planners::TableOptions;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::remote::store_client_provider::StoreClientProvider;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
-#[allow(dead_code)]
 pub struct RemoteTable {
-    pub(crate) db: String,
+    db: String,
     name: String,
     schema: DataSchemaRef,
     store_client_provider: StoreClientProvider,
+    options: TableOptions,
 }
 
 impl RemoteTable {
-    #[allow(dead_code)]
     pub fn try_create(
         db: String,
         name: String,
         schema: DataSchemaRef,
         store_client_provider: StoreClientProvider,
-        _options: TableOptions,
+        options: TableOptions,
     ) -> Result<Box<dyn ITable>> {
         let table = Self {
             db,
             name,
             schema,
             store_client_provider,
+            options,
         };
         Ok(Box::new(table))
     }
+
+    /// The same table of the new name, with the schema and options kept.
+    pub fn renamed(&self, new_name: &str) -> Self {
+        Self {
+            db: self.db.clone(),
+            name: new_name.to_string(),
+            schema: self.schema.clone(),
+            store_client_provider: self.store_client_provider.clone(),
+            options: self.options.clone(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn options(&self) -> &TableOptions {
+        &self.options
+    }
 }
 
 #[async_trait::async_trait]
@@ -51,7 +68,7 @@ impl ITable for RemoteTable {
     }
 
     fn engine(&self) -> &str {
-        "remote"
+        engine_names::REMOTE
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -66,6 +83,10 @@ impl ITable for RemoteTable {
         false
     }
 
+    fn is_writable(&self) -> bool {
+        true
+    }
+
     fn read_plan(
         &self,
         _ctx: FuseQueryContextRef,
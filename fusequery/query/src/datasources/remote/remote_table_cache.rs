@@ -0,0 +1,92 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_infallible::RwLock;
+
+use crate::datasources::ITable;
+
+pub const DEFAULT_TABLE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+struct CachedTable {
+    table: Arc<dyn ITable>,
+    cached_at: Instant,
+}
+
+/// Tables fetched from the store, keyed by table name.
+/// The entries older than ttl are stale and should be refetched from the store.
+pub struct RemoteTableCache {
+    ttl: Duration,
+    tables: RwLock<HashMap<String, CachedTable>>,
+}
+
+impl RemoteTableCache {
+    pub fn create(ttl: Duration) -> Self {
+        RemoteTableCache {
+            ttl,
+            tables: RwLock::new(HashMap::default()),
+        }
+    }
+
+    /// Get the table whether it is stale or not.
+    pub fn get(&self, table_name: &str) -> Option<Arc<dyn ITable>> {
+        self.tables
+            .read()
+            .get(table_name)
+            .map(|cached| cached.table.clone())
+    }
+
+    /// Get the table only if it is not stale.
+    pub fn get_fresh(&self, table_name: &str) -> Option<Arc<dyn ITable>> {
+        self.tables
+            .read()
+            .get(table_name)
+            .filter(|cached| cached.cached_at.elapsed() < self.ttl)
+            .map(|cached| cached.table.clone())
+    }
+
+    pub fn contains(&self, table_name: &str) -> bool {
+        self.tables.read().contains_key(table_name)
+    }
+
+    pub fn tables(&self) -> Vec<Arc<dyn ITable>> {
+        self.tables
+            .read()
+            .values()
+            .map(|cached| cached.table.clone())
+            .collect()
+    }
+
+    pub fn insert(&self, table: Arc<dyn ITable>) {
+        self.tables
+            .write()
+            .insert(table.name().to_string(), CachedTable {
+                table,
+                cached_at: Instant::now(),
+            });
+    }
+
+    /// Replace the table cached as `table_name` by the renamed table under one lock,
+    /// the readers see either the old name or the new one.
+    pub fn rename(&self, table_name: &str, table: Arc<dyn ITable>) {
+        let mut tables = self.tables.write();
+        tables.remove(table_name);
+        tables.insert(table.name().to_string(), CachedTable {
+            table,
+            cached_at: Instant::now(),
+        });
+    }
+
+    pub fn invalidate(&self, table_name: &str) {
+        self.tables.write().remove(table_name);
+    }
+
+    pub fn invalidate_all(&self) {
+        self.tables.write().clear();
+    }
+}
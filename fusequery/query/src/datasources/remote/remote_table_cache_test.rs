@@ -0,0 +1,56 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_remote_table_cache() -> anyhow::Result<()> {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use common_datavalues::*;
+    use common_planners::*;
+
+    use crate::datasources::local::*;
+    use crate::datasources::remote::remote_table_cache::RemoteTableCache;
+    use crate::datasources::*;
+
+    let create_table = |name: &str| -> anyhow::Result<Arc<dyn ITable>> {
+        let table = NullTable::try_create(
+            "default".into(),
+            name.into(),
+            DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+            TableOptions::default(),
+        )?;
+        Ok(Arc::from(table))
+    };
+
+    let cache = RemoteTableCache::create(Duration::from_secs(3600));
+    let a = create_table("a")?;
+    cache.insert(a.clone());
+    cache.insert(create_table("b")?);
+    assert_eq!(2, cache.tables().len());
+
+    // The second get hits the cache.
+    let first = cache.get_fresh("a").unwrap();
+    let second = cache.get_fresh("a").unwrap();
+    assert!(Arc::ptr_eq(&first, &a));
+    assert!(Arc::ptr_eq(&second, &a));
+    assert!(cache.get_fresh("c").is_none());
+
+    // Invalidate forces a refetch.
+    cache.invalidate("a");
+    assert!(cache.get("a").is_none());
+    assert!(cache.get_fresh("a").is_none());
+    assert!(cache.contains("b"));
+
+    cache.invalidate_all();
+    assert!(cache.tables().is_empty());
+
+    // Stale entries are still visible but not fresh.
+    let cache = RemoteTableCache::create(Duration::from_secs(0));
+    cache.insert(create_table("a")?);
+    assert!(cache.get("a").is_some());
+    assert!(cache.get_fresh("a").is_none());
+
+    Ok(())
+}
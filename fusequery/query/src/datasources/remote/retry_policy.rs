@@ -0,0 +1,121 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::future::Future;
+use std::time::Duration;
+
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use common_flights::StatusError;
+use rand::Rng;
+use tonic::Code;
+
+/// Retry policy of the remote store calls.
+/// Only the transient(network) failures are retried, with exponential backoff and jitter.
+/// The failures are classified by their status codes, not by the messages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn create(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Run the idempotent operation until it succeeds, fails with a non-transient error,
+    /// or the max attempts are used up.
+    pub async fn retry<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.retry_if(op, Self::is_transient).await
+    }
+
+    /// Run the non-idempotent operation(e.g. create database) like `retry`, but only the
+    /// failures before the store applies the call are retried: a timed out or aborted call
+    /// may have been applied, retrying it fails with "already exists".
+    pub async fn retry_non_idempotent<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.retry_if(op, Self::is_unapplied).await
+    }
+
+    async fn retry_if<T, F, Fut>(&self, mut op: F, retryable: fn(&ErrorCodes) -> bool) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match op().await {
+                Ok(v) => return Ok(v),
+                Err(e) if attempt < self.max_attempts && retryable(&e) => {
+                    log::warn!("Remote call attempt {} failed, will retry: {}", attempt, e);
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// The transient failures are the store unreachable, and the unavailable/timeout
+    /// statuses, the logical errors(e.g. already exists) are not.
+    pub fn is_transient(error: &ErrorCodes) -> bool {
+        Self::is_unapplied(error)
+            || matches!(
+                Self::status_code(error),
+                Some(Code::DeadlineExceeded) | Some(Code::Aborted)
+            )
+    }
+
+    /// The transient failures which are known not applied by the store.
+    pub fn is_unapplied(error: &ErrorCodes) -> bool {
+        error.code() == ErrorCodes::CannotConnectNode("").code()
+            || matches!(
+                Self::status_code(error),
+                Some(Code::Unavailable) | Some(Code::ResourceExhausted)
+            )
+    }
+
+    fn status_code(error: &ErrorCodes) -> Option<Code> {
+        error
+            .downcast_cause_ref::<StatusError>()
+            .map(|status| status.code())
+    }
+
+    // Exponential backoff of the attempt, jittered to [50%, 100%].
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .initial_backoff
+            .checked_mul(1 << (attempt - 1).min(16))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        let millis = exp.as_millis() as u64;
+        if millis == 0 {
+            return exp;
+        }
+        Duration::from_millis(rand::thread_rng().gen_range(millis / 2..=millis))
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use common_flights::status_err;
+use pretty_assertions::assert_eq;
+use tonic::Status;
+
+use crate::datasources::remote::RetryPolicy;
+
+// A mock store call which fails `failures` times with the `error` before succeeding.
+fn mock_call(
+    calls: Arc<AtomicU32>,
+    failures: u32,
+    error: fn() -> Status,
+) -> impl FnMut() -> futures::future::Ready<Result<u32>> {
+    move || {
+        let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if n <= failures {
+            futures::future::err(ErrorCodes::from(status_err(error())))
+        } else {
+            futures::future::ok(n)
+        }
+    }
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::create(3, Duration::from_millis(1), Duration::from_millis(4))
+}
+
+#[tokio::test]
+async fn test_retry_policy_transient_errors() -> anyhow::Result<()> {
+    let calls = Arc::new(AtomicU32::new(0));
+    let unavailable = || Status::unavailable("store is down");
+
+    // Fails twice then succeeds.
+    let result = policy()
+        .retry(mock_call(calls.clone(), 2, unavailable))
+        .await?;
+    assert_eq!(3, result);
+    assert_eq!(3, calls.load(Ordering::SeqCst));
+
+    // Gives up after the max attempts.
+    let calls = Arc::new(AtomicU32::new(0));
+    let result = policy()
+        .retry(mock_call(calls.clone(), 5, unavailable))
+        .await;
+    assert!(result.is_err());
+    assert_eq!(3, calls.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_policy_logical_error() -> anyhow::Result<()> {
+    let calls = Arc::new(AtomicU32::new(0));
+    let exists = || Status::internal("database db1 already exists");
+
+    let result = policy().retry(mock_call(calls.clone(), 2, exists)).await;
+    assert!(result.is_err());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_policy_classified_by_status_code() -> anyhow::Result<()> {
+    // The message mentions an unavailable transport, but the status is not transient.
+    let calls = Arc::new(AtomicU32::new(0));
+    let internal = || Status::internal("Service was not ready: transport error, unavailable");
+    let result = policy().retry(mock_call(calls.clone(), 2, internal)).await;
+    assert!(result.is_err());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    // The store is unreachable.
+    assert!(RetryPolicy::is_transient(&ErrorCodes::CannotConnectNode(
+        "Cannot connect to store"
+    )));
+    assert!(RetryPolicy::is_unapplied(&ErrorCodes::CannotConnectNode(
+        "Cannot connect to store"
+    )));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_policy_non_idempotent() -> anyhow::Result<()> {
+    // The unavailable call is not applied, it's retried.
+    let calls = Arc::new(AtomicU32::new(0));
+    let unavailable = || Status::unavailable("store is down");
+    let result = policy()
+        .retry_non_idempotent(mock_call(calls.clone(), 2, unavailable))
+        .await?;
+    assert_eq!(3, result);
+
+    // The timed out call may be applied, it's not retried.
+    let calls = Arc::new(AtomicU32::new(0));
+    let deadline = || Status::deadline_exceeded("timeout");
+    let result = policy()
+        .retry_non_idempotent(mock_call(calls.clone(), 2, deadline))
+        .await;
+    assert!(result.is_err());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    // The idempotent one is retried.
+    let calls = Arc::new(AtomicU32::new(0));
+    let result = policy()
+        .retry(mock_call(calls.clone(), 2, deadline))
+        .await?;
+    assert_eq!(3, result);
+
+    Ok(())
+}
@@ -0,0 +1,239 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use common_flights::status_err;
+use common_flights::AppendResult;
+use common_flights::BlockStream;
+use common_flights::CreateDatabaseActionResult;
+use common_flights::CreateTableActionResult;
+use common_flights::DropDatabaseActionResult;
+use common_flights::DropTableActionResult;
+use common_flights::GetTableActionResult;
+use common_flights::PingActionResult;
+use common_flights::RenameTableActionResult;
+use common_infallible::Mutex;
+use common_planners::CreateDatabasePlan;
+use common_planners::CreateTablePlan;
+use common_planners::DropDatabasePlan;
+use common_planners::DropTablePlan;
+use tonic::Code;
+use tonic::Status;
+
+use crate::datasources::remote::IStoreClient;
+use crate::datasources::remote::IStoreClientProvider;
+
+/// An in-memory store for the tests of the remote databases and tables,
+/// the clones share the same store. The calls can be made to fail by `fail_next`.
+#[derive(Clone)]
+pub struct MockStore {
+    state: Arc<Mutex<MockStoreState>>,
+}
+
+#[derive(Default)]
+struct MockStoreState {
+    // Database name to its tables.
+    databases: HashMap<String, HashMap<String, (i64, DataSchemaRef)>>,
+    next_table_id: i64,
+    // The failures of the next calls by method, in order.
+    failures: HashMap<&'static str, VecDeque<Code>>,
+    calls: HashMap<&'static str, usize>,
+}
+
+impl MockStore {
+    pub fn create() -> Self {
+        MockStore {
+            state: Arc::new(Mutex::new(MockStoreState::default())),
+        }
+    }
+
+    /// Fail the next call of the method with the status code, before the call is applied.
+    pub fn fail_next(&self, method: &'static str, code: Code) {
+        let mut state = self.state.lock();
+        state.failures.entry(method).or_default().push_back(code);
+    }
+
+    /// The number of the calls of the method, including the failed ones.
+    pub fn calls(&self, method: &str) -> usize {
+        self.state.lock().calls.get(method).cloned().unwrap_or(0)
+    }
+
+    pub fn has_database(&self, db: &str) -> bool {
+        self.state.lock().databases.contains_key(db)
+    }
+
+    /// The table names of the database, sorted.
+    pub fn tables(&self, db: &str) -> Vec<String> {
+        let state = self.state.lock();
+        let mut tables: Vec<String> = state
+            .databases
+            .get(db)
+            .map(|tables| tables.keys().cloned().collect())
+            .unwrap_or_default();
+        tables.sort();
+        tables
+    }
+
+    // Count the call, and fail it if a failure of the method is queued.
+    fn call(&self, method: &'static str) -> Result<()> {
+        let mut state = self.state.lock();
+        *state.calls.entry(method).or_default() += 1;
+        match state.failures.get_mut(method).and_then(|f| f.pop_front()) {
+            Some(code) => Err(Self::status(code, format!("mock {} failure", method))),
+            None => Ok(()),
+        }
+    }
+
+    fn status(code: Code, message: String) -> ErrorCodes {
+        ErrorCodes::from(status_err(Status::new(code, message)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IStoreClientProvider for MockStore {
+    async fn try_get_client(&self) -> Result<Box<dyn IStoreClient>> {
+        Ok(Box::new(self.clone()))
+    }
+}
+
+#[async_trait::async_trait]
+impl IStoreClient for MockStore {
+    async fn create_database(
+        &mut self,
+        plan: CreateDatabasePlan,
+    ) -> Result<CreateDatabaseActionResult> {
+        self.call("create_database")?;
+        let mut state = self.state.lock();
+        if state.databases.contains_key(&plan.db) && !plan.if_not_exists {
+            let message = format!("database {} already exists", plan.db);
+            return Err(Self::status(Code::AlreadyExists, message));
+        }
+        state.databases.entry(plan.db).or_default();
+        Ok(CreateDatabaseActionResult { database_id: 0 })
+    }
+
+    async fn drop_database(&mut self, plan: DropDatabasePlan) -> Result<DropDatabaseActionResult> {
+        self.call("drop_database")?;
+        let mut state = self.state.lock();
+        if state.databases.remove(&plan.db).is_none() && !plan.if_exists {
+            let message = format!("database {} not found", plan.db);
+            return Err(Self::status(Code::NotFound, message));
+        }
+        Ok(DropDatabaseActionResult {})
+    }
+
+    async fn create_table(&mut self, plan: CreateTablePlan) -> Result<CreateTableActionResult> {
+        self.call("create_table")?;
+        let mut state = self.state.lock();
+        state.next_table_id += 1;
+        let table_id = state.next_table_id;
+        let tables = match state.databases.get_mut(&plan.db) {
+            Some(tables) => tables,
+            None => {
+                let message = format!("database {} not found", plan.db);
+                return Err(Self::status(Code::NotFound, message));
+            }
+        };
+        if tables.contains_key(&plan.table) && !plan.if_not_exists {
+            let message = format!("table {} already exists", plan.table);
+            return Err(Self::status(Code::AlreadyExists, message));
+        }
+        tables.entry(plan.table).or_insert((table_id, plan.schema));
+        Ok(CreateTableActionResult { table_id })
+    }
+
+    async fn drop_table(&mut self, plan: DropTablePlan) -> Result<DropTableActionResult> {
+        self.call("drop_table")?;
+        let mut state = self.state.lock();
+        let dropped = state
+            .databases
+            .get_mut(&plan.db)
+            .and_then(|tables| tables.remove(&plan.table));
+        if dropped.is_none() && !plan.if_exists {
+            let message = format!("table {} not found", plan.table);
+            return Err(Self::status(Code::NotFound, message));
+        }
+        Ok(DropTableActionResult {})
+    }
+
+    async fn rename_table(
+        &mut self,
+        db: String,
+        table: String,
+        new_table: String,
+    ) -> Result<RenameTableActionResult> {
+        self.call("rename_table")?;
+        let mut state = self.state.lock();
+        let tables = match state.databases.get_mut(&db) {
+            Some(tables) => tables,
+            None => {
+                let message = format!("database {} not found", db);
+                return Err(Self::status(Code::NotFound, message));
+            }
+        };
+        if tables.contains_key(&new_table) {
+            let message = format!("table {} already exists", new_table);
+            return Err(Self::status(Code::AlreadyExists, message));
+        }
+        match tables.remove(&table) {
+            Some(entry) => {
+                tables.insert(new_table, entry);
+                Ok(RenameTableActionResult {})
+            }
+            None => {
+                let message = format!("table {} not found", table);
+                Err(Self::status(Code::NotFound, message))
+            }
+        }
+    }
+
+    async fn get_table(&mut self, db: String, table: String) -> Result<GetTableActionResult> {
+        self.call("get_table")?;
+        let state = self.state.lock();
+        match state
+            .databases
+            .get(&db)
+            .and_then(|tables| tables.get(&table))
+        {
+            Some((table_id, schema)) => Ok(GetTableActionResult {
+                table_id: *table_id,
+                db,
+                name: table,
+                schema: schema.clone(),
+            }),
+            None => {
+                let message = format!("table {} not found", table);
+                Err(Self::status(Code::NotFound, message))
+            }
+        }
+    }
+
+    async fn ping(&mut self) -> Result<PingActionResult> {
+        self.call("ping")?;
+        Ok(PingActionResult {
+            version: "mock".to_string(),
+            server_time_ms: 0,
+        })
+    }
+
+    async fn append_data(
+        &mut self,
+        _db_name: String,
+        _tbl_name: String,
+        _schema: DataSchemaRef,
+        _block_stream: BlockStream,
+    ) -> Result<AppendResult> {
+        self.call("append_data")?;
+        Err(Self::status(
+            Code::Unimplemented,
+            "mock store has no data".to_string(),
+        ))
+    }
+}
@@ -5,12 +5,118 @@
 
 use std::sync::Arc;
 
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCodes;
 use common_exception::Result;
+use common_flights::AppendResult;
+use common_flights::BlockStream;
+use common_flights::CreateDatabaseActionResult;
+use common_flights::CreateTableActionResult;
+use common_flights::DropDatabaseActionResult;
+use common_flights::DropTableActionResult;
+use common_flights::GetTableActionResult;
+use common_flights::PingActionResult;
+use common_flights::RenameTableActionResult;
 use common_flights::StoreClient;
+use common_planners::CreateDatabasePlan;
+use common_planners::CreateTablePlan;
+use common_planners::DropDatabasePlan;
+use common_planners::DropTablePlan;
+
+/// The store calls of the remote databases and tables,
+/// implemented by the StoreClient and by the mock stores of the tests.
+#[async_trait::async_trait]
+pub trait IStoreClient: Send {
+    async fn create_database(
+        &mut self,
+        plan: CreateDatabasePlan,
+    ) -> Result<CreateDatabaseActionResult>;
+    async fn drop_database(&mut self, plan: DropDatabasePlan) -> Result<DropDatabaseActionResult>;
+    async fn create_table(&mut self, plan: CreateTablePlan) -> Result<CreateTableActionResult>;
+    async fn drop_table(&mut self, plan: DropTablePlan) -> Result<DropTableActionResult>;
+    async fn rename_table(
+        &mut self,
+        db: String,
+        table: String,
+        new_table: String,
+    ) -> Result<RenameTableActionResult>;
+    async fn get_table(&mut self, db: String, table: String) -> Result<GetTableActionResult>;
+    async fn ping(&mut self) -> Result<PingActionResult>;
+    async fn append_data(
+        &mut self,
+        db_name: String,
+        tbl_name: String,
+        schema: DataSchemaRef,
+        block_stream: BlockStream,
+    ) -> Result<AppendResult>;
+}
 
 #[async_trait::async_trait]
 pub trait IStoreClientProvider {
-    async fn try_get_client(&self) -> Result<StoreClient>;
+    async fn try_get_client(&self) -> Result<Box<dyn IStoreClient>>;
 }
 
 pub type StoreClientProvider = Arc<dyn IStoreClientProvider + Send + Sync>;
+
+#[async_trait::async_trait]
+impl IStoreClient for StoreClient {
+    async fn create_database(
+        &mut self,
+        plan: CreateDatabasePlan,
+    ) -> Result<CreateDatabaseActionResult> {
+        StoreClient::create_database(self, plan)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+
+    async fn drop_database(&mut self, plan: DropDatabasePlan) -> Result<DropDatabaseActionResult> {
+        StoreClient::drop_database(self, plan)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+
+    async fn create_table(&mut self, plan: CreateTablePlan) -> Result<CreateTableActionResult> {
+        StoreClient::create_table(self, plan)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+
+    async fn drop_table(&mut self, plan: DropTablePlan) -> Result<DropTableActionResult> {
+        StoreClient::drop_table(self, plan)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+
+    async fn rename_table(
+        &mut self,
+        db: String,
+        table: String,
+        new_table: String,
+    ) -> Result<RenameTableActionResult> {
+        StoreClient::rename_table(self, db, table, new_table)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+
+    async fn get_table(&mut self, db: String, table: String) -> Result<GetTableActionResult> {
+        StoreClient::get_table(self, db, table)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+
+    async fn ping(&mut self) -> Result<PingActionResult> {
+        StoreClient::ping(self).await.map_err(ErrorCodes::from)
+    }
+
+    async fn append_data(
+        &mut self,
+        db_name: String,
+        tbl_name: String,
+        schema: DataSchemaRef,
+        block_stream: BlockStream,
+    ) -> Result<AppendResult> {
+        StoreClient::append_data(self, db_name, tbl_name, schema, block_stream)
+            .await
+            .map_err(ErrorCodes::from)
+    }
+}
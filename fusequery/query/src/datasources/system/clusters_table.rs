@@ -12,6 +12,7 @@ use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_datavalues::StringArray;
 use common_datavalues::UInt16Array;
+use common_datavalues::UInt64Array;
 use common_datavalues::UInt8Array;
 use common_exception::Result;
 use common_planners::Partition;
@@ -21,6 +22,7 @@ use common_planners::Statistics;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -36,6 +38,7 @@ impl ClustersTable {
                 DataField::new("host", DataType::Utf8, false),
                 DataField::new("port", DataType::UInt16, false),
                 DataField::new("priority", DataType::UInt8, false),
+                DataField::new("uptime_secs", DataType::UInt64, false),
             ]),
         }
     }
@@ -48,7 +51,7 @@ impl ITable for ClustersTable {
     }
 
     fn engine(&self) -> &str {
-        "SystemClusters"
+        engine_names::SYSTEM_CLUSTERS
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -76,6 +79,8 @@ impl ITable for ClustersTable {
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: "(Read from system.clusters table)".to_string(),
@@ -93,11 +98,13 @@ impl ITable for ClustersTable {
         let hostnames = hosts.iter().map(|x| x.as_str()).collect::<Vec<&str>>();
         let ports: Vec<u16> = nodes.iter().map(|x| x.address.port()).collect();
         let priorities: Vec<u8> = nodes.iter().map(|x| x.priority).collect();
+        let uptimes: Vec<u64> = nodes.iter().map(|x| x.uptime_secs()).collect();
         let block = DataBlock::create_by_array(self.schema.clone(), vec![
             Arc::new(StringArray::from(names)),
             Arc::new(StringArray::from(hostnames)),
             Arc::new(UInt16Array::from(ports)),
             Arc::new(UInt8Array::from(priorities)),
+            Arc::new(UInt64Array::from(uptimes)),
         ]);
         Ok(Box::pin(DataBlockStream::create(
             self.schema.clone(),
@@ -6,6 +6,7 @@ use common_exception::Result;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_clusters_table() -> Result<()> {
+    use common_datavalues::DataValue;
     use common_planners::*;
     use futures::TryStreamExt;
 
@@ -13,7 +14,12 @@ async fn test_clusters_table() -> Result<()> {
     use crate::datasources::*;
 
     let ctx = crate::tests::try_create_context()?;
+    let cluster = ctx.try_get_cluster()?;
+    cluster.add_node("local", 5, "127.0.0.1:9090").await?;
+    cluster.add_node("remote", 5, "127.0.0.1:9001").await?;
+
     let table = ClustersTable::create();
+    assert!(!table.is_writable());
     table.read_plan(
         ctx.clone(),
         &ScanPlan::empty(),
@@ -23,7 +29,19 @@ async fn test_clusters_table() -> Result<()> {
     let stream = table.read(ctx).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 4);
+    assert_eq!(block.num_columns(), 5);
+    assert_eq!(block.num_rows(), 2);
+
+    // The local node has just started, the remote one doesn't report its start time.
+    let uptimes = block.try_column_by_name("uptime_secs")?.to_array()?;
+    match DataValue::try_from_array(&uptimes, 0)? {
+        DataValue::UInt64(Some(uptime)) => assert!(uptime < 60),
+        other => panic!("Unexpected uptime: {:?}", other),
+    }
+    assert_eq!(
+        DataValue::try_from_array(&uptimes, 1)?,
+        DataValue::UInt64(Some(0))
+    );
 
     Ok(())
 }
@@ -19,6 +19,7 @@ use common_planners::Statistics;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -29,7 +30,10 @@ pub struct DatabasesTable {
 impl DatabasesTable {
     pub fn create() -> Self {
         DatabasesTable {
-            schema: DataSchemaRefExt::create(vec![DataField::new("name", DataType::Utf8, false)]),
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("name", DataType::Utf8, false),
+                DataField::new("engine", DataType::Utf8, false),
+            ]),
         }
     }
 }
@@ -41,7 +45,7 @@ impl ITable for DatabasesTable {
     }
 
     fn engine(&self) -> &str {
-        "SystemDatabases"
+        engine_names::SYSTEM_DATABASES
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -69,6 +73,8 @@ impl ITable for DatabasesTable {
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: "(Read from system.databases table)".to_string(),
@@ -77,21 +83,25 @@ impl ITable for DatabasesTable {
     }
 
     async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
-        ctx.get_datasource()
-            .get_databases()
-            .map(|databases_name| -> SendableDataBlockStream {
-                let databases_name_str: Vec<&str> = databases_name
-                    .iter()
-                    .map(|database_name| database_name.as_str())
-                    .collect();
-
-                let block = DataBlock::create_by_array(self.schema.clone(), vec![Arc::new(
-                    StringArray::from(databases_name_str),
-                )]);
+        let datasource = ctx.get_datasource();
+        let mut names = vec![];
+        let mut engines = vec![];
+        for name in datasource.get_databases()? {
+            let engine = datasource.get_database(&name)?.engine().to_string();
+            names.push(name);
+            engines.push(engine);
+        }
 
-                Box::pin(DataBlockStream::create(self.schema.clone(), None, vec![
-                    block,
-                ]))
-            })
+        let names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+        let engines: Vec<&str> = engines.iter().map(|engine| engine.as_str()).collect();
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Arc::new(StringArray::from(names)),
+            Arc::new(StringArray::from(engines)),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
     }
 }
@@ -21,17 +21,17 @@ async fn test_tables_table() -> anyhow::Result<()> {
     let stream = table.read(ctx).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 1);
+    assert_eq!(block.num_columns(), 2);
 
     let expected = vec![
-        "+----------+",
-        "| name     |",
-        "+----------+",
-        "| default  |",
-        "| for_test |",
-        "| local    |",
-        "| system   |",
-        "+----------+",
+        "+----------+--------+",
+        "| name     | engine |",
+        "+----------+--------+",
+        "| default  | Local  |",
+        "| for_test | Remote |",
+        "| local    | Local  |",
+        "| system   | System |",
+        "+----------+--------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
@@ -0,0 +1,108 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::StringArray;
+use common_exception::Result;
+use common_planners::Partition;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::datasources::engine_names;
+use crate::datasources::ITable;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct EnginesTable {
+    schema: DataSchemaRef,
+}
+
+impl EnginesTable {
+    pub fn create() -> Self {
+        EnginesTable {
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("name", DataType::Utf8, false),
+                DataField::new("type", DataType::Utf8, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ITable for EnginesTable {
+    fn name(&self) -> &str {
+        "engines"
+    }
+
+    fn engine(&self) -> &str {
+        engine_names::SYSTEM_ENGINES
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: FuseQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions: vec![Partition {
+                name: "".to_string(),
+                version: 0,
+                rows: 0,
+                bytes: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.engines table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+        })
+    }
+
+    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+        // The database engines first, then the table engines.
+        let engines = engine_names::DATABASE_ENGINES
+            .iter()
+            .map(|engine| (*engine, "Database"))
+            .chain(
+                engine_names::TABLE_ENGINES
+                    .iter()
+                    .map(|engine| (*engine, "Table")),
+            )
+            .collect::<Vec<_>>();
+        let names: Vec<&str> = engines.iter().map(|(name, _)| *name).collect();
+        let types: Vec<&str> = engines.iter().map(|(_, kind)| *kind).collect();
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Arc::new(StringArray::from(names)),
+            Arc::new(StringArray::from(types)),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
+    }
+}
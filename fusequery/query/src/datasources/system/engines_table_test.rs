@@ -0,0 +1,85 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_engines_table() -> anyhow::Result<()> {
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = EnginesTable::create();
+    table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_max_threads()? as usize,
+    )?;
+
+    let stream = table.read(ctx).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 2);
+    assert_eq!(
+        block.num_rows(),
+        engine_names::DATABASE_ENGINES.len() + engine_names::TABLE_ENGINES.len()
+    );
+
+    // The database engines are the same as the DatabaseEngineType names.
+    let expected = vec![
+        "+----------+----------+",
+        "| name     | type     |",
+        "+----------+----------+",
+        "| Local    | Database |",
+        "| Remote   | Database |",
+        "| System   | Database |",
+        "+----------+----------+",
+    ];
+    let database_engines = block.slice(0, engine_names::DATABASE_ENGINES.len());
+    common_datablocks::assert_blocks_eq(expected, &[database_engines]);
+    for engine in [DatabaseEngineType::Local, DatabaseEngineType::Remote].iter() {
+        assert!(engine_names::DATABASE_ENGINES.contains(&engine.to_string().as_str()));
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_engine_names_registered() -> anyhow::Result<()> {
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    for db_name in datasource.get_databases()? {
+        let database = datasource.get_database(&db_name)?;
+        assert!(
+            engine_names::DATABASE_ENGINES.contains(&database.engine()),
+            "Database {} engine {} is not registered",
+            db_name,
+            database.engine()
+        );
+
+        for table in database.get_tables()? {
+            assert!(
+                engine_names::TABLE_ENGINES.contains(&table.engine()),
+                "Table {}.{} engine {} is not registered",
+                db_name,
+                table.name(),
+                table.engine()
+            );
+        }
+
+        for table_function in database.get_table_functions()? {
+            assert!(
+                engine_names::TABLE_ENGINES.contains(&table_function.engine()),
+                "Table function {} engine {} is not registered",
+                table_function.name(),
+                table_function.engine()
+            );
+        }
+    }
+
+    Ok(())
+}
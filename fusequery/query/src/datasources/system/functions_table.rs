@@ -20,6 +20,7 @@ use common_planners::Statistics;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -42,7 +43,7 @@ impl ITable for FunctionsTable {
     }
 
     fn engine(&self) -> &str {
-        "SystemFunctions"
+        engine_names::SYSTEM_FUNCTIONS
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -70,6 +71,8 @@ impl ITable for FunctionsTable {
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: "(Read from system.functions table)".to_string(),
@@ -49,6 +49,7 @@ async fn test_functions_table() -> anyhow::Result<()> {
         "| not        |",
         "| or         |",
         "| plus       |",
+        "| rand       |",
         "| siphash    |",
         "| substring  |",
         "| totypename |",
@@ -0,0 +1,129 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::Int64Array;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use common_streams::ProgressStream;
+use futures::stream::Stream;
+
+use crate::sessions::FuseQueryContextRef;
+
+#[derive(Debug, Clone)]
+struct BlockRange {
+    begin: u64,
+    end: u64,
+    // The row i of the range is: start + i * step
+    start: i64,
+    step: i64,
+}
+
+pub struct GenerateSeriesStream {
+    ctx: FuseQueryContextRef,
+    schema: DataSchemaRef,
+    block_index: usize,
+    blocks: Vec<BlockRange>,
+}
+
+impl GenerateSeriesStream {
+    pub fn try_create(ctx: FuseQueryContextRef, schema: DataSchemaRef) -> Result<ProgressStream> {
+        let stream = Box::pin(GenerateSeriesStream {
+            ctx: ctx.clone(),
+            schema,
+            block_index: 0,
+            blocks: vec![],
+        });
+        ProgressStream::try_create(stream, ctx.progress_callback()?)
+    }
+
+    // The partition name is: total-begin-end:start:step
+    fn parse_partition(name: &str) -> Result<(u64, u64, i64, i64)> {
+        let bad_partition =
+            || ErrorCodes::LogicalError(format!("Bad generate_series partition: {}", name));
+
+        let parts: Vec<_> = name.split(':').collect();
+        if parts.len() != 3 {
+            return Err(bad_partition());
+        }
+        let range: Vec<_> = parts[0].split('-').collect();
+        if range.len() != 3 {
+            return Err(bad_partition());
+        }
+
+        let begin: u64 = range[1].parse()?;
+        let end: u64 = range[2].parse()?;
+        let start: i64 = parts[1].parse()?;
+        let step: i64 = parts[2].parse()?;
+        Ok((begin, end, start, step))
+    }
+
+    fn try_get_one_block(&mut self) -> Result<Option<DataBlock>> {
+        // Empty partitions are skipped.
+        while self.block_index == self.blocks.len() {
+            let partitions = self.ctx.try_get_partitions(1)?;
+            if partitions.is_empty() {
+                return Ok(None);
+            }
+
+            let block_size = self.ctx.get_max_block_size()?;
+            let mut blocks = vec![];
+            for part in partitions {
+                let (begin, end, start, step) = Self::parse_partition(&part.name)?;
+
+                let mut range_begin = begin;
+                while range_begin < end {
+                    let range_end = std::cmp::min(range_begin.saturating_add(block_size), end);
+                    blocks.push(BlockRange {
+                        begin: range_begin,
+                        end: range_end,
+                        start,
+                        step,
+                    });
+                    range_begin = range_end;
+                }
+            }
+            self.blocks = blocks;
+            self.block_index = 0;
+        }
+
+        let current = self.blocks[self.block_index].clone();
+        self.block_index += 1;
+
+        // The i * step may overflow i64 even if the value doesn't, e.g. from i64::MIN by 2^62.
+        let values = (current.begin..current.end)
+            .map(|i| {
+                let value = current.start as i128 + i as i128 * current.step as i128;
+                i64::try_from(value).map_err(|_| {
+                    ErrorCodes::NumericOverflow(format!(
+                        "The row {} of generate_series({}, _, {}) overflows Int64",
+                        i, current.start, current.step
+                    ))
+                })
+            })
+            .collect::<Result<Vec<i64>>>()?;
+        Ok(Some(DataBlock::create_by_array(self.schema.clone(), vec![
+            Arc::new(Int64Array::from(values)),
+        ])))
+    }
+}
+
+impl Stream for GenerateSeriesStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let block = self.try_get_one_block()?;
+
+        Poll::Ready(block.map(Ok))
+    }
+}
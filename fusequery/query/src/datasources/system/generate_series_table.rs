@@ -0,0 +1,204 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::SendableDataBlockStream;
+
+use crate::datasources::engine_names;
+use crate::datasources::system::GenerateSeriesStream;
+use crate::datasources::Common;
+use crate::datasources::ITable;
+use crate::datasources::ITableFunction;
+use crate::sessions::FuseQueryContextRef;
+
+/// The generate_series(start, stop, step) table function,
+/// returns the values from start to stop(inclusive) by step.
+pub struct GenerateSeriesTable {
+    schema: DataSchemaRef,
+}
+
+impl GenerateSeriesTable {
+    pub fn create() -> Self {
+        GenerateSeriesTable {
+            schema: DataSchemaRefExt::create(vec![DataField::new(
+                "generate_series",
+                DataType::Int64,
+                false,
+            )]),
+        }
+    }
+
+    // The integer literal, which may be out of the Int64 range, e.g. -(i64::MIN).
+    fn literal_to_i128(expr: &Expression) -> Option<i128> {
+        match expr {
+            Expression::Literal(DataValue::Int64(Some(v))) => Some(*v as i128),
+            Expression::Literal(DataValue::UInt64(Some(v))) => Some(*v as i128),
+            Expression::UnaryExpression { op, expr } if op == "-" => {
+                Self::literal_to_i128(expr).and_then(|v| v.checked_neg())
+            }
+            _ => None,
+        }
+    }
+
+    fn literal_to_i64(&self, expr: &Expression) -> Result<i64> {
+        let value = Self::literal_to_i128(expr).ok_or_else(|| {
+            ErrorCodes::BadArguments(format!(
+                "Table system.{} arguments must be integer literals, but got {:?}",
+                self.name(),
+                expr
+            ))
+        })?;
+        i64::try_from(value).map_err(|_| {
+            ErrorCodes::NumericOverflow(format!(
+                "Table system.{} argument {} is out of the Int64 range",
+                self.name(),
+                value
+            ))
+        })
+    }
+
+    // The number of the values from start to stop(inclusive) by step, None if overflows u64.
+    fn series_len(start: i64, stop: i64, step: i64) -> Option<u64> {
+        let (start, stop, step) = (start as i128, stop as i128, step as i128);
+        if (step > 0 && stop < start) || (step < 0 && stop > start) {
+            return Some(0);
+        }
+        u64::try_from((stop - start) / step + 1).ok()
+    }
+}
+
+#[async_trait::async_trait]
+impl ITable for GenerateSeriesTable {
+    fn name(&self) -> &str {
+        "generate_series"
+    }
+
+    fn engine(&self) -> &str {
+        engine_names::SYSTEM_GENERATE_SERIES
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn read_plan(
+        &self,
+        ctx: FuseQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        let args = match &scan.table_args {
+            Some(Expression::ScalarFunction { args, .. }) if args.len() == 3 => args,
+            _ => {
+                return Result::Err(ErrorCodes::BadArguments(format!(
+                    "Table system.{} expects 3 arguments: (start, stop, step)",
+                    self.name()
+                )));
+            }
+        };
+
+        let values = args
+            .iter()
+            .map(|arg| self.literal_to_i64(arg))
+            .collect::<Result<Vec<_>>>()?;
+
+        let (start, stop, step) = (values[0], values[1], values[2]);
+        if step == 0 {
+            return Result::Err(ErrorCodes::BadArguments(format!(
+                "Table system.{} step must not be zero",
+                self.name()
+            )));
+        }
+
+        // The row i of the series is: start + i * step
+        let too_many_rows = || {
+            ErrorCodes::NumericOverflow(format!(
+                "Table system.{} has too many rows: ({}, {}, {})",
+                self.name(),
+                start,
+                stop,
+                step
+            ))
+        };
+        let total = Self::series_len(start, stop, step).ok_or_else(too_many_rows)?;
+        let read_rows = usize::try_from(total).map_err(|_| too_many_rows())?;
+        let read_bytes = total
+            .checked_mul(size_of::<i64>() as u64)
+            .and_then(|bytes| usize::try_from(bytes).ok())
+            .ok_or_else(too_many_rows)?;
+        let mut partitions = Common::generate_parts(0, ctx.get_max_threads()?, total);
+        for part in partitions.iter_mut() {
+            // The start and step may be negative, so they are not separated by '-'.
+            part.name = format!("{}:{}:{}", part.name, start, step);
+        }
+
+        let statistics = Statistics {
+            read_rows,
+            read_bytes,
+            null_counts: None,
+        };
+        ctx.try_set_statistics(&statistics)?;
+        ctx.add_total_rows_approx(statistics.read_rows);
+
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions,
+            statistics: statistics.clone(),
+            description: format!(
+                "(Read from system.{} table, Read Rows:{}, Read Bytes:{})",
+                self.name(),
+                statistics.read_rows,
+                statistics.read_bytes
+            ),
+            scan_plan: Arc::new(scan.clone()),
+        })
+    }
+
+    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(GenerateSeriesStream::try_create(
+            ctx,
+            self.schema.clone(),
+        )?))
+    }
+}
+
+impl ITableFunction for GenerateSeriesTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn db(&self) -> &str {
+        "system"
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn ITable + 'a>
+    where Self: 'a {
+        self
+    }
+}
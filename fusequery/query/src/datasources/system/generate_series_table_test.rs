@@ -0,0 +1,248 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::*;
+use common_planners::*;
+
+fn generate_series_scan(args: Vec<Expression>) -> ScanPlan {
+    ScanPlan {
+        schema_name: "scan_test".to_string(),
+        table_args: Some(Expression::ScalarFunction {
+            op: "generate_series".to_string(),
+            args,
+        }),
+        projected_schema: DataSchemaRefExt::create(vec![DataField::new(
+            "generate_series",
+            DataType::Int64,
+            false,
+        )]),
+        ..ScanPlan::empty()
+    }
+}
+
+fn negative(v: i64) -> Expression {
+    Expression::UnaryExpression {
+        op: "-".to_string(),
+        expr: Box::new(lit(v)),
+    }
+}
+
+#[tokio::test]
+async fn test_generate_series_table_ascending() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_threads(3)?;
+    let table = GenerateSeriesTable::create();
+
+    // generate_series(-2, 10, 3)
+    let scan = generate_series_scan(vec![negative(2), lit(10i64), lit(3i64)]);
+    let source_plan = table.read_plan(ctx.clone(), &scan, ctx.get_max_threads()? as usize)?;
+    assert_eq!(5, source_plan.statistics.read_rows);
+    assert!(source_plan.partitions.len() > 1);
+    ctx.try_set_partitions(source_plan.partitions)?;
+
+    let stream = table.read(ctx).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+-----------------+",
+        "| generate_series |",
+        "+-----------------+",
+        "| -2              |",
+        "| 1               |",
+        "| 4               |",
+        "| 7               |",
+        "| 10              |",
+        "+-----------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_series_table_descending() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = GenerateSeriesTable::create();
+
+    // generate_series(5, -1, -2)
+    let scan = generate_series_scan(vec![lit(5i64), negative(1), negative(2)]);
+    let source_plan = table.read_plan(ctx.clone(), &scan, ctx.get_max_threads()? as usize)?;
+    assert_eq!(4, source_plan.statistics.read_rows);
+    ctx.try_set_partitions(source_plan.partitions)?;
+
+    let stream = table.read(ctx.clone()).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+-----------------+",
+        "| generate_series |",
+        "+-----------------+",
+        "| -1              |",
+        "| 1               |",
+        "| 3               |",
+        "| 5               |",
+        "+-----------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // The stop is never reached: generate_series(1, 5, -1) is empty.
+    let scan = generate_series_scan(vec![lit(1i64), lit(5i64), negative(1)]);
+    let source_plan = table.read_plan(ctx.clone(), &scan, 1)?;
+    assert_eq!(0, source_plan.statistics.read_rows);
+    ctx.try_set_partitions(source_plan.partitions)?;
+
+    let stream = table.read(ctx).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert!(result.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_generate_series_table_bad_arguments() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = GenerateSeriesTable::create();
+
+    // Zero step.
+    let scan = generate_series_scan(vec![lit(1i64), lit(5i64), lit(0i64)]);
+    let result = table.read_plan(ctx.clone(), &scan, 1);
+    assert_eq!(
+        "Code: 6, displayText = Table system.generate_series step must not be zero.",
+        result.err().unwrap().to_string()
+    );
+
+    // Not enough arguments.
+    let scan = generate_series_scan(vec![lit(1i64), lit(5i64)]);
+    let result = table.read_plan(ctx.clone(), &scan, 1);
+    assert_eq!(
+        "Code: 6, displayText = Table system.generate_series expects 3 arguments: (start, stop, step).",
+        result.err().unwrap().to_string()
+    );
+
+    // Not a literal.
+    let scan = generate_series_scan(vec![col("a"), lit(5i64), lit(1i64)]);
+    let result = table.read_plan(ctx, &scan, 1);
+    assert_eq!(
+        "Code: 6, displayText = Table system.generate_series arguments must be integer literals, but got a.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_series_table_int64_bounds() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    // -9223372036854775808 is parsed as -(9223372036854775808).
+    let min = Expression::UnaryExpression {
+        op: "-".to_string(),
+        expr: Box::new(lit(9223372036854775808u64)),
+    };
+    let max = lit(i64::MAX);
+    let table = GenerateSeriesTable::create();
+
+    // The values near the bounds.
+    let cases = vec![
+        (vec![min.clone(), negative(i64::MAX - 1), lit(1i64)], vec![
+            "+----------------------+",
+            "| generate_series      |",
+            "+----------------------+",
+            "| -9223372036854775808 |",
+            "| -9223372036854775807 |",
+            "| -9223372036854775806 |",
+            "+----------------------+",
+        ]),
+        (vec![max.clone(), lit(i64::MAX - 2), negative(1)], vec![
+            "+---------------------+",
+            "| generate_series     |",
+            "+---------------------+",
+            "| 9223372036854775805 |",
+            "| 9223372036854775806 |",
+            "| 9223372036854775807 |",
+            "+---------------------+",
+        ]),
+        // The i * step overflows Int64, the values don't.
+        (vec![min.clone(), max.clone(), lit(1i64 << 62)], vec![
+            "+----------------------+",
+            "| generate_series      |",
+            "+----------------------+",
+            "| -4611686018427387904 |",
+            "| -9223372036854775808 |",
+            "| 0                    |",
+            "| 4611686018427387904  |",
+            "+----------------------+",
+        ]),
+    ];
+    for (args, expected) in cases {
+        let ctx = crate::tests::try_create_context()?;
+        let scan = generate_series_scan(args);
+        let source_plan = table.read_plan(ctx.clone(), &scan, 1)?;
+        ctx.try_set_partitions(source_plan.partitions)?;
+
+        let stream = table.read(ctx).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    let ctx = crate::tests::try_create_context()?;
+
+    // The rows overflow u64.
+    let scan = generate_series_scan(vec![min.clone(), max.clone(), lit(1i64)]);
+    let result = table.read_plan(ctx.clone(), &scan, 1);
+    assert_eq!(
+        "Code: 43, displayText = Table system.generate_series has too many rows: (-9223372036854775808, 9223372036854775807, 1).",
+        result.err().unwrap().to_string()
+    );
+
+    // The read bytes overflow u64.
+    let scan = generate_series_scan(vec![min.clone(), max, lit(2i64)]);
+    let result = table.read_plan(ctx.clone(), &scan, 1);
+    assert_eq!(
+        "Code: 43, displayText = Table system.generate_series has too many rows: (-9223372036854775808, 9223372036854775807, 2).",
+        result.err().unwrap().to_string()
+    );
+
+    // The arguments out of the Int64 range.
+    let scan = generate_series_scan(vec![lit(1i64), lit(9223372036854775808u64), lit(1i64)]);
+    let result = table.read_plan(ctx.clone(), &scan, 1);
+    assert_eq!(
+        "Code: 43, displayText = Table system.generate_series argument 9223372036854775808 is out of the Int64 range.",
+        result.err().unwrap().to_string()
+    );
+
+    let below_min = Expression::UnaryExpression {
+        op: "-".to_string(),
+        expr: Box::new(lit(9223372036854775809u64)),
+    };
+    let scan = generate_series_scan(vec![below_min, lit(1i64), lit(1i64)]);
+    let result = table.read_plan(ctx, &scan, 1);
+    assert_eq!(
+        "Code: 43, displayText = Table system.generate_series argument -9223372036854775809 is out of the Int64 range.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
@@ -9,34 +9,56 @@ mod contributors_table_test;
 #[cfg(test)]
 mod databases_table_test;
 #[cfg(test)]
+mod engines_table_test;
+#[cfg(test)]
 mod functions_table_test;
 #[cfg(test)]
+mod generate_series_table_test;
+#[cfg(test)]
 mod numbers_table_test;
 #[cfg(test)]
+mod sessions_table_test;
+#[cfg(test)]
 mod settings_table_test;
 #[cfg(test)]
+mod table_functions_table_test;
+#[cfg(test)]
 mod tables_table_test;
+#[cfg(test)]
+mod tracing_table_test;
 
 mod clusters_table;
 mod contributors_table;
 mod databases_table;
+mod engines_table;
 mod functions_table;
+mod generate_series_stream;
+mod generate_series_table;
 mod numbers_stream;
 mod numbers_table;
 mod one_table;
+mod sessions_table;
 mod settings_table;
 mod system_database;
 mod system_factory;
+mod table_functions_table;
 mod tables_table;
+mod tracing_table;
 
 pub use clusters_table::ClustersTable;
 pub use contributors_table::ContributorsTable;
 pub use databases_table::DatabasesTable;
+pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
+pub use generate_series_stream::GenerateSeriesStream;
+pub use generate_series_table::GenerateSeriesTable;
 pub use numbers_stream::NumbersStream;
 pub use numbers_table::NumbersTable;
 pub use one_table::OneTable;
+pub use sessions_table::SessionsTable;
 pub use settings_table::SettingsTable;
 pub use system_database::SystemDatabase;
 pub use system_factory::SystemFactory;
+pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
+pub use tracing_table::TracingTable;
@@ -26,6 +26,9 @@ use crate::sessions::FuseQueryContextRef;
 struct BlockRange {
     begin: u64,
     end: u64,
+    // The row i of the range is: start + i * step
+    start: u64,
+    step: u64,
 }
 
 pub struct NumbersStream {
@@ -47,7 +50,8 @@ impl NumbersStream {
     }
 
     fn try_get_one_block(&mut self) -> Result<Option<DataBlock>> {
-        if (self.block_index as usize) == self.blocks.len() {
+        // The empty partitions have no block, fetch the next ones.
+        while (self.block_index as usize) == self.blocks.len() {
             let partitions = self.ctx.try_get_partitions(1)?;
             if partitions.is_empty() {
                 return Ok(None);
@@ -60,13 +64,30 @@ impl NumbersStream {
                 let names: Vec<_> = part.name.split('-').collect();
                 let begin: u64 = names[1].parse()?;
                 let end: u64 = names[2].parse()?;
+                let start: u64 = match names.get(3) {
+                    Some(v) => v.parse()?,
+                    None => 0,
+                };
+                let step: u64 = match names.get(4) {
+                    Some(v) => v.parse()?,
+                    None => 1,
+                };
 
                 let diff = end - begin;
+                if diff == 0 {
+                    continue;
+                }
+
                 let block_nums = diff / block_size;
                 let block_remain = diff % block_size;
 
                 if block_nums == 0 {
-                    blocks.push(BlockRange { begin, end });
+                    blocks.push(BlockRange {
+                        begin,
+                        end,
+                        start,
+                        step,
+                    });
                 } else {
                     for r in 0..block_nums {
                         let range_begin = begin + block_size * r;
@@ -77,6 +98,8 @@ impl NumbersStream {
                         blocks.push(BlockRange {
                             begin: range_begin,
                             end: range_end,
+                            start,
+                            step,
                         });
                     }
                 }
@@ -91,7 +114,13 @@ impl NumbersStream {
         Ok(if current.begin == current.end {
             None
         } else {
-            let v = (current.begin..current.end).collect::<Vec<u64>>();
+            let v = if current.start == 0 && current.step == 1 {
+                (current.begin..current.end).collect::<Vec<u64>>()
+            } else {
+                (current.begin..current.end)
+                    .map(|i| current.start + i * current.step)
+                    .collect::<Vec<u64>>()
+            };
             let mut me = ManuallyDrop::new(v);
             let byte_size = mem::size_of::<u64>();
 
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use std::any::Any;
+use std::convert::TryFrom;
 use std::mem::size_of;
 use std::sync::Arc;
 
@@ -14,11 +15,14 @@ use common_datavalues::DataValue;
 use common_exception::ErrorCodes;
 use common_exception::Result;
 use common_planners::Expression;
+use common_planners::Partition;
+use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::ScanPlan;
 use common_planners::Statistics;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::system::NumbersStream;
 use crate::datasources::Common;
 use crate::datasources::ITable;
@@ -41,6 +45,41 @@ impl NumbersTable {
             )]),
         }
     }
+
+    // The negative integer literals are rejected, instead of wrapped to u64.
+    fn literal_to_u64(&self, expr: &Expression) -> Result<Option<u64>> {
+        match expr {
+            Expression::Literal(DataValue::UInt64(Some(v))) => Ok(Some(*v)),
+            Expression::Literal(DataValue::Int64(Some(v))) => {
+                u64::try_from(*v).map(Some).map_err(|_| {
+                    ErrorCodes::BadArguments(format!(
+                        "Table system.{} arguments must not be negative, but got {}",
+                        self.name(),
+                        v
+                    ))
+                })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // numbers_mt always has one partition per worker to exercise the parallel execution,
+    // the partitions may be empty if there are less rows than workers.
+    fn generate_worker_parts(workers: u64, total: u64) -> Partitions {
+        (0..workers)
+            .map(|worker| {
+                // The total * worker may overflow u64, but not u128.
+                let begin = (total as u128 * worker as u128 / workers as u128) as u64;
+                let end = (total as u128 * (worker + 1) as u128 / workers as u128) as u64;
+                Partition {
+                    name: format!("{}-{}-{}", total, begin, end),
+                    version: 0,
+                    rows: end - begin,
+                    bytes: 0,
+                }
+            })
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -51,9 +90,9 @@ impl ITable for NumbersTable {
 
     fn engine(&self) -> &str {
         match self.table {
-            "numbers" => "SystemNumbers",
-            "numbers_mt" => "SystemNumbersMt",
-            "numbers_local" => "SystemNumbersLocal",
+            "numbers" => engine_names::SYSTEM_NUMBERS,
+            "numbers_mt" => engine_names::SYSTEM_NUMBERS_MT,
+            "numbers_local" => engine_names::SYSTEM_NUMBERS_LOCAL,
             _ => unreachable!(),
         }
     }
@@ -77,27 +116,93 @@ impl ITable for NumbersTable {
         scan: &ScanPlan,
         _partitions: usize,
     ) -> Result<ReadDataSourcePlan> {
+        let mut start = 0;
+        let mut step = 1;
         let mut total = ctx.get_max_block_size()? as u64;
 
         let ScanPlan { table_args, .. } = scan.clone();
-        if let Some(args) = table_args {
-            if let Expression::Literal(DataValue::UInt64(Some(v))) = args {
-                total = v;
+        match table_args {
+            // numbers(start, end, step)
+            Some(Expression::ScalarFunction { args, .. }) => {
+                if args.len() != 3 {
+                    return Result::Err(ErrorCodes::BadArguments(format!(
+                        "Table system.{} expects 1 or 3 arguments, but got {}",
+                        self.name(),
+                        args.len()
+                    )));
+                }
+
+                let values = args
+                    .iter()
+                    .map(|arg| {
+                        self.literal_to_u64(arg)?.ok_or_else(|| {
+                            ErrorCodes::BadArguments(format!(
+                                "Table system.{} arguments must be unsigned integer literals, but got {:?}",
+                                self.name(),
+                                arg
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                if values[2] == 0 {
+                    return Result::Err(ErrorCodes::BadArguments(format!(
+                        "Table system.{} step must be greater than zero",
+                        self.name()
+                    )));
+                }
+
+                start = values[0];
+                step = values[2];
+                // The values below end: start, start + step, ...
+                // (end - start - 1) / step doesn't overflow as (end - start + step - 1) does.
+                total = match values[1].checked_sub(start) {
+                    Some(len) if len > 0 => (len - 1) / step + 1,
+                    _ => 0,
+                };
             }
+            // numbers(n)
+            Some(args) => {
+                if let Some(v) = self.literal_to_u64(&args)? {
+                    total = v;
+                }
+            }
+            None => {
+                return Result::Err(ErrorCodes::BadArguments(format!(
+                    "Must have one argument for table: system.{}",
+                    self.name()
+                )));
+            }
+        }
 
-            if let Expression::Literal(DataValue::Int64(Some(v))) = args {
-                total = v as u64;
+        let too_many_rows = || {
+            ErrorCodes::NumericOverflow(format!(
+                "Table system.{} has too many rows: {}",
+                self.name(),
+                total
+            ))
+        };
+        let read_rows = usize::try_from(total).map_err(|_| too_many_rows())?;
+        let read_bytes = total
+            .checked_mul(size_of::<u64>() as u64)
+            .and_then(|bytes| usize::try_from(bytes).ok())
+            .ok_or_else(too_many_rows)?;
+
+        let workers = ctx.get_max_threads()?;
+        let mut partitions = match self.table {
+            "numbers_mt" => Self::generate_worker_parts(workers, total),
+            _ => Common::generate_parts(0, workers, total),
+        };
+        if start != 0 || step != 1 {
+            for part in partitions.iter_mut() {
+                part.name = format!("{}-{}-{}", part.name, start, step);
             }
-        } else {
-            return Result::Err(ErrorCodes::BadArguments(format!(
-                "Must have one argument for table: system.{}",
-                self.name()
-            )));
         }
 
         let statistics = Statistics {
-            read_rows: total as usize,
-            read_bytes: ((total) * size_of::<u64>() as u64) as usize,
+            read_rows,
+            read_bytes,
+            null_counts: None,
         };
         ctx.try_set_statistics(&statistics)?;
         ctx.add_total_rows_approx(statistics.read_rows);
@@ -106,7 +211,7 @@ impl ITable for NumbersTable {
             db: "system".to_string(),
             table: self.name().to_string(),
             schema: self.schema.clone(),
-            partitions: Common::generate_parts(0, ctx.get_max_threads()?, total),
+            partitions,
             statistics: statistics.clone(),
             description: format!(
                 "(Read from system.{} table, Read Rows:{}, Read Bytes:{})",
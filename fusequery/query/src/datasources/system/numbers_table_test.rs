@@ -53,3 +53,252 @@ async fn test_number_table() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_number_table_with_step() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = NumbersTable::create("numbers");
+
+    // numbers(2, 10, 3)
+    let scan = &ScanPlan {
+        schema_name: "scan_test".to_string(),
+        table_schema: DataSchemaRefExt::create(vec![]),
+        table_args: Some(Expression::ScalarFunction {
+            op: "numbers".to_string(),
+            args: vec![lit(2u64), lit(10u64), lit(3u64)],
+        }),
+        projection: None,
+        projected_schema: DataSchemaRefExt::create(vec![DataField::new(
+            "number",
+            DataType::UInt64,
+            false,
+        )]),
+        filters: vec![],
+        limit: None,
+    };
+    let source_plan = table.read_plan(ctx.clone(), scan, ctx.get_max_threads()? as usize)?;
+    assert_eq!(3, source_plan.statistics.read_rows);
+    ctx.try_set_partitions(source_plan.partitions)?;
+
+    let stream = table.read(ctx.clone()).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+--------+",
+        "| number |",
+        "+--------+",
+        "| 2      |",
+        "| 5      |",
+        "| 8      |",
+        "+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // Zero step.
+    let scan = &ScanPlan {
+        table_args: Some(Expression::ScalarFunction {
+            op: "numbers".to_string(),
+            args: vec![lit(2u64), lit(10u64), lit(0u64)],
+        }),
+        ..scan.clone()
+    };
+    let result = table.read_plan(ctx.clone(), scan, ctx.get_max_threads()? as usize);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_number_table_u64_bounds() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let table = NumbersTable::create("numbers");
+    let scan = |table_args: Expression| ScanPlan {
+        schema_name: "scan_test".to_string(),
+        table_schema: DataSchemaRefExt::create(vec![]),
+        table_args: Some(table_args),
+        projection: None,
+        projected_schema: DataSchemaRefExt::create(vec![DataField::new(
+            "number",
+            DataType::UInt64,
+            false,
+        )]),
+        filters: vec![],
+        limit: None,
+    };
+    let numbers = |args: Vec<Expression>| {
+        scan(Expression::ScalarFunction {
+            op: "numbers".to_string(),
+            args,
+        })
+    };
+
+    // The end - start + step overflows u64, the rows don't.
+    let cases = vec![
+        (vec![lit(u64::MAX - 1), lit(u64::MAX), lit(u64::MAX)], vec![
+            "+----------------------+",
+            "| number               |",
+            "+----------------------+",
+            "| 18446744073709551614 |",
+            "+----------------------+",
+        ]),
+        (vec![lit(0u64), lit(u64::MAX), lit(1u64 << 63)], vec![
+            "+---------------------+",
+            "| number              |",
+            "+---------------------+",
+            "| 0                   |",
+            "| 9223372036854775808 |",
+            "+---------------------+",
+        ]),
+    ];
+    for (args, expected) in cases {
+        let ctx = crate::tests::try_create_context()?;
+        let source_plan = table.read_plan(ctx.clone(), &numbers(args), 1)?;
+        ctx.try_set_partitions(source_plan.partitions)?;
+
+        let stream = table.read(ctx).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    let ctx = crate::tests::try_create_context()?;
+
+    // The end is below the start.
+    let source_plan = table.read_plan(
+        ctx.clone(),
+        &numbers(vec![lit(5u64), lit(2u64), lit(1u64)]),
+        1,
+    )?;
+    assert_eq!(0, source_plan.statistics.read_rows);
+
+    // The read bytes overflow u64.
+    let result = table.read_plan(ctx.clone(), &scan(lit(u64::MAX)), 1);
+    assert_eq!(
+        "Code: 43, displayText = Table system.numbers has too many rows: 18446744073709551615.",
+        result.err().unwrap().to_string()
+    );
+
+    // The negative arguments are not wrapped.
+    let result = table.read_plan(ctx.clone(), &scan(lit(-1i64)), 1);
+    assert_eq!(
+        "Code: 6, displayText = Table system.numbers arguments must not be negative, but got -1.",
+        result.err().unwrap().to_string()
+    );
+    let result = table.read_plan(ctx, &numbers(vec![lit(0u64), lit(-2i64), lit(1u64)]), 1);
+    assert_eq!(
+        "Code: 6, displayText = Table system.numbers arguments must not be negative, but got -2.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_number_table_read_with_limit() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_threads(8)?;
+    let table = NumbersTable::create("numbers_mt");
+
+    let total = 1_000_000u64;
+    let scan = &ScanPlan {
+        table_args: Some(Expression::Literal(DataValue::UInt64(Some(total)))),
+        ..ScanPlan::empty()
+    };
+    let source_plan = table.read_plan(ctx.clone(), scan, ctx.get_max_threads()? as usize)?;
+    ctx.try_set_partitions(source_plan.partitions)?;
+
+    let stream = table.read_with_limit(ctx.clone(), Some(5)).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(5, rows);
+
+    // The upstream stops early, the rest of the partitions are left unread.
+    assert!((ctx.get_progress_value().read_rows as u64) < total);
+    assert!(!ctx.try_get_partitions(1)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_number_table_function_schema() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    for name in &["numbers", "numbers_mt"] {
+        let func = datasource.get_table_function(name)?;
+        assert_eq!(*name, func.function_name());
+        assert_eq!(
+            DataSchemaRefExt::create(vec![DataField::new("number", DataType::UInt64, false)]),
+            func.schema()?
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_number_mt_table_partitions() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_threads(8)?;
+    let table = NumbersTable::create("numbers_mt");
+
+    for total in &[3u64, 8, 1001] {
+        let scan = &ScanPlan {
+            table_args: Some(Expression::Literal(DataValue::UInt64(Some(*total)))),
+            ..ScanPlan::empty()
+        };
+        let source_plan = table.read_plan(ctx.clone(), scan, ctx.get_max_threads()? as usize)?;
+        assert_eq!(8, source_plan.partitions.len());
+
+        // The partitions are contiguous and cover 0..total.
+        let mut next = 0;
+        for part in &source_plan.partitions {
+            let names: Vec<_> = part.name.split('-').collect();
+            let begin: u64 = names[1].parse()?;
+            let end: u64 = names[2].parse()?;
+            assert_eq!(next, begin);
+            assert_eq!(end - begin, part.rows);
+            next = end;
+        }
+        assert_eq!(*total, next);
+
+        ctx.try_set_partitions(source_plan.partitions)?;
+        let stream = table.read(ctx.clone()).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+        assert_eq!(*total as usize, rows);
+    }
+
+    Ok(())
+}
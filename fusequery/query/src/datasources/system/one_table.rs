@@ -19,6 +19,7 @@ use common_planners::Statistics;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -41,7 +42,7 @@ impl ITable for OneTable {
     }
 
     fn engine(&self) -> &str {
-        "SystemOne"
+        engine_names::SYSTEM_ONE
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -69,6 +70,8 @@ impl ITable for OneTable {
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: "(Read from system.one table)".to_string(),
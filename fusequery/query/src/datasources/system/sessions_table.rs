@@ -0,0 +1,105 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::StringArray;
+use common_exception::Result;
+use common_planners::Partition;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::datasources::engine_names;
+use crate::datasources::ITable;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct SessionsTable {
+    schema: DataSchemaRef,
+}
+
+impl SessionsTable {
+    pub fn create() -> Self {
+        SessionsTable {
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("id", DataType::Utf8, false),
+                DataField::new("database", DataType::Utf8, false),
+                DataField::new("client_host", DataType::Utf8, false),
+                DataField::new("started_at", DataType::Utf8, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ITable for SessionsTable {
+    fn name(&self) -> &str {
+        "sessions"
+    }
+
+    fn engine(&self) -> &str {
+        engine_names::SYSTEM_SESSIONS
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: FuseQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions: vec![Partition {
+                name: "".to_string(),
+                version: 0,
+                rows: 0,
+                bytes: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.sessions table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+        })
+    }
+
+    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+        let sessions = ctx.get_sessions_info()?;
+        let ids: Vec<&str> = sessions.iter().map(|x| x.id.as_str()).collect();
+        let databases: Vec<&str> = sessions.iter().map(|x| x.database.as_str()).collect();
+        let client_hosts: Vec<&str> = sessions.iter().map(|x| x.client_host.as_str()).collect();
+        let started_ats: Vec<&str> = sessions.iter().map(|x| x.started_at.as_str()).collect();
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Arc::new(StringArray::from(ids)),
+            Arc::new(StringArray::from(databases)),
+            Arc::new(StringArray::from(client_hosts)),
+            Arc::new(StringArray::from(started_ats)),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
+    }
+}
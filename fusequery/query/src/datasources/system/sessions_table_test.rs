@@ -0,0 +1,52 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sessions_table() -> Result<()> {
+    use common_planners::*;
+    use futures::TryStreamExt;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+    use crate::sessions::SessionManager;
+
+    let sessions = SessionManager::create();
+    let ctx = sessions
+        .try_create_context()?
+        .with_client_host("127.0.0.1:3307")?;
+    ctx.set_current_database("system".to_string())?;
+    let other = sessions.try_create_context()?;
+
+    let table = SessionsTable::create();
+    assert!(!table.is_writable());
+    table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_max_threads()? as usize,
+    )?;
+
+    let stream = table.read(ctx.clone()).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 4);
+    assert_eq!(block.num_rows(), 2);
+
+    let (ctx_id, other_id) = (ctx.get_id()?, other.get_id()?);
+    let infos = ctx.get_sessions_info()?;
+    let info = infos.iter().find(|x| x.id == ctx_id).unwrap();
+    assert_eq!("system", info.database);
+    assert_eq!("127.0.0.1:3307", info.client_host);
+    let info = infos.iter().find(|x| x.id == other_id).unwrap();
+    assert_eq!("default", info.database);
+
+    // The removed session disappears.
+    sessions.try_remove_context(other)?;
+    let stream = table.read(ctx).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(result[0].num_rows(), 1);
+
+    Ok(())
+}
@@ -20,6 +20,7 @@ use common_planners::Statistics;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -47,7 +48,7 @@ impl ITable for SettingsTable {
     }
 
     fn engine(&self) -> &str {
-        "SystemSettings"
+        engine_names::SYSTEM_SETTINGS
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -75,6 +76,8 @@ impl ITable for SettingsTable {
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: "(Read from system.settings table)".to_string(),
@@ -10,6 +10,7 @@ use common_exception::Result;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 
+use crate::datasources::engine_names;
 use crate::datasources::system;
 use crate::datasources::IDatabase;
 use crate::datasources::ITable;
@@ -21,20 +22,7 @@ pub struct SystemDatabase {
 }
 
 impl SystemDatabase {
-    pub fn create() -> Self {
-        // Table list.
-        let table_list: Vec<Arc<dyn ITable>> = vec![
-            Arc::new(system::OneTable::create()),
-            Arc::new(system::FunctionsTable::create()),
-            Arc::new(system::ContributorsTable::create()),
-            Arc::new(system::SettingsTable::create()),
-            Arc::new(system::NumbersTable::create("numbers")),
-            Arc::new(system::NumbersTable::create("numbers_mt")),
-            Arc::new(system::NumbersTable::create("numbers_local")),
-            Arc::new(system::TablesTable::create()),
-            Arc::new(system::ClustersTable::create()),
-            Arc::new(system::DatabasesTable::create()),
-        ];
+    pub fn create(table_list: Vec<Arc<dyn ITable>>) -> Self {
         let mut tables: HashMap<String, Arc<dyn ITable>> = HashMap::default();
         for tbl in table_list.iter() {
             tables.insert(tbl.name().to_string(), tbl.clone());
@@ -45,6 +33,7 @@ impl SystemDatabase {
             Arc::new(system::NumbersTable::create("numbers")),
             Arc::new(system::NumbersTable::create("numbers_mt")),
             Arc::new(system::NumbersTable::create("numbers_local")),
+            Arc::new(system::GenerateSeriesTable::create()),
         ];
         let mut table_functions: HashMap<String, Arc<dyn ITableFunction>> = HashMap::default();
         for tbl_func in table_function_list.iter() {
@@ -65,7 +54,7 @@ impl IDatabase for SystemDatabase {
     }
 
     fn engine(&self) -> &str {
-        "local"
+        engine_names::SYSTEM_DATABASE
     }
 
     fn is_local(&self) -> bool {
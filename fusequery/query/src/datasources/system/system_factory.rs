@@ -6,18 +6,45 @@ use std::sync::Arc;
 
 use common_exception::Result;
 
+use crate::datasources::system;
 use crate::datasources::system::SystemDatabase;
 use crate::datasources::IDatabase;
+use crate::datasources::ITable;
 
-pub struct SystemFactory;
+pub struct SystemFactory {
+    tables: Vec<Arc<dyn ITable>>,
+}
 
 impl SystemFactory {
     pub fn create() -> Self {
-        Self
+        let mut factory = SystemFactory { tables: vec![] };
+
+        // Builtin tables.
+        factory.register(Arc::new(system::OneTable::create()));
+        factory.register(Arc::new(system::FunctionsTable::create()));
+        factory.register(Arc::new(system::ContributorsTable::create()));
+        factory.register(Arc::new(system::SettingsTable::create()));
+        factory.register(Arc::new(system::NumbersTable::create("numbers")));
+        factory.register(Arc::new(system::NumbersTable::create("numbers_mt")));
+        factory.register(Arc::new(system::NumbersTable::create("numbers_local")));
+        factory.register(Arc::new(system::TablesTable::create()));
+        factory.register(Arc::new(system::TableFunctionsTable::create()));
+        factory.register(Arc::new(system::ClustersTable::create()));
+        factory.register(Arc::new(system::DatabasesTable::create()));
+        factory.register(Arc::new(system::EnginesTable::create()));
+        factory.register(Arc::new(system::SessionsTable::create()));
+        factory.register(Arc::new(system::TracingTable::create()));
+        factory
+    }
+
+    /// Add a table to the system database, it replaces the registered table of the same name.
+    pub fn register(&mut self, table: Arc<dyn ITable>) {
+        self.tables.push(table);
     }
 
     pub fn load_databases(&self) -> Result<Vec<Arc<dyn IDatabase>>> {
-        let databases: Vec<Arc<dyn IDatabase>> = vec![Arc::new(SystemDatabase::create())];
+        let databases: Vec<Arc<dyn IDatabase>> =
+            vec![Arc::new(SystemDatabase::create(self.tables.clone()))];
         Ok(databases)
     }
 }
@@ -0,0 +1,114 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::StringArray;
+use common_exception::Result;
+use common_planners::Partition;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::datasources::engine_names;
+use crate::datasources::ITable;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct TableFunctionsTable {
+    schema: DataSchemaRef,
+}
+
+impl TableFunctionsTable {
+    pub fn create() -> Self {
+        TableFunctionsTable {
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("name", DataType::Utf8, false),
+                DataField::new("schema", DataType::Utf8, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ITable for TableFunctionsTable {
+    fn name(&self) -> &str {
+        "table_functions"
+    }
+
+    fn engine(&self) -> &str {
+        engine_names::SYSTEM_TABLE_FUNCTIONS
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: FuseQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions: vec![Partition {
+                name: "".to_string(),
+                version: 0,
+                rows: 0,
+                bytes: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.table_functions table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+        })
+    }
+
+    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+        let infos = ctx.get_datasource().list_table_functions()?;
+
+        let names: Vec<&str> = infos.iter().map(|info| info.name.as_str()).collect();
+        // The fields are rendered as name:type, e.g. number:UInt64.
+        let schemas: Vec<String> = infos
+            .iter()
+            .map(|info| {
+                info.schema
+                    .fields()
+                    .iter()
+                    .map(|field| format!("{}:{:?}", field.name(), field.data_type()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        let schemas: Vec<&str> = schemas.iter().map(|schema| schema.as_str()).collect();
+
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Arc::new(StringArray::from(names)),
+            Arc::new(StringArray::from(schemas)),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_table_functions_table() -> anyhow::Result<()> {
+    use common_planners::*;
+    use futures::TryStreamExt;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = TableFunctionsTable::create();
+    table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_max_threads()? as usize,
+    )?;
+
+    let stream = table.read(ctx).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 2);
+
+    let expected = vec![
+        "+-----------------+-----------------------+",
+        "| name            | schema                |",
+        "+-----------------+-----------------------+",
+        "| generate_series | generate_series:Int64 |",
+        "| numbers         | number:UInt64         |",
+        "| numbers_local   | number:UInt64         |",
+        "| numbers_mt      | number:UInt64         |",
+        "+-----------------+-----------------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
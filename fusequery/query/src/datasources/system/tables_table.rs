@@ -19,6 +19,7 @@ use common_planners::Statistics;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::datasources::engine_names;
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 
@@ -45,7 +46,7 @@ impl ITable for TablesTable {
     }
 
     fn engine(&self) -> &str {
-        "SystemTables"
+        engine_names::SYSTEM_TABLES
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -73,6 +74,8 @@ impl ITable for TablesTable {
             partitions: vec![Partition {
                 name: "".to_string(),
                 version: 0,
+                rows: 0,
+                bytes: 0,
             }],
             statistics: Statistics::default(),
             description: "(Read from system.functions table)".to_string(),
@@ -0,0 +1,106 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::StringArray;
+use common_exception::Result;
+use common_planners::Partition;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::datasources::engine_names;
+use crate::datasources::ITable;
+use crate::logging::LogBuffer;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct TracingTable {
+    schema: DataSchemaRef,
+}
+
+impl TracingTable {
+    pub fn create() -> Self {
+        TracingTable {
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("timestamp", DataType::Utf8, false),
+                DataField::new("level", DataType::Utf8, false),
+                DataField::new("target", DataType::Utf8, false),
+                DataField::new("message", DataType::Utf8, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ITable for TracingTable {
+    fn name(&self) -> &str {
+        "tracing_log"
+    }
+
+    fn engine(&self) -> &str {
+        engine_names::SYSTEM_TRACING_LOG
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: FuseQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions: vec![Partition {
+                name: "".to_string(),
+                version: 0,
+                rows: 0,
+                bytes: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.tracing_log table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+        })
+    }
+
+    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+        let records = LogBuffer::global().records();
+        let timestamps: Vec<&str> = records.iter().map(|x| x.timestamp.as_str()).collect();
+        let levels: Vec<&str> = records.iter().map(|x| x.level.as_str()).collect();
+        let targets: Vec<&str> = records.iter().map(|x| x.target.as_str()).collect();
+        let messages: Vec<&str> = records.iter().map(|x| x.message.as_str()).collect();
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Arc::new(StringArray::from(timestamps)),
+            Arc::new(StringArray::from(levels)),
+            Arc::new(StringArray::from(targets)),
+            Arc::new(StringArray::from(messages)),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
+    }
+}
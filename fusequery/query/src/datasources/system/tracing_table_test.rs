@@ -0,0 +1,65 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_table() -> Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use log::Log;
+
+    use crate::datasources::system::*;
+    use crate::datasources::*;
+    use crate::logging::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let table = TracingTable::create();
+    assert!(!table.is_writable());
+    table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_max_threads()? as usize,
+    )?;
+
+    // Emit a log record through the buffered logger.
+    let inner = env_logger::Builder::new()
+        .filter_level(log::LevelFilter::Info)
+        .build();
+    let logger = BufferedLogger::create(inner, LogBuffer::global());
+    logger.log(
+        &log::Record::builder()
+            .args(format_args!("test tracing table"))
+            .level(log::Level::Warn)
+            .target("tracing_table_test")
+            .build(),
+    );
+    // The record below the level is not kept.
+    logger.log(
+        &log::Record::builder()
+            .args(format_args!("test tracing table debug"))
+            .level(log::Level::Debug)
+            .target("tracing_table_test")
+            .build(),
+    );
+
+    let stream = table.read(ctx).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 4);
+
+    let levels = block.try_array_by_name("level")?;
+    let levels = levels.as_any().downcast_ref::<StringArray>().unwrap();
+    let messages = block.try_array_by_name("message")?;
+    let messages = messages.as_any().downcast_ref::<StringArray>().unwrap();
+    let found = (0..block.num_rows())
+        .filter(|i| messages.value(*i).starts_with("test tracing table"))
+        .collect::<Vec<_>>();
+    assert_eq!(1, found.len());
+    assert_eq!("WARN", levels.value(found[0]));
+    assert_eq!("test tracing table", messages.value(found[0]));
+
+    Ok(())
+}
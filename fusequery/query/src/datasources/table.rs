@@ -7,11 +7,15 @@ use std::any::Any;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCodes;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::InsertIntoPlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::ScanPlan;
+use common_streams::LimitStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
 
+use crate::datasources::Common;
 use crate::sessions::FuseQueryContextRef;
 
 #[async_trait::async_trait]
@@ -22,6 +26,15 @@ pub trait ITable: Sync + Send {
     fn schema(&self) -> Result<DataSchemaRef>;
     // Is Local or Remote.
     fn is_local(&self) -> bool;
+    // Whether the table accepts the append_data(INSERT).
+    fn is_writable(&self) -> bool {
+        false
+    }
+    // The expressions which the data is sorted/distributed by, empty if unknown.
+    // The optimizer can skip the re-sorts by them.
+    fn cluster_keys(&self) -> Vec<Expression> {
+        vec![]
+    }
     // Get the read source plan.
     fn read_plan(
         &self,
@@ -31,6 +44,33 @@ pub trait ITable: Sync + Send {
     ) -> Result<ReadDataSourcePlan>;
     // Read block data from the underling.
     async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream>;
+    // Read block data from the underling, stop reading once the limit rows are produced.
+    async fn read_with_limit(
+        &self,
+        ctx: FuseQueryContextRef,
+        limit: Option<usize>,
+    ) -> Result<SendableDataBlockStream> {
+        let stream = self.read(ctx).await?;
+        match limit {
+            None => Ok(stream),
+            Some(n) => Ok(Box::pin(LimitStream::try_create(stream, n)?)),
+        }
+    }
+
+    // The checksum of all the rows, it's independent of the row order(see Common::block_checksum).
+    // The tables holding the same rows have the same checksum.
+    async fn checksum(&self, ctx: FuseQueryContextRef) -> Result<u64> {
+        let max_threads = ctx.get_max_threads()? as usize;
+        let plan = self.read_plan(ctx.clone(), &ScanPlan::empty(), max_threads)?;
+        ctx.try_set_partitions(plan.partitions)?;
+
+        let mut checksum = 0u64;
+        let mut stream = self.read(ctx).await?;
+        while let Some(block) = stream.next().await {
+            checksum = checksum.wrapping_add(Common::block_checksum(&block?)?);
+        }
+        Ok(checksum)
+    }
 
     // temporary added, pls feel free to rm it
     async fn append_data(
@@ -44,3 +84,10 @@ pub trait ITable: Sync + Send {
         )))
     }
 }
+
+impl dyn ITable {
+    /// Downcast the table to the concrete engine type, None if the type not matched.
+    pub fn downcast_ref<T: ITable + 'static>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+}
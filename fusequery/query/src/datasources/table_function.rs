@@ -4,8 +4,11 @@
 
 use std::sync::Arc;
 
+use common_datavalues::DataSchemaRef;
+
 use crate::datasources::ITable;
 
+/// A table function like numbers(n), its output schema is the `ITable::schema`.
 pub trait ITableFunction: Sync + Send + ITable {
     fn function_name(&self) -> &str;
     fn db(&self) -> &str;
@@ -13,3 +16,10 @@ pub trait ITableFunction: Sync + Send + ITable {
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn ITable + 'a>
     where Self: 'a;
 }
+
+/// The name and output schema of a registered table function, for the listings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableFunctionInfo {
+    pub name: String,
+    pub schema: DataSchemaRef,
+}
@@ -0,0 +1,28 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+
+use crate::datasources::ITable;
+
+/// The metadata of a table, for the listings which don't read the table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableMeta {
+    pub name: String,
+    pub engine: String,
+    pub schema: DataSchemaRef,
+    pub is_local: bool,
+}
+
+impl TableMeta {
+    pub fn try_create(table: &dyn ITable) -> Result<Self> {
+        Ok(TableMeta {
+            name: table.name().to_string(),
+            engine: table.engine().to_string(),
+            schema: table.schema()?,
+            is_local: table.is_local(),
+        })
+    }
+}
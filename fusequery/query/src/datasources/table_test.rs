@@ -0,0 +1,150 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use pretty_assertions::assert_eq;
+
+use crate::datasources::ITable;
+use crate::sessions::FuseQueryContextRef;
+
+struct MockTable {
+    schema: DataSchemaRef,
+    cluster_keys: Vec<Expression>,
+    blocks: Vec<DataBlock>,
+}
+
+#[async_trait::async_trait]
+impl ITable for MockTable {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    fn engine(&self) -> &str {
+        "Mock"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn cluster_keys(&self) -> Vec<Expression> {
+        self.cluster_keys.clone()
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: FuseQueryContextRef,
+        _scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan::empty())
+    }
+
+    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            self.blocks.clone(),
+        )))
+    }
+}
+
+#[test]
+fn test_table_cluster_keys() -> Result<()> {
+    use crate::datasources::system::OneTable;
+
+    // Default is empty.
+    let table: Box<dyn ITable> = Box::new(OneTable::create());
+    assert!(table.cluster_keys().is_empty());
+
+    // The engine reports its cluster key.
+    let table: Box<dyn ITable> = Box::new(MockTable {
+        schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+        cluster_keys: vec![col("a")],
+        blocks: vec![],
+    });
+    assert_eq!(vec![col("a")], table.cluster_keys());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_checksum() -> Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::TableOptions;
+
+    use crate::datasources::local::NullTable;
+
+    let ctx = crate::tests::try_create_context()?;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]);
+    let block = |a: Vec<i64>, b: Vec<&str>| {
+        DataBlock::create_by_array(schema.clone(), vec![
+            Arc::new(Int64Array::from(a)),
+            Arc::new(StringArray::from(b)),
+        ])
+    };
+    let table = |blocks: Vec<DataBlock>| -> Box<dyn ITable> {
+        Box::new(MockTable {
+            schema: schema.clone(),
+            cluster_keys: vec![],
+            blocks,
+        })
+    };
+
+    let t1 = table(vec![
+        block(vec![1, 2], vec!["x", "y"]),
+        block(vec![3], vec!["z"]),
+    ]);
+    let checksum = t1.checksum(ctx.clone()).await?;
+    assert_ne!(0, checksum);
+
+    // Same rows, different blocks and order.
+    let t2 = table(vec![block(vec![3, 1, 2], vec!["z", "x", "y"])]);
+    assert_eq!(checksum, t2.checksum(ctx.clone()).await?);
+
+    // Different values.
+    let t3 = table(vec![block(vec![1, 2, 3], vec!["x", "y", "w"])]);
+    assert_ne!(checksum, t3.checksum(ctx.clone()).await?);
+
+    // Values swapped between the rows.
+    let t4 = table(vec![block(vec![1, 2, 3], vec!["y", "x", "z"])]);
+    assert_ne!(checksum, t4.checksum(ctx.clone()).await?);
+
+    // Null engine.
+    let null = NullTable::try_create(
+        "default".into(),
+        "a".into(),
+        schema.clone(),
+        TableOptions::default(),
+    )?;
+    assert_eq!(0, null.checksum(ctx.clone()).await?);
+
+    Ok(())
+}
@@ -49,5 +49,895 @@ async fn test_datasource() -> anyhow::Result<()> {
         assert_eq!(true, result.is_err());
     }
 
+    // Snapshot tests.
+    {
+        datasource
+            .create_database(CreateDatabasePlan {
+                if_not_exists: false,
+                db: "snapshot_db".to_string(),
+                engine: DatabaseEngineType::Local,
+                options: Default::default(),
+            })
+            .await?;
+
+        let snapshot = datasource.snapshot()?;
+        datasource
+            .drop_database(DropDatabasePlan {
+                if_exists: false,
+                db: "snapshot_db".to_string(),
+            })
+            .await?;
+
+        // The snapshot still resolves the dropped database.
+        assert_eq!(true, datasource.get_database("snapshot_db").is_err());
+        assert_eq!(true, snapshot.get_database("snapshot_db").is_ok());
+        assert_eq!(
+            true,
+            snapshot
+                .get_databases()?
+                .contains(&"snapshot_db".to_string())
+        );
+        snapshot.get_table("system", "numbers_mt")?;
+        snapshot.get_table_function("numbers")?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_get_tables() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    datasource
+        .create_database(CreateDatabasePlan {
+            if_not_exists: false,
+            db: "tables_db".to_string(),
+            engine: DatabaseEngineType::Local,
+            options: Default::default(),
+        })
+        .await?;
+
+    let database = datasource.get_database("tables_db")?;
+    for name in &["t1", "t2"] {
+        database
+            .create_table(CreateTablePlan {
+                if_not_exists: false,
+                db: "tables_db".to_string(),
+                table: name.to_string(),
+                schema: DataSchemaRefExt::create(vec![DataField::new(
+                    "a",
+                    DataType::UInt64,
+                    false,
+                )]),
+                engine: TableEngineType::Null,
+                options: TableOptions::default(),
+            })
+            .await?;
+    }
+
+    let mut names = datasource
+        .get_tables("tables_db")?
+        .iter()
+        .map(|table| table.name().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(vec!["t1", "t2"], names);
+
+    // Missing database.
+    match datasource.get_tables("missing_db") {
+        Ok(_) => panic!("expect unknown database error"),
+        Err(e) => assert_eq!(
+            "Code: 3, displayText = Unknown database: 'missing_db'.",
+            e.to_string()
+        ),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_ddl_listener() -> anyhow::Result<()> {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_exception::ErrorCodes;
+    use common_exception::Result;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    #[derive(Default)]
+    struct CountingListener {
+        create_database: AtomicUsize,
+        drop_database: AtomicUsize,
+        create_table: AtomicUsize,
+        drop_table: AtomicUsize,
+    }
+
+    impl IDdlListener for CountingListener {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn on_create_database(&self, _plan: &CreateDatabasePlan) -> Result<()> {
+            self.create_database.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn on_drop_database(&self, _plan: &DropDatabasePlan) -> Result<()> {
+            self.drop_database.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn on_create_table(&self, _plan: &CreateTablePlan) -> Result<()> {
+            self.create_table.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn on_drop_table(&self, _plan: &DropTablePlan) -> Result<()> {
+            self.drop_table.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct FailingListener;
+
+    impl IDdlListener for FailingListener {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn on_create_database(&self, _plan: &CreateDatabasePlan) -> Result<()> {
+            Err(ErrorCodes::LogicalError("listener failed"))
+        }
+    }
+
+    let datasource = DataSource::try_create()?;
+    let listener = Arc::new(CountingListener::default());
+    // The failing listener is registered first, it doesn't stop the DDL nor the others.
+    datasource.register_ddl_listener(Arc::new(FailingListener));
+    datasource.register_ddl_listener(listener.clone());
+
+    datasource
+        .create_database(CreateDatabasePlan {
+            if_not_exists: false,
+            db: "listener_db".to_string(),
+            engine: DatabaseEngineType::Local,
+            options: Default::default(),
+        })
+        .await?;
+    assert!(datasource.get_database("listener_db").is_ok());
+
+    datasource
+        .create_table(CreateTablePlan {
+            if_not_exists: false,
+            db: "listener_db".to_string(),
+            table: "t1".to_string(),
+            schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+            engine: TableEngineType::Null,
+            options: TableOptions::default(),
+        })
+        .await?;
+    datasource
+        .drop_table(DropTablePlan {
+            if_exists: false,
+            db: "listener_db".to_string(),
+            table: "t1".to_string(),
+        })
+        .await?;
+
+    // A failed DDL doesn't notify.
+    let result = datasource
+        .drop_table(DropTablePlan {
+            if_exists: false,
+            db: "listener_db".to_string(),
+            table: "t1".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    datasource
+        .drop_database(DropDatabasePlan {
+            if_exists: false,
+            db: "listener_db".to_string(),
+        })
+        .await?;
+
+    assert_eq!(1, listener.create_database.load(Ordering::SeqCst));
+    assert_eq!(1, listener.drop_database.load(Ordering::SeqCst));
+    assert_eq!(1, listener.create_table.load(Ordering::SeqCst));
+    assert_eq!(1, listener.drop_table.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_clear_user_databases() -> anyhow::Result<()> {
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    let mut builtin = datasource.get_databases()?;
+    builtin.sort();
+
+    for db in &["user_db1", "user_db2"] {
+        datasource
+            .create_database(CreateDatabasePlan {
+                if_not_exists: false,
+                db: db.to_string(),
+                engine: DatabaseEngineType::Local,
+                options: Default::default(),
+            })
+            .await?;
+    }
+    assert_eq!(builtin.len() + 2, datasource.get_databases()?.len());
+
+    datasource.clear_user_databases();
+
+    let mut databases = datasource.get_databases()?;
+    databases.sort();
+    assert_eq!(builtin, databases);
+    assert!(databases.contains(&"system".to_string()));
+    assert!(databases.contains(&"default".to_string()));
+    assert!(datasource.get_database("user_db1").is_err());
+    datasource.get_table_function("numbers")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_datasource_register_system_table() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::datasources::local::NullTable;
+    use crate::datasources::*;
+
+    let table = NullTable::try_create(
+        "system".into(),
+        "custom".into(),
+        DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+        TableOptions::default(),
+    )?;
+    let mut factory = SystemFactory::create();
+    factory.register(Arc::from(table));
+
+    let datasource = DataSource::try_create_with_system_factory(&Config::default(), factory)?;
+    let table = datasource.get_table("system", "custom")?;
+    assert_eq!("custom", table.name());
+    assert_eq!("Null", table.engine());
+
+    // The builtin tables are kept.
+    datasource.get_table("system", "numbers_mt")?;
+
+    // Not registered by default.
+    let datasource = DataSource::try_create()?;
+    assert!(datasource.get_table("system", "custom").is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_get_table_meta() -> anyhow::Result<()> {
+    use std::any::Any;
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_exception::Result;
+    use common_planners::*;
+    use common_streams::SendableDataBlockStream;
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::datasources::*;
+    use crate::sessions::FuseQueryContextRef;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]);
+
+    // Local table.
+    let datasource = DataSource::try_create()?;
+    datasource
+        .create_table(CreateTablePlan {
+            if_not_exists: false,
+            db: "default".to_string(),
+            table: "meta_t".to_string(),
+            schema: schema.clone(),
+            engine: TableEngineType::Null,
+            options: TableOptions::default(),
+        })
+        .await?;
+    let table = datasource.get_table("default", "meta_t")?;
+    let meta = datasource.get_table_meta("default", "meta_t")?;
+    assert_eq!(TableMeta::try_create(table.as_ref())?, meta);
+    assert_eq!(
+        TableMeta {
+            name: "meta_t".to_string(),
+            engine: "Null".to_string(),
+            schema: schema.clone(),
+            is_local: true,
+        },
+        meta
+    );
+
+    // The meta is resolved without reading the table.
+    struct UnreadableTable {
+        schema: DataSchemaRef,
+    }
+
+    #[async_trait::async_trait]
+    impl ITable for UnreadableTable {
+        fn name(&self) -> &str {
+            "unreadable"
+        }
+
+        fn engine(&self) -> &str {
+            "Unreadable"
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn schema(&self) -> Result<DataSchemaRef> {
+            Ok(self.schema.clone())
+        }
+
+        fn is_local(&self) -> bool {
+            true
+        }
+
+        fn read_plan(
+            &self,
+            _ctx: FuseQueryContextRef,
+            _scan: &ScanPlan,
+            _partitions: usize,
+        ) -> Result<ReadDataSourcePlan> {
+            panic!("the table must not be read")
+        }
+
+        async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+            panic!("the table must not be read")
+        }
+    }
+
+    let mut factory = SystemFactory::create();
+    factory.register(Arc::new(UnreadableTable {
+        schema: schema.clone(),
+    }));
+    let datasource = DataSource::try_create_with_system_factory(&Config::default(), factory)?;
+    let meta = datasource.get_table_meta("system", "unreadable")?;
+    assert_eq!("Unreadable", meta.engine);
+    assert_eq!(schema, meta.schema);
+
+    // Missing table.
+    assert!(datasource.get_table_meta("system", "missing").is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_replace_database() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    let create_plan = |db: &str, engine: DatabaseEngineType| CreateDatabasePlan {
+        if_not_exists: false,
+        db: db.to_string(),
+        engine,
+        options: Default::default(),
+    };
+    let create_table = |db: &str| CreateTablePlan {
+        if_not_exists: false,
+        db: db.to_string(),
+        table: "t1".to_string(),
+        schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+        engine: TableEngineType::Null,
+        options: TableOptions::default(),
+    };
+
+    // Replace a missing database creates it.
+    datasource
+        .replace_database(create_plan("replace_db", DatabaseEngineType::Local))
+        .await?;
+    datasource.get_database("replace_db")?;
+
+    // Replace an existing database drops its tables.
+    datasource.create_table(create_table("replace_db")).await?;
+    assert_eq!(1, datasource.get_tables("replace_db")?.len());
+    datasource
+        .replace_database(create_plan("replace_db", DatabaseEngineType::Local))
+        .await?;
+    assert!(datasource.get_tables("replace_db")?.is_empty());
+
+    // The store is unreachable, the failed creation keeps the original database.
+    datasource.create_table(create_table("replace_db")).await?;
+    let result = datasource
+        .replace_database(create_plan("replace_db", DatabaseEngineType::Remote))
+        .await;
+    assert!(result.is_err());
+    let database = datasource.get_database("replace_db")?;
+    assert_eq!("Local", database.engine());
+    database.get_table("t1")?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_replace_remote_database() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+    use tonic::Code;
+
+    use crate::configs::Config;
+    use crate::datasources::remote::store_client_mock::MockStore;
+    use crate::datasources::remote::RemoteFactory;
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    let conf = Config::default();
+    let store = MockStore::create();
+    let mut remote_factory = RemoteFactory::new(&conf);
+    remote_factory.set_store_client_provider(Arc::new(store.clone()));
+    let datasource = DataSource::try_create_with_remote_factory(&conf, remote_factory)?;
+
+    let create_plan = |engine: DatabaseEngineType| CreateDatabasePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        engine,
+        options: Default::default(),
+    };
+    let create_table = CreateTablePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        table: "t1".to_string(),
+        schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+        engine: TableEngineType::Null,
+        options: TableOptions::default(),
+    };
+
+    // The store fails the creation, the old local database keeps its tables.
+    datasource
+        .create_database(create_plan(DatabaseEngineType::Local))
+        .await?;
+    datasource.create_table(create_table.clone()).await?;
+    store.fail_next("create_database", Code::InvalidArgument);
+    let result = datasource
+        .replace_database(create_plan(DatabaseEngineType::Remote))
+        .await;
+    assert!(result.is_err());
+    let database = datasource.get_database("db1")?;
+    assert_eq!("Local", database.engine());
+    database.get_table("t1")?;
+    assert!(!store.has_database("db1"));
+
+    // Replaced by a remote database once the store succeeds.
+    datasource
+        .replace_database(create_plan(DatabaseEngineType::Remote))
+        .await?;
+    assert!(store.has_database("db1"));
+    datasource.create_table(create_table.clone()).await?;
+    assert_eq!(vec!["t1"], store.tables("db1"));
+
+    // A remote database can't be replaced by a remote one, nothing is dropped in the store.
+    let result = datasource
+        .replace_database(create_plan(DatabaseEngineType::Remote))
+        .await;
+    assert_eq!(
+        "Code: 2, displayText = Cannot replace remote database 'db1' with a remote one, drop it first.",
+        result.err().unwrap().to_string()
+    );
+    assert_eq!(0, store.calls("drop_database"));
+    assert_eq!(vec!["t1"], store.tables("db1"));
+
+    // The store fails dropping the old remote database, it's kept with its tables.
+    store.fail_next("drop_database", Code::InvalidArgument);
+    let result = datasource
+        .replace_database(create_plan(DatabaseEngineType::Local))
+        .await;
+    assert!(result.is_err());
+    assert_eq!("Remote", datasource.get_database("db1")?.engine());
+    datasource.get_table("db1", "t1")?;
+    assert_eq!(vec!["t1"], store.tables("db1"));
+
+    // Replaced by a local database, the remote one is dropped from the store.
+    datasource
+        .replace_database(create_plan(DatabaseEngineType::Local))
+        .await?;
+    assert_eq!("Local", datasource.get_database("db1")?.engine());
+    assert!(!store.has_database("db1"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_datasource_remote_ddl_retry() -> anyhow::Result<()> {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+    use tonic::Code;
+
+    use crate::configs::Config;
+    use crate::datasources::remote::store_client_mock::MockStore;
+    use crate::datasources::remote::RemoteFactory;
+    use crate::datasources::remote::RetryPolicy;
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    let conf = Config::default();
+    let store = MockStore::create();
+    let mut remote_factory = RemoteFactory::new(&conf);
+    remote_factory.set_store_client_provider(Arc::new(store.clone()));
+    remote_factory.set_retry_policy(RetryPolicy::create(
+        3,
+        Duration::from_millis(1),
+        Duration::from_millis(4),
+    ));
+    let datasource = DataSource::try_create_with_remote_factory(&conf, remote_factory)?;
+
+    let create_plan = |db: &str| CreateDatabasePlan {
+        if_not_exists: false,
+        db: db.to_string(),
+        engine: DatabaseEngineType::Remote,
+        options: Default::default(),
+    };
+    let drop_plan = |db: &str| DropDatabasePlan {
+        if_exists: false,
+        db: db.to_string(),
+    };
+
+    // The store is unavailable once, the retry succeeds.
+    store.fail_next("create_database", Code::Unavailable);
+    datasource.create_database(create_plan("db1")).await?;
+    assert_eq!(2, store.calls("create_database"));
+    assert!(store.has_database("db1"));
+    datasource.get_database("db1")?;
+
+    store.fail_next("drop_database", Code::Unavailable);
+    datasource.drop_database(drop_plan("db1")).await?;
+    assert_eq!(2, store.calls("drop_database"));
+    assert!(!store.has_database("db1"));
+    assert!(datasource.get_database("db1").is_err());
+
+    // The timed out creation may be applied by the store, it's not retried.
+    store.fail_next("create_database", Code::DeadlineExceeded);
+    let result = datasource.create_database(create_plan("db2")).await;
+    assert!(result.is_err());
+    assert_eq!(3, store.calls("create_database"));
+    assert!(datasource.get_database("db2").is_err());
+
+    // The logical error fails immediately.
+    datasource.create_database(create_plan("db3")).await?;
+    datasource.drop_database(drop_plan("db3")).await?;
+    store.fail_next("create_database", Code::AlreadyExists);
+    let result = datasource.create_database(create_plan("db3")).await;
+    assert!(result.is_err());
+    assert_eq!(5, store.calls("create_database"));
+
+    Ok(())
+}
+
+#[test]
+fn test_datasource_list_table_functions() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    let infos = datasource.list_table_functions()?;
+
+    let names: Vec<&str> = infos.iter().map(|info| info.name.as_str()).collect();
+    assert_eq!(
+        vec!["generate_series", "numbers", "numbers_local", "numbers_mt"],
+        names
+    );
+
+    let numbers = infos.iter().find(|info| info.name == "numbers").unwrap();
+    assert_eq!(
+        DataSchemaRefExt::create(vec![DataField::new("number", DataType::UInt64, false)]),
+        numbers.schema
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_datasource_duplicate_table_functions() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_exception::ErrorCodes;
+    use common_exception::Result;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::datasources::system::NumbersTable;
+    use crate::datasources::*;
+
+    // A database exposes the numbers function, which system has registered.
+    struct FunctionsDatabase;
+
+    #[async_trait::async_trait]
+    impl IDatabase for FunctionsDatabase {
+        fn name(&self) -> &str {
+            "functions_db"
+        }
+
+        fn engine(&self) -> &str {
+            "Local"
+        }
+
+        fn is_local(&self) -> bool {
+            true
+        }
+
+        fn get_table(&self, table_name: &str) -> Result<Arc<dyn ITable>> {
+            Err(ErrorCodes::UnknownTable(format!(
+                "Unknown table: '{}'",
+                table_name
+            )))
+        }
+
+        fn get_tables(&self) -> Result<Vec<Arc<dyn ITable>>> {
+            Ok(vec![])
+        }
+
+        fn get_table_functions(&self) -> Result<Vec<Arc<dyn ITableFunction>>> {
+            Ok(vec![Arc::new(NumbersTable::create("numbers"))])
+        }
+
+        async fn create_table(&self, _plan: CreateTablePlan) -> Result<()> {
+            Err(ErrorCodes::UnImplement("Cannot create table"))
+        }
+
+        async fn drop_table(&self, _plan: DropTablePlan) -> Result<()> {
+            Err(ErrorCodes::UnImplement("Cannot drop table"))
+        }
+    }
+
+    // Error by default.
+    {
+        let mut datasource = DataSource::try_create()?;
+        let result = datasource.insert_databases(vec![Arc::new(FunctionsDatabase)]);
+        assert_eq!(
+            "Code: 44, displayText = Table function 'numbers' of database 'functions_db' is already registered by database 'system'.",
+            result.err().unwrap().to_string()
+        );
+    }
+
+    // Overwritten with a warning if allowed.
+    {
+        let mut conf = Config::default();
+        conf.allow_duplicate_table_functions = true;
+        let mut datasource = DataSource::try_create_with_config(&conf)?;
+        datasource.insert_databases(vec![Arc::new(FunctionsDatabase)])?;
+        datasource.get_database("functions_db")?;
+        datasource.get_table_function("numbers")?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_datasource_database_engines() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::*;
+
+    let datasource = DataSource::try_create()?;
+    let mut engines = vec![];
+    for db_name in datasource.get_databases()? {
+        let database = datasource.get_database(&db_name)?;
+        engines.push((db_name, database.engine().to_string()));
+    }
+    engines.sort();
+
+    let expect: Vec<(String, String)> = vec![
+        ("default", "Local"),
+        ("for_test", "Remote"),
+        ("local", "Local"),
+        ("system", "System"),
+    ]
+    .into_iter()
+    .map(|(db, engine)| (db.to_string(), engine.to_string()))
+    .collect();
+    assert_eq!(expect, engines);
+
+    Ok(())
+}
+
+#[test]
+fn test_datasource_lookup_metrics() -> anyhow::Result<()> {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::sync::Once;
+
+    use metrics::GaugeValue;
+    use metrics::Key;
+    use metrics::Recorder;
+    use metrics::Unit;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::*;
+
+    thread_local! {
+        // The counters incremented by the current thread, each test runs in its own thread.
+        static COUNTERS: RefCell<HashMap<String, u64>> = RefCell::new(HashMap::new());
+    }
+
+    struct ThreadLocalRecorder;
+
+    impl Recorder for ThreadLocalRecorder {
+        fn register_counter(
+            &self,
+            _key: &Key,
+            _unit: Option<Unit>,
+            _description: Option<&'static str>,
+        ) {
+        }
+        fn register_gauge(
+            &self,
+            _key: &Key,
+            _unit: Option<Unit>,
+            _description: Option<&'static str>,
+        ) {
+        }
+        fn register_histogram(
+            &self,
+            _key: &Key,
+            _unit: Option<Unit>,
+            _description: Option<&'static str>,
+        ) {
+        }
+
+        fn increment_counter(&self, key: &Key, value: u64) {
+            let labels: Vec<String> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let name = format!("{}{{{}}}", key.name(), labels.join(","));
+            COUNTERS.with(|counters| *counters.borrow_mut().entry(name).or_default() += value);
+        }
+
+        fn update_gauge(&self, _key: &Key, _value: GaugeValue) {}
+        fn record_histogram(&self, _key: &Key, _value: f64) {}
+    }
+
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| metrics::set_boxed_recorder(Box::new(ThreadLocalRecorder)).unwrap());
+
+    let datasource = DataSource::try_create()?;
+    datasource.get_database("system")?;
+    datasource.get_database("default")?;
+    assert!(datasource.get_database("missing").is_err());
+    datasource.get_table("system", "numbers")?;
+    assert!(datasource.get_table("system", "missing").is_err());
+    assert!(datasource.get_table("missing", "numbers").is_err());
+    datasource.get_table_function("numbers")?;
+    assert!(datasource.get_table_function("missing").is_err());
+
+    let counter = |name: &str| COUNTERS.with(|counters| counters.borrow().get(name).cloned());
+    let cases = vec![
+        ("datasource.lookup_hits{method=get_database}", Some(2)),
+        ("datasource.lookup_misses{method=get_database}", Some(1)),
+        ("datasource.lookup_hits{method=get_table}", Some(1)),
+        ("datasource.lookup_misses{method=get_table}", Some(2)),
+        ("datasource.lookup_hits{method=get_table_function}", Some(1)),
+        (
+            "datasource.lookup_misses{method=get_table_function}",
+            Some(1),
+        ),
+        ("datasource.lookup_hits{method=missing_method}", None),
+    ];
+    for (name, expect) in cases {
+        assert_eq!(expect, counter(name), "{}", name);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_datasource_rename_table() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_exception::Result;
+    use common_infallible::Mutex;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::datasources::remote::store_client_mock::MockStore;
+    use crate::datasources::remote::RemoteFactory;
+    use crate::datasources::IDataSource;
+    use crate::datasources::*;
+
+    struct RenameListener {
+        renames: Mutex<Vec<(String, String, String)>>,
+    }
+
+    impl IDdlListener for RenameListener {
+        fn name(&self) -> &str {
+            "rename"
+        }
+
+        fn on_rename_table(&self, db: &str, table: &str, new_table: &str) -> Result<()> {
+            let rename = (db.to_string(), table.to_string(), new_table.to_string());
+            self.renames.lock().push(rename);
+            Ok(())
+        }
+    }
+
+    let conf = Config::default();
+    let store = MockStore::create();
+    let mut remote_factory = RemoteFactory::new(&conf);
+    remote_factory.set_store_client_provider(Arc::new(store.clone()));
+    let datasource = DataSource::try_create_with_remote_factory(&conf, remote_factory)?;
+    let listener = Arc::new(RenameListener {
+        renames: Mutex::new(vec![]),
+    });
+    datasource.register_ddl_listener(listener.clone());
+
+    datasource
+        .create_database(CreateDatabasePlan {
+            if_not_exists: false,
+            db: "db1".into(),
+            engine: DatabaseEngineType::Remote,
+            options: Default::default(),
+        })
+        .await?;
+    datasource
+        .create_table(CreateTablePlan {
+            if_not_exists: false,
+            db: "db1".into(),
+            table: "t1".into(),
+            schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]),
+            engine: TableEngineType::JsonEachRaw,
+            options: Default::default(),
+        })
+        .await?;
+
+    // Renamed in the store and in the database.
+    datasource.rename_table("db1", "t1", "t2").await?;
+    assert_eq!(vec!["t2".to_string()], store.tables("db1"));
+    assert_eq!("t2", datasource.get_table("db1", "t2")?.name());
+
+    // The local databases can't rename.
+    let result = datasource.rename_table("default", "t1", "t2").await;
+    let actual = format!("{}", result.err().unwrap());
+    let expect = "Code: 2, displayText = Cannot rename table for Local database.";
+    assert_eq!(expect, actual);
+
+    // Only the successful rename is notified.
+    let expect = vec![("db1".to_string(), "t1".to_string(), "t2".to_string())];
+    assert_eq!(expect, *listener.renames.lock());
+
     Ok(())
 }
@@ -4,6 +4,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCodes;
 use common_exception::Result;
 use common_planners::InsertIntoPlan;
 use common_streams::DataBlockStream;
@@ -34,6 +35,15 @@ impl IInterpreter for InsertIntoInterpreter {
         let datasource = self.ctx.get_datasource();
         let database = datasource.get_database(self.plan.db_name.as_str())?;
         let table = database.get_table(self.plan.tbl_name.as_str())?;
+        if !table.is_writable() {
+            return Err(ErrorCodes::UnImplement(format!(
+                "Table '{}.{}' with engine '{}' is not writable",
+                self.plan.db_name,
+                self.plan.tbl_name,
+                table.engine()
+            )));
+        }
+
         table
             .append_data(self.ctx.clone(), self.plan.clone())
             .await?;
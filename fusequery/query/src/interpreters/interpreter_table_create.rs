@@ -32,8 +32,7 @@ impl IInterpreter for CreateTableInterpreter {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let datasource = self.ctx.get_datasource();
-        let database = datasource.get_database(self.plan.db.as_str())?;
-        database.create_table(self.plan.clone()).await?;
+        datasource.create_table(self.plan.clone()).await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema.clone(),
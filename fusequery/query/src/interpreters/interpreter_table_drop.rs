@@ -32,8 +32,7 @@ impl IInterpreter for DropTableInterpreter {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let datasource = self.ctx.get_datasource();
-        let database = datasource.get_database(self.plan.db.as_str())?;
-        database.drop_table(self.plan.clone()).await?;
+        datasource.drop_table(self.plan.clone()).await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -11,6 +11,7 @@ pub mod configs;
 pub mod datasources;
 pub mod functions;
 pub mod interpreters;
+pub mod logging;
 pub mod metrics;
 pub mod optimizers;
 pub mod pipelines;
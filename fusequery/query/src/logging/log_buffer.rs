@@ -0,0 +1,75 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_infallible::RwLock;
+use lazy_static::lazy_static;
+
+pub const DEFAULT_LOG_BUFFER_CAPACITY: usize = 1000;
+
+lazy_static! {
+    static ref GLOBAL_LOG_BUFFER: LogBufferRef = LogBuffer::create(DEFAULT_LOG_BUFFER_CAPACITY);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogRecord {
+    pub timestamp: String,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+}
+
+/// A bounded buffer of the recent log records, the oldest records are evicted when it's full.
+pub struct LogBuffer {
+    capacity: RwLock<usize>,
+    records: RwLock<VecDeque<LogRecord>>,
+}
+
+pub type LogBufferRef = Arc<LogBuffer>;
+
+impl LogBuffer {
+    pub fn create(capacity: usize) -> LogBufferRef {
+        Arc::new(LogBuffer {
+            capacity: RwLock::new(capacity),
+            records: RwLock::new(VecDeque::with_capacity(capacity)),
+        })
+    }
+
+    /// The buffer which the global logger writes into.
+    pub fn global() -> LogBufferRef {
+        GLOBAL_LOG_BUFFER.clone()
+    }
+
+    pub fn capacity(&self) -> usize {
+        *self.capacity.read()
+    }
+
+    pub fn set_capacity(&self, capacity: usize) {
+        *self.capacity.write() = capacity;
+        let mut records = self.records.write();
+        while records.len() > capacity {
+            records.pop_front();
+        }
+    }
+
+    pub fn push(&self, record: LogRecord) {
+        let capacity = self.capacity();
+        if capacity == 0 {
+            return;
+        }
+
+        let mut records = self.records.write();
+        while records.len() >= capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Snapshot of the records, newest first.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.read().iter().rev().cloned().collect()
+    }
+}
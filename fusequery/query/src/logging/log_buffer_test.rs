@@ -0,0 +1,41 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_log_buffer() -> common_exception::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::logging::*;
+
+    let record = |message: &str| LogRecord {
+        timestamp: "".to_string(),
+        level: "INFO".to_string(),
+        target: "test".to_string(),
+        message: message.to_string(),
+    };
+
+    let buffer = LogBuffer::create(2);
+    buffer.push(record("a"));
+    buffer.push(record("b"));
+    buffer.push(record("c"));
+
+    // Bounded and newest first.
+    let messages = buffer
+        .records()
+        .iter()
+        .map(|r| r.message.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["c", "b"], messages);
+
+    // Shrink the capacity.
+    buffer.set_capacity(1);
+    assert_eq!(vec![record("c")], buffer.records());
+
+    // Disabled.
+    buffer.set_capacity(0);
+    buffer.push(record("d"));
+    assert_eq!(0, buffer.records().len());
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use chrono::Utc;
+use common_exception::ErrorCodes;
+use common_exception::Result;
+use log::Log;
+use log::Metadata;
+use log::Record;
+
+use crate::logging::LogBuffer;
+use crate::logging::LogBufferRef;
+use crate::logging::LogRecord;
+
+/// Logger which writes the records to env_logger and keeps them in the log buffer.
+pub struct BufferedLogger {
+    inner: env_logger::Logger,
+    buffer: LogBufferRef,
+}
+
+impl BufferedLogger {
+    pub fn create(inner: env_logger::Logger, buffer: LogBufferRef) -> Self {
+        BufferedLogger { inner, buffer }
+    }
+}
+
+impl Log for BufferedLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.inner.matches(record) {
+            return;
+        }
+
+        self.inner.log(record);
+        self.buffer.push(LogRecord {
+            timestamp: Utc::now().format("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+            level: record.level().to_string(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        });
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Install the global logger, the recent records are kept in the global log buffer.
+pub fn init_logger(level: &str, buffer_capacity: usize) -> Result<()> {
+    let inner =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(level)).build();
+    let max_level = inner.filter();
+
+    let buffer = LogBuffer::global();
+    buffer.set_capacity(buffer_capacity);
+
+    log::set_boxed_logger(Box::new(BufferedLogger::create(inner, buffer)))
+        .map_err(|e| ErrorCodes::LogicalError(format!("Cannot init the logger: {}", e)))?;
+    log::set_max_level(max_level);
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod log_buffer_test;
+
+mod log_buffer;
+mod logger;
+
+pub use log_buffer::LogBuffer;
+pub use log_buffer::LogBufferRef;
+pub use log_buffer::LogRecord;
+pub use logger::init_logger;
+pub use logger::BufferedLogger;
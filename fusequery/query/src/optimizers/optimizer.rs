@@ -6,6 +6,7 @@ use common_exception::Result;
 use common_planners::PlanNode;
 
 use crate::optimizers::optimizer_scatters::ScattersOptimizer;
+use crate::optimizers::LimitPushDownOptimizer;
 use crate::optimizers::ProjectionPushDownOptimizer;
 use crate::sessions::FuseQueryContextRef;
 
@@ -22,6 +23,7 @@ impl Optimizer {
     pub fn create(ctx: FuseQueryContextRef) -> Self {
         let optimizers: Vec<Box<dyn IOptimizer>> = vec![
             Box::new(ProjectionPushDownOptimizer::create(ctx.clone())),
+            Box::new(LimitPushDownOptimizer::create(ctx.clone())),
             Box::new(ScattersOptimizer::create(ctx)),
         ];
         Optimizer { optimizers }
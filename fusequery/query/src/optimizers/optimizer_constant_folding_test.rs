@@ -22,6 +22,7 @@ mod tests {
         let statistics = Statistics {
             read_rows: total as usize,
             read_bytes: ((total) * size_of::<u64>() as u64) as usize,
+            null_counts: None,
         };
         ctx.try_set_statistics(&statistics)?;
         let source_plan = PlanNode::ReadSource(ReadDataSourcePlan {
@@ -0,0 +1,116 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::AggregatorFinalPlan;
+use common_planners::AggregatorPartialPlan;
+use common_planners::FilterPlan;
+use common_planners::HavingPlan;
+use common_planners::LimitPlan;
+use common_planners::PlanNode;
+use common_planners::PlanRewriter;
+use common_planners::ReadDataSourcePlan;
+use common_planners::SortPlan;
+
+use crate::optimizers::IOptimizer;
+use crate::sessions::FuseQueryContextRef;
+
+/// Push the limit down to the scan of the source, so the source stops reading early.
+/// It's pushed only through the plans keeping the rows one to one, such as projection
+/// and expression, the filter, having, sort and aggregators below the limit stop it.
+pub struct LimitPushDownOptimizer {}
+
+struct LimitPushDownImpl {
+    // The limit of the plans being rewritten, None if there is none or it's stopped.
+    limit: Option<usize>,
+}
+
+impl LimitPushDownImpl {
+    // Rewrite the input with the limit, restored after the input is rewritten.
+    fn rewrite_input_with_limit(
+        &mut self,
+        input: &PlanNode,
+        limit: Option<usize>,
+    ) -> Result<Arc<PlanNode>> {
+        let outer = std::mem::replace(&mut self.limit, limit);
+        let input = self.rewrite_plan_node(input);
+        self.limit = outer;
+        Ok(Arc::new(input?))
+    }
+}
+
+impl<'plan> PlanRewriter<'plan> for LimitPushDownImpl {
+    fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.input = self.rewrite_input_with_limit(&plan.input, None)?;
+        Ok(PlanNode::AggregatorPartial(new_plan))
+    }
+
+    fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.input = self.rewrite_input_with_limit(&plan.input, None)?;
+        Ok(PlanNode::AggregatorFinal(new_plan))
+    }
+
+    fn rewrite_filter(&mut self, plan: &FilterPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.input = self.rewrite_input_with_limit(&plan.input, None)?;
+        Ok(PlanNode::Filter(new_plan))
+    }
+
+    fn rewrite_having(&mut self, plan: &HavingPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.input = self.rewrite_input_with_limit(&plan.input, None)?;
+        Ok(PlanNode::Having(new_plan))
+    }
+
+    fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.input = self.rewrite_input_with_limit(&plan.input, None)?;
+        Ok(PlanNode::Sort(new_plan))
+    }
+
+    fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
+        // The nested limits take the smaller one.
+        let limit = match self.limit {
+            Some(outer) => std::cmp::min(outer, plan.n),
+            None => plan.n,
+        };
+        let mut new_plan = plan.clone();
+        new_plan.input = self.rewrite_input_with_limit(&plan.input, Some(limit))?;
+        Ok(PlanNode::Limit(new_plan))
+    }
+
+    fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        if let Some(limit) = self.limit {
+            let mut scan_plan = plan.scan_plan.as_ref().clone();
+            scan_plan.limit = Some(match scan_plan.limit {
+                Some(scan_limit) => std::cmp::min(scan_limit, limit),
+                None => limit,
+            });
+            new_plan.scan_plan = Arc::new(scan_plan);
+        }
+        Ok(PlanNode::ReadSource(new_plan))
+    }
+}
+
+impl IOptimizer for LimitPushDownOptimizer {
+    fn name(&self) -> &str {
+        "LimitPushDown"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut rewriter = LimitPushDownImpl { limit: None };
+        rewriter.rewrite_plan_node(plan)
+    }
+}
+
+impl LimitPushDownOptimizer {
+    pub fn create(_ctx: FuseQueryContextRef) -> LimitPushDownOptimizer {
+        LimitPushDownOptimizer {}
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::optimizers::*;
+use crate::sql::*;
+
+// The limit of the scan of the only source.
+fn scan_limit(plan: &PlanNode) -> Option<usize> {
+    match plan {
+        PlanNode::ReadSource(plan) => plan.scan_plan.limit,
+        _ => scan_limit(plan.input(0).as_ref()),
+    }
+}
+
+#[test]
+fn test_limit_push_down_optimizer() -> anyhow::Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    let cases = vec![
+        // Through the projection and the expression.
+        ("select number from numbers_mt(100) limit 3", Some(3)),
+        (
+            "select number + 1 as c from numbers_mt(100) limit 3",
+            Some(3),
+        ),
+        // Stopped by the filter, sort and aggregator below the limit.
+        (
+            "select number from numbers_mt(100) where number > 1 limit 3",
+            None,
+        ),
+        (
+            "select number from numbers_mt(100) order by number desc limit 3",
+            None,
+        ),
+        ("select count(*) from numbers_mt(100) limit 3", None),
+        (
+            "select number from numbers_mt(100) group by number limit 3",
+            None,
+        ),
+        // No limit.
+        ("select number from numbers_mt(100)", None),
+    ];
+    for (query, expect) in cases {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql(query)?;
+        let mut limit_push_down = LimitPushDownOptimizer::create(ctx.clone());
+        let optimized = limit_push_down.optimize(&plan)?;
+        assert_eq!(expect, scan_limit(&optimized), "{}", query);
+
+        // The plan is the same except for the scan.
+        assert_eq!(
+            format!("{:?}", plan),
+            format!("{:?}", optimized),
+            "{}",
+            query
+        );
+    }
+
+    Ok(())
+}
@@ -5,7 +5,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use common_arrow::arrow::error::Result as ArrowResult;
+use common_datavalues::index_of_ignore_case;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
@@ -73,18 +73,26 @@ impl<'plan> PlanRewriter<'plan> for ProjectionPushDownImpl {
     }
 
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
-        self.get_projected_schema(plan.schema.as_ref())
-            .map(|projected_schema| {
-                PlanNode::ReadSource(ReadDataSourcePlan {
-                    db: plan.db.to_string(),
-                    table: plan.table.to_string(),
-                    schema: projected_schema,
-                    partitions: plan.partitions.clone(),
-                    statistics: plan.statistics.clone(),
-                    description: plan.description.to_string(),
-                    scan_plan: plan.scan_plan.clone(),
-                })
-            })
+        let projection = self.get_projection(plan.schema.as_ref());
+        let projected_schema = Self::get_projected_schema(plan.schema.as_ref(), &projection);
+
+        // Record the projection on the scan, relative to the table schema.
+        let mut scan_plan = plan.scan_plan.as_ref().clone();
+        scan_plan.projection = Some(match &plan.scan_plan.projection {
+            None => projection,
+            Some(scan_projection) => projection.iter().map(|i| scan_projection[*i]).collect(),
+        });
+        scan_plan.projected_schema = projected_schema.clone();
+
+        Ok(PlanNode::ReadSource(ReadDataSourcePlan {
+            db: plan.db.to_string(),
+            table: plan.table.to_string(),
+            schema: projected_schema,
+            partitions: plan.partitions.clone(),
+            statistics: plan.statistics.clone(),
+            description: plan.description.to_string(),
+            scan_plan: Arc::new(scan_plan),
+        }))
     }
 
     fn rewrite_empty(&mut self, plan: &EmptyPlan) -> Result<PlanNode> {
@@ -123,13 +131,13 @@ impl ProjectionPushDownImpl {
         Ok(())
     }
 
-    fn get_projected_schema(&self, schema: &DataSchema) -> Result<DataSchemaRef> {
+    fn get_projection(&self, schema: &DataSchema) -> Vec<usize> {
         // Discard non-existing columns, e.g. when the column derives from aggregation
         let mut projection: Vec<usize> = self
             .required_columns
             .iter()
-            .map(|name| schema.index_of(name))
-            .filter_map(ArrowResult::ok)
+            .map(|name| index_of_ignore_case(schema, name))
+            .filter_map(|index| index.ok())
             .collect();
         if projection.is_empty() {
             if self.has_projection {
@@ -146,15 +154,20 @@ impl ProjectionPushDownImpl {
                     .collect::<Vec<usize>>();
             }
         }
-        // sort the projection to get deterministic behavior
+        // sort the projection to get deterministic behavior,
+        // the case variants of one column have the same index.
         projection.sort_unstable();
+        projection.dedup();
+        projection
+    }
 
+    fn get_projected_schema(schema: &DataSchema, projection: &[usize]) -> DataSchemaRef {
         // create the projected schema
         let mut projected_fields: Vec<DataField> = Vec::with_capacity(projection.len());
-        for i in &projection {
+        for i in projection {
             projected_fields.push(schema.fields()[*i].clone());
         }
-        Ok(DataSchemaRefExt::create(projected_fields))
+        DataSchemaRefExt::create(projected_fields)
     }
 }
 
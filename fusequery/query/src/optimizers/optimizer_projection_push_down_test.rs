@@ -77,6 +77,7 @@ fn test_projection_push_down_optimizer_2() -> anyhow::Result<()> {
     let statistics = Statistics {
         read_rows: total as usize,
         read_bytes: ((total) * size_of::<u64>() as u64) as usize,
+        null_counts: None,
     };
     ctx.try_set_statistics(&statistics)?;
     let source_plan = PlanNode::ReadSource(ReadDataSourcePlan {
@@ -118,6 +119,15 @@ fn test_projection_push_down_optimizer_2() -> anyhow::Result<()> {
     let actual = format!("{:?}", optimized);
     assert_eq!(expect, actual);
 
+    // The projection is recorded on the scan plan as well.
+    match optimized.input(0).input(0).as_ref() {
+        PlanNode::ReadSource(plan) => {
+            assert_eq!(Some(vec![0, 1]), plan.scan_plan.projection);
+            assert_eq!(plan.schema, plan.scan_plan.projected_schema);
+        }
+        other => panic!("expect ReadSource, got {:?}", other),
+    }
+
     Ok(())
 }
 
@@ -129,6 +139,7 @@ fn test_projection_push_down_optimizer_3() -> anyhow::Result<()> {
     let statistics = Statistics {
         read_rows: total as usize,
         read_bytes: ((total) * size_of::<u64>() as u64) as usize,
+        null_counts: None,
     };
     ctx.try_set_statistics(&statistics)?;
     let source_plan = PlanNode::ReadSource(ReadDataSourcePlan {
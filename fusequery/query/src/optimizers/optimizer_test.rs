@@ -9,6 +9,8 @@ pub fn generate_partitions(workers: u64, total: u64) -> Partitions {
         partitions.push(Partition {
             name: format!("{}-{}-{}", total, 0, total,),
             version: 0,
+            rows: total,
+            bytes: 0,
         })
     } else {
         for part in 0..workers {
@@ -20,6 +22,8 @@ pub fn generate_partitions(workers: u64, total: u64) -> Partitions {
             partitions.push(Partition {
                 name: format!("{}-{}-{}", total, part_begin, part_end,),
                 version: 0,
+                rows: part_end - part_begin,
+                bytes: 0,
             })
         }
     }
@@ -65,9 +65,7 @@ impl PipelineBuilder {
                 PlanNode::Select(_) => Ok(true),
                 PlanNode::Stage(plan) => self.visit_stage_plan(&mut pipeline, &plan),
                 PlanNode::Remote(plan) => self.visit_remote_plan(&mut pipeline, &plan),
-                PlanNode::Expression(plan) => {
-                    PipelineBuilder::visit_expression_plan(&mut pipeline, plan)
-                }
+                PlanNode::Expression(plan) => self.visit_expression_plan(&mut pipeline, plan),
                 PlanNode::Projection(plan) => {
                     PipelineBuilder::visit_projection_plan(&mut pipeline, plan)
                 }
@@ -114,12 +112,18 @@ impl PipelineBuilder {
         Ok(true)
     }
 
-    fn visit_expression_plan(pipeline: &mut Pipeline, plan: &ExpressionPlan) -> Result<bool> {
+    fn visit_expression_plan(
+        &self,
+        pipeline: &mut Pipeline,
+        plan: &ExpressionPlan,
+    ) -> Result<bool> {
+        let max_block_size = self.ctx.get_max_block_size()? as usize;
         pipeline.add_simple_transform(|| {
             Ok(Box::new(ExpressionTransform::try_create(
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.exprs.clone(),
+                max_block_size,
             )?))
         })?;
         Ok(true)
@@ -273,6 +277,7 @@ impl PipelineBuilder {
                 self.ctx.clone(),
                 plan.db.as_str(),
                 plan.table.as_str(),
+                plan.scan_plan.limit,
             )?;
             pipeline.add_source(Arc::new(source))?;
         }
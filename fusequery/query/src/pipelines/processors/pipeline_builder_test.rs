@@ -147,3 +147,65 @@ async fn test_local_pipeline_builds() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_pipeline_column_ignore_case() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    use crate::optimizers::Optimizer;
+    use crate::pipelines::processors::*;
+    use crate::sql::*;
+
+    // The column is number, referenced in the other cases by the projection, filter and sort.
+    let ctx = crate::tests::try_create_context()?;
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(
+        "select Number as c1, (NUMBER + 1) as c2 from numbers_mt(5) where Number > 1 order by c1 desc",
+    )?;
+    let plan = Optimizer::create(ctx.clone()).optimize(&plan)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx.clone(), plan).build()?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----+----+",
+        "| c1 | c2 |",
+        "+----+----+",
+        "| 4  | 5  |",
+        "| 3  | 4  |",
+        "| 2  | 3  |",
+        "+----+----+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_pipeline_limit_push_down() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    use crate::optimizers::Optimizer;
+    use crate::pipelines::processors::*;
+    use crate::sql::*;
+
+    // The sources stop reading at the limit, instead of reading all the rows.
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_threads(2)?;
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("select number from numbers_mt(10000000) limit 3")?;
+    let plan = Optimizer::create(ctx.clone()).optimize(&plan)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx.clone(), plan).build()?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(3, rows);
+
+    // At most one block of each source is read.
+    let read_rows = ctx.get_progress_value().read_rows;
+    let max_block_size = ctx.get_max_block_size()? as usize;
+    assert!(read_rows <= 2 * max_block_size, "read_rows: {}", read_rows);
+    assert!(read_rows < 10000000, "read_rows: {}", read_rows);
+
+    Ok(())
+}
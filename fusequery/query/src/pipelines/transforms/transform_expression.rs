@@ -10,7 +10,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
-use tokio_stream::StreamExt;
+use futures::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::IProcessor;
@@ -29,10 +29,14 @@ use crate::pipelines::transforms::ExpressionExecutor;
 ///
 /// So the final block:
 /// |number|c1|c2|
+///
+/// The input blocks larger than `max_block_size` rows are split before the evaluation,
+/// so the memory of evaluating one block is bounded.
 pub struct ExpressionTransform {
     // The final schema(Build by plan_builder.expression).
     input: Arc<dyn IProcessor>,
     executor: Arc<ExpressionExecutor>,
+    max_block_size: usize,
 }
 
 impl ExpressionTransform {
@@ -40,6 +44,7 @@ impl ExpressionTransform {
         input_schema: DataSchemaRef,
         output_schema: DataSchemaRef,
         exprs: Vec<Expression>,
+        max_block_size: usize,
     ) -> Result<Self> {
         let executor = ExpressionExecutor::try_create(input_schema, output_schema, exprs, false)?;
         executor.validate()?;
@@ -47,8 +52,22 @@ impl ExpressionTransform {
         Ok(ExpressionTransform {
             input: Arc::new(EmptyProcessor::create()),
             executor: Arc::new(executor),
+            max_block_size,
         })
     }
+
+    // Split the block into the blocks of at most max_block_size rows, 0 means unbounded.
+    fn split_block(block: DataBlock, max_block_size: usize) -> Vec<DataBlock> {
+        let rows = block.num_rows();
+        if max_block_size == 0 || rows <= max_block_size {
+            return vec![block];
+        }
+
+        (0..rows)
+            .step_by(max_block_size)
+            .map(|offset| block.slice(offset, max_block_size))
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -72,16 +91,22 @@ impl IProcessor for ExpressionTransform {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let executor = self.executor.clone();
+        let max_block_size = self.max_block_size;
         let input_stream = self.input.execute().await?;
 
-        let executor_fn =
-            |executor: Arc<ExpressionExecutor>, block: Result<DataBlock>| -> Result<DataBlock> {
-                let block = block?;
-                executor.execute(&block)
+        // The slices are evaluated lazily, one output block at a time.
+        let stream = input_stream.flat_map(move |block| {
+            let executor = executor.clone();
+            let blocks = match block {
+                Ok(block) => Self::split_block(block, max_block_size)
+                    .into_iter()
+                    .map(Ok)
+                    .collect(),
+                Err(e) => vec![Err(e)],
             };
-
-        let stream = input_stream
-            .filter_map(move |v| executor_fn(executor.clone(), v).map(Some).transpose());
+            futures::stream::iter(blocks)
+                .map(move |block| block.and_then(|block| executor.execute(&block)))
+        });
 
         Ok(Box::pin(stream))
     }
@@ -32,6 +32,7 @@ async fn test_transform_expression() -> anyhow::Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.exprs.clone(),
+                ctx.get_max_block_size()? as usize,
             )?))
         })?;
     }
@@ -87,3 +88,49 @@ async fn test_transform_expression_error() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_expression_max_block_size() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::pipelines::transforms::*;
+
+    // The source reads one block of 1M rows.
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_threads(1)?;
+    ctx.set_max_block_size(1_000_000)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(1_000_000)?;
+    pipeline.add_source(Arc::new(source))?;
+
+    if let PlanNode::Expression(plan) = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .expression(&[add(col("number"), lit(1u8))], "")?
+        .build()?
+    {
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(ExpressionTransform::try_create(
+                plan.input.schema(),
+                plan.schema.clone(),
+                plan.exprs.clone(),
+                100_000,
+            )?))
+        })?;
+    }
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(10, result.len());
+    for block in &result {
+        assert_eq!(100_000, block.num_rows());
+        assert_eq!(2, block.num_columns());
+    }
+
+    Ok(())
+}
@@ -29,6 +29,7 @@ async fn test_transform_projection() -> anyhow::Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.expr.clone(),
+                ctx.get_max_block_size()? as usize,
             )?))
         })?;
         pipeline.add_simple_transform(|| {
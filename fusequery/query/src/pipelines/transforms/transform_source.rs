@@ -17,14 +17,21 @@ pub struct SourceTransform {
     ctx: FuseQueryContextRef,
     db: String,
     table: String,
+    limit: Option<usize>,
 }
 
 impl SourceTransform {
-    pub fn try_create(ctx: FuseQueryContextRef, db: &str, table: &str) -> Result<Self> {
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        db: &str,
+        table: &str,
+        limit: Option<usize>,
+    ) -> Result<Self> {
         Ok(SourceTransform {
             ctx,
             db: db.to_string(),
             table: table.to_string(),
+            limit,
         })
     }
 }
@@ -51,6 +58,6 @@ impl IProcessor for SourceTransform {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let table = self.ctx.get_table(self.db.as_str(), self.table.as_str())?;
-        table.read(self.ctx.clone()).await
+        table.read_with_limit(self.ctx.clone(), self.limit).await
     }
 }
@@ -193,11 +193,12 @@ impl ClickHouseHandler {
         loop {
             let session_mgr = self.session_manager.clone();
             // Asynchronously wait for an inbound TcpStream.
-            let (stream, _) = listener.accept().await?;
+            let (stream, addr) = listener.accept().await?;
             let ctx = self
                 .session_manager
                 .try_create_context()?
-                .with_cluster(self.cluster.clone())?;
+                .with_cluster(self.cluster.clone())?
+                .with_client_host(&addr.to_string())?;
             ctx.set_max_threads(self.conf.num_cpus)?;
 
             // Spawn our handler to be run asynchronously.
@@ -141,10 +141,15 @@ impl MySQLHandler {
 
         for stream in listener.incoming() {
             let stream = stream?;
+            let client_host = stream
+                .peer_addr()
+                .map(|addr| addr.to_string())
+                .unwrap_or_default();
             let ctx = self
                 .session_manager
                 .try_create_context()?
-                .with_cluster(self.cluster.clone())?;
+                .with_cluster(self.cluster.clone())?
+                .with_client_host(&client_host)?;
             ctx.set_max_threads(self.conf.num_cpus)?;
 
             let session_mgr = self.session_manager.clone();
@@ -5,7 +5,10 @@
 use std::collections::VecDeque;
 use std::future::Future;
 use std::sync::Arc;
+use std::sync::Weak;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_datavalues::DataValue;
 use common_exception::ErrorCodes;
 use common_exception::Result;
@@ -26,6 +29,8 @@ use crate::datasources::DataSource;
 use crate::datasources::IDataSource;
 use crate::datasources::ITable;
 use crate::datasources::ITableFunction;
+use crate::sessions::SessionInfo;
+use crate::sessions::SessionManager;
 use crate::sessions::Settings;
 
 #[derive(Clone)]
@@ -39,6 +44,9 @@ pub struct FuseQueryContext {
     current_database: Arc<RwLock<String>>,
     progress: Arc<Progress>,
     runtime: Arc<RwLock<Runtime>>,
+    client_host: Arc<RwLock<String>>,
+    started_at: DateTime<Utc>,
+    session_manager: Arc<RwLock<Weak<SessionManager>>>,
 }
 
 pub type FuseQueryContextRef = Arc<FuseQueryContext>;
@@ -57,6 +65,9 @@ impl FuseQueryContext {
             current_database: Arc::new(RwLock::new(String::from("default"))),
             progress: Arc::new(Progress::create()),
             runtime: Arc::new(RwLock::new(Runtime::with_worker_threads(cpus)?)),
+            client_host: Arc::new(RwLock::new(String::new())),
+            started_at: Utc::now(),
+            session_manager: Arc::new(RwLock::new(Weak::new())),
         };
         // Default settings.
         ctx.initial_settings()?;
@@ -76,6 +87,35 @@ impl FuseQueryContext {
         Ok(Arc::new(self.clone()))
     }
 
+    pub fn with_client_host(&self, client_host: &str) -> Result<FuseQueryContextRef> {
+        *self.client_host.write() = client_host.to_string();
+        Ok(Arc::new(self.clone()))
+    }
+
+    // Registered by the session manager which creates this context.
+    pub fn set_session_manager(&self, session_manager: Weak<SessionManager>) {
+        *self.session_manager.write() = session_manager;
+    }
+
+    pub fn get_session_info(&self) -> Result<SessionInfo> {
+        Ok(SessionInfo {
+            id: self.get_id()?,
+            database: self.get_current_database(),
+            client_host: self.client_host.read().clone(),
+            started_at: self.started_at.format("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+        })
+    }
+
+    /// The active sessions of the session manager,
+    /// only the current session if this context is not created by a session manager.
+    pub fn get_sessions_info(&self) -> Result<Vec<SessionInfo>> {
+        let session_manager = self.session_manager.read().upgrade();
+        match session_manager {
+            Some(session_manager) => session_manager.get_sessions_info(),
+            None => Ok(vec![self.get_session_info()?]),
+        }
+    }
+
     /// ctx.reset will reset the necessary variables in the session
     pub fn reset(&self) -> Result<()> {
         self.progress.reset();
@@ -145,6 +185,7 @@ impl FuseQueryContext {
         Ok(Statistics {
             read_rows: statistics.read_rows,
             read_bytes: statistics.read_bytes,
+            null_counts: statistics.null_counts.clone(),
         })
     }
 
@@ -206,7 +247,7 @@ impl FuseQueryContext {
     }
 
     apply_macros! { apply_getter_setter_settings, apply_initial_settings, apply_update_settings,
-        ("max_block_size", u64, 10000, "Maximum block size for reading".to_string()),
+        ("max_block_size", u64, 10000, "Maximum block size for reading and expression evaluation".to_string()),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds".to_string()),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query.".to_string()),
         ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query.".to_string())
@@ -2,6 +2,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+mod sessions_test;
+
 #[macro_use]
 mod macros;
 
@@ -13,6 +16,7 @@ mod settings;
 
 pub use context::FuseQueryContext;
 pub use context::FuseQueryContextRef;
+pub use sessions::SessionInfo;
 pub use sessions::SessionManager;
 pub use sessions::SessionManagerRef;
 pub use settings::Settings;
@@ -4,6 +4,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::Weak;
 
 use common_exception::ErrorCodes;
 use common_exception::Result;
@@ -11,30 +12,66 @@ use common_infallible::RwLock;
 use common_planners::Partitions;
 use metrics::counter;
 
+use crate::configs::Config;
 use crate::sessions::FuseQueryContext;
 use crate::sessions::FuseQueryContextRef;
 
+/// The snapshot of an active session.
+#[derive(Clone, Debug)]
+pub struct SessionInfo {
+    pub id: String,
+    pub database: String,
+    pub client_host: String,
+    pub started_at: String,
+}
+
 pub struct SessionManager {
+    // The current database of the new sessions.
+    default_database: String,
     sessions: RwLock<HashMap<String, FuseQueryContextRef>>,
+    // The contexts only keep a weak reference to the manager, it's not a cycle.
+    weak_self: RwLock<Weak<SessionManager>>,
 }
 
 pub type SessionManagerRef = Arc<SessionManager>;
 
 impl SessionManager {
     pub fn create() -> SessionManagerRef {
-        Arc::new(SessionManager {
+        SessionManager::from_conf(Config::default())
+    }
+
+    pub fn from_conf(conf: Config) -> SessionManagerRef {
+        let manager = Arc::new(SessionManager {
+            default_database: conf.default_database,
             sessions: RwLock::new(HashMap::new()),
-        })
+            weak_self: RwLock::new(Weak::new()),
+        });
+        *manager.weak_self.write() = Arc::downgrade(&manager);
+        manager
     }
 
     pub fn try_create_context(&self) -> Result<FuseQueryContextRef> {
         counter!(super::metrics::METRIC_SESSION_CONNECT_NUMBERS, 1);
 
         let ctx = FuseQueryContext::try_create()?;
+        ctx.set_current_database(self.default_database.clone())?;
+        ctx.set_session_manager(self.weak_self.read().clone());
         self.sessions.write().insert(ctx.get_id()?, ctx.clone());
         Ok(ctx)
     }
 
+    /// Snapshot of the active sessions, ordered by the started time.
+    pub fn get_sessions_info(&self) -> Result<Vec<SessionInfo>> {
+        let mut infos = self
+            .sessions
+            .read()
+            .values()
+            .map(|ctx| ctx.get_session_info())
+            .collect::<Result<Vec<_>>>()?;
+        infos.sort_by(|a, b| (&a.started_at, &a.id).cmp(&(&b.started_at, &b.id)));
+        Ok(infos)
+    }
+
     pub fn try_remove_context(&self, ctx: FuseQueryContextRef) -> Result<()> {
         counter!(super::metrics::METRIC_SESSION_CLOSE_NUMBERS, 1);
 
@@ -0,0 +1,53 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_session_manager_default_database() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::sessions::SessionManager;
+
+    // Default.
+    {
+        let sessions = SessionManager::create();
+        let ctx = sessions.try_create_context()?;
+        assert_eq!("default", ctx.get_current_database());
+    }
+
+    // Configured.
+    {
+        let mut conf = Config::default();
+        conf.default_database = "system".to_string();
+        let sessions = SessionManager::from_conf(conf);
+        let ctx = sessions.try_create_context()?;
+        assert_eq!("system", ctx.get_current_database());
+
+        // USE switches the current database of this session only.
+        ctx.set_current_database("default".to_string())?;
+        assert_eq!("default", ctx.get_current_database());
+        let other = sessions.try_create_context()?;
+        assert_eq!("system", other.get_current_database());
+
+        // USE an unknown database keeps the current one.
+        let result = ctx.set_current_database("missing_db".to_string());
+        assert_eq!(
+            "Code: 3, displayText = Database missing_db  doesn't exist..",
+            result.unwrap_err().to_string()
+        );
+        assert_eq!("default", ctx.get_current_database());
+    }
+
+    // Configured database doesn't exist.
+    {
+        let mut conf = Config::default();
+        conf.default_database = "missing_db".to_string();
+        let sessions = SessionManager::from_conf(conf);
+        let result = sessions.try_create_context();
+        assert_eq!(3, result.unwrap_err().code());
+        assert!(sessions.get_sessions_info()?.is_empty());
+    }
+
+    Ok(())
+}
@@ -11,10 +11,11 @@ use common_planners::Expression;
 use common_planners::ExpressionVisitor;
 use common_planners::Recursion;
 
-/// Resolves an `Expression::Wildcard` to a collection of `Expression::Column`'s.
+/// Resolves an `Expression::Wildcard` or `Expression::QualifiedWildcard` to a collection of
+/// `Expression::Column`'s, the qualifier is checked against the relation by the parser.
 pub fn expand_wildcard(expr: &Expression, schema: &DataSchemaRef) -> Vec<Expression> {
     match expr {
-        Expression::Wildcard => schema
+        Expression::Wildcard | Expression::QualifiedWildcard(_) => schema
             .fields()
             .iter()
             .map(|f| Expression::Column(f.name().to_string()))
@@ -237,6 +238,7 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
         // clone_with_replacement() on any nested Expressionessions.
         None => match expr {
             Expression::Wildcard => Ok(Expression::Wildcard),
+            Expression::QualifiedWildcard(_) => Ok(expr.clone()),
             Expression::Alias(alias_name, nested_expr) => Ok(Expression::Alias(
                 alias_name.clone(),
                 Box::new(clone_with_replacement(&**nested_expr, replacement_fn)?),
@@ -290,6 +292,47 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                 data_type: data_type.clone(),
             }),
 
+            Expression::If {
+                condition,
+                then,
+                otherwise,
+            } => Ok(Expression::If {
+                condition: Box::new(clone_with_replacement(&**condition, replacement_fn)?),
+                then: Box::new(clone_with_replacement(&**then, replacement_fn)?),
+                otherwise: Box::new(clone_with_replacement(&**otherwise, replacement_fn)?),
+            }),
+
+            Expression::Extract {
+                field,
+                expr: nested_expr,
+            } => Ok(Expression::Extract {
+                field: *field,
+                expr: Box::new(clone_with_replacement(&**nested_expr, replacement_fn)?),
+            }),
+
+            Expression::WindowFunction {
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame,
+            } => {
+                let clone_exprs = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|e| clone_with_replacement(e, replacement_fn))
+                        .collect::<Result<Vec<Expression>>>()
+                };
+
+                Ok(Expression::WindowFunction {
+                    func: func.clone(),
+                    args: clone_exprs(args)?,
+                    partition_by: clone_exprs(partition_by)?,
+                    order_by: clone_exprs(order_by)?,
+                    frame: frame.clone(),
+                })
+            }
+
             Expression::Column(_) | Expression::Literal(_) => Ok(expr.clone()),
         },
     }
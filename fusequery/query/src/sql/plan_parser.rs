@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -20,6 +21,7 @@ use common_exception::ErrorCodes;
 use common_exception::Result;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
+use common_planners::DatabaseEngineType;
 use common_planners::DropDatabasePlan;
 use common_planners::DropTablePlan;
 use common_planners::ExplainPlan;
@@ -29,6 +31,7 @@ use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
 use common_planners::SettingPlan;
+use common_planners::TableEngineType;
 use common_planners::UseDatabasePlan;
 use common_planners::VarValue;
 use sqlparser::ast::Expr;
@@ -155,7 +158,7 @@ impl PlanParser {
         Ok(PlanNode::CreateDatabase(CreateDatabasePlan {
             if_not_exists: create.if_not_exists,
             db: name,
-            engine: create.engine,
+            engine: DatabaseEngineType::from_str(&create.engine)?,
             options,
         }))
     }
@@ -215,7 +218,7 @@ impl PlanParser {
             db,
             table,
             schema,
-            engine: create.engine,
+            engine: TableEngineType::from_str(&create.engine)?,
             options,
         }))
     }
@@ -516,11 +519,42 @@ impl PlanParser {
                 Box::new(self.sql_to_rex(&expr, schema, select)?),
             )),
             sqlparser::ast::SelectItem::Wildcard => Ok(Expression::Wildcard),
-            _ => Result::Err(ErrorCodes::UnImplement(format!(
-                "SelectItem: {:?} are not supported",
-                sql
-            ))),
+            sqlparser::ast::SelectItem::QualifiedWildcard(name) => {
+                let qualifier = name.0.iter().map(|x| x.value.clone()).collect::<Vec<_>>();
+                self.check_wildcard_qualifier(&qualifier, select)?;
+                Ok(Expression::QualifiedWildcard(qualifier))
+            }
+        }
+    }
+
+    /// Check the qualifier of table.* is the relation of the select.
+    fn check_wildcard_qualifier(
+        &self,
+        qualifier: &[String],
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<()> {
+        use sqlparser::ast::TableFactor::Table;
+
+        if let Some(select) = select {
+            if let Some(from) = select.from.first() {
+                if let Table { name, alias, .. } = &from.relation {
+                    let table_name = name.0.iter().map(|x| x.value.clone()).collect::<Vec<_>>();
+                    let matched = match alias {
+                        Some(alias) => qualifier.len() == 1 && qualifier[0] == alias.name.value,
+                        None => table_name.ends_with(qualifier),
+                    };
+
+                    if matched {
+                        return Ok(());
+                    }
+                }
+            }
         }
+
+        Result::Err(ErrorCodes::UnknownTable(format!(
+            "Unknown table in wildcard: {}.*",
+            qualifier.join(".")
+        )))
     }
 
     fn plan_tables_with_joins(&self, from: &[sqlparser::ast::TableWithJoins]) -> Result<PlanNode> {
@@ -582,16 +616,27 @@ impl PlanParser {
                     }
 
                     let empty_schema = Arc::new(DataSchema::empty());
-                    match &args[0] {
-                        FunctionArg::Named { arg, .. } => {
-                            table_args =
-                                Some(self.sql_to_rex(&arg, empty_schema.as_ref(), None)?);
-                        }
-                        FunctionArg::Unnamed(arg) => {
-                            table_args =
-                                Some(self.sql_to_rex(&arg, empty_schema.as_ref(), None)?);
-                        }
-                    }
+                    let mut arg_exprs = args
+                        .iter()
+                        .map(|arg| match arg {
+                            FunctionArg::Named { arg, .. } => {
+                                self.sql_to_rex(&arg, empty_schema.as_ref(), None)
+                            }
+                            FunctionArg::Unnamed(arg) => {
+                                self.sql_to_rex(&arg, empty_schema.as_ref(), None)
+                            }
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    // Multiple arguments are packed as table_name(arg1, arg2, ...).
+                    table_args = if arg_exprs.len() == 1 {
+                        arg_exprs.pop()
+                    } else {
+                        Some(Expression::ScalarFunction {
+                            op: table_name.clone(),
+                            args: arg_exprs,
+                        })
+                    };
 
                     let table_function = self.ctx.get_table_function(&table_name)?;
                     table_name = table_function.name().to_string();
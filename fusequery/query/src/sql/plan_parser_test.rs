@@ -28,6 +28,12 @@ fn test_plan_parser() -> anyhow::Result<()> {
             expect: "Create database db1, engine: Remote, if_not_exists:true, option: {}",
             error: "",
         },
+        Test {
+            name: "create-database-unknown-engine",
+            sql: "CREATE DATABASE db1 ENGINE = XX",
+            expect: "",
+            error: "Code: 41, displayText = Unknown database engine 'XX', the supported engines are: Local, Remote.",
+        },
         Test {
             name: "drop-database-passed",
             sql: "DROP DATABASE db1",
@@ -52,6 +58,12 @@ fn test_plan_parser() -> anyhow::Result<()> {
             expect: "Create table default.t Field { name: \"c1\", data_type: Int32, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: None }, Field { name: \"c2\", data_type: Int64, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: None }, Field { name: \"c3\", data_type: Utf8, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: None }, engine: Parquet, if_not_exists:true, option: {\"location\": \"foo.parquet\"}",
             error: "",
         },
+        Test {
+            name: "create-table-unknown-engine",
+            sql: "CREATE TABLE t(c1 int) ENGINE = XX",
+            expect: "",
+            error: "Code: 40, displayText = Unknown table engine 'XX', the supported engines are: Parquet, JSONEachRaw, CSV, Null.",
+        },
         Test {
             name: "drop-table-passed",
             sql: "DROP TABLE t1",
@@ -112,6 +124,12 @@ fn test_plan_parser() -> anyhow::Result<()> {
             expect: "",
             error: "Code: 5, displayText = Interval field value out of range: \"100000000000000000 day\".",
         },
+        Test {
+            name: "qualified-wildcard-unknown-table",
+            sql: "select t.* from numbers(3)",
+            expect: "",
+            error: "Code: 25, displayText = Unknown table in wildcard: t.*.",
+        },
 
         Test {
             name: "insert-simple",
@@ -349,22 +349,14 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::UseDatabase(DfUseDatabase { name }))
     }
 
-    fn parse_database_engine(&mut self) -> Result<DatabaseEngineType, ParserError> {
+    fn parse_database_engine(&mut self) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
-            return Ok(DatabaseEngineType::Remote);
+            return Ok(DatabaseEngineType::Remote.to_string());
         }
 
         self.parser.expect_token(&Token::Eq)?;
-
-        match self.parser.next_token() {
-            Token::Word(w) => match &*w.value {
-                "Local" => Ok(DatabaseEngineType::Local),
-                "Remote" => Ok(DatabaseEngineType::Remote),
-                _ => self.expected("Engine must one of Local, Remote", Token::Word(w)),
-            },
-            unexpected => self.expected("Engine must one of Local, Remote", unexpected),
-        }
+        self.parse_engine_name()
     }
 
     fn parse_create_table(&mut self) -> Result<DfStatement, ParserError> {
@@ -398,30 +390,21 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateTable(create))
     }
 
-    /// Parses the set of valid formats
-    fn parse_table_engine(&mut self) -> Result<TableEngineType, ParserError> {
+    fn parse_table_engine(&mut self) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
-            return Ok(TableEngineType::Null);
+            return Ok(TableEngineType::Null.to_string());
         }
 
         self.parser.expect_token(&Token::Eq)?;
+        self.parse_engine_name()
+    }
 
+    // The name is resolved by the plan parser, an unknown engine is reported there with its own error code.
+    fn parse_engine_name(&mut self) -> Result<String, ParserError> {
         match self.parser.next_token() {
-            Token::Word(w) => match &*w.value {
-                "Parquet" => Ok(TableEngineType::Parquet),
-                "JSONEachRaw" => Ok(TableEngineType::JsonEachRaw),
-                "CSV" => Ok(TableEngineType::Csv),
-                "Null" => Ok(TableEngineType::Null),
-                _ => self.expected(
-                    "Engine must one of Parquet, JSONEachRaw, Null or CSV",
-                    Token::Word(w),
-                ),
-            },
-            unexpected => self.expected(
-                "Engine must one of Parquet, JSONEachRaw, Null or CSV",
-                unexpected,
-            ),
+            Token::Word(w) => Ok(w.value),
+            unexpected => self.expected("Engine name", unexpected),
         }
     }
 
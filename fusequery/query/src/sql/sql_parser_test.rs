@@ -5,8 +5,6 @@
 #[cfg(test)]
 mod tests {
     use common_exception::Result;
-    use common_planners::DatabaseEngineType;
-    use common_planners::TableEngineType;
     use sqlparser::ast::*;
 
     use crate::sql::sql_statement::DfDropDatabase;
@@ -65,7 +63,7 @@ mod tests {
             let expected = DfStatement::CreateDatabase(DfCreateDatabase {
                 if_not_exists: false,
                 name: ObjectName(vec![Ident::new("db1")]),
-                engine: DatabaseEngineType::Remote,
+                engine: "Remote".to_string(),
                 options: vec![],
             });
             expect_parse_ok(sql, expected)?;
@@ -76,7 +74,7 @@ mod tests {
             let expected = DfStatement::CreateDatabase(DfCreateDatabase {
                 if_not_exists: true,
                 name: ObjectName(vec![Ident::new("db1")]),
-                engine: DatabaseEngineType::Remote,
+                engine: "Remote".to_string(),
                 options: vec![],
             });
             expect_parse_ok(sql, expected)?;
@@ -87,16 +85,28 @@ mod tests {
             let expected = DfStatement::CreateDatabase(DfCreateDatabase {
                 if_not_exists: false,
                 name: ObjectName(vec![Ident::new("db1")]),
-                engine: DatabaseEngineType::Local,
+                engine: "Local".to_string(),
                 options: vec![],
             });
             expect_parse_ok(sql, expected)?;
         }
 
-        // Error cases: Invalid type
+        // Unknown engine names are resolved by the plan parser.
         {
             let sql = "CREATE DATABASE db1 ENGINE=XX";
-            expect_parse_error(sql, "Expected Engine must one of Local, Remote, found: XX")?;
+            let expected = DfStatement::CreateDatabase(DfCreateDatabase {
+                if_not_exists: false,
+                name: ObjectName(vec![Ident::new("db1")]),
+                engine: "XX".to_string(),
+                options: vec![],
+            });
+            expect_parse_ok(sql, expected)?;
+        }
+
+        // Error cases: Invalid engine name
+        {
+            let sql = "CREATE DATABASE db1 ENGINE=1";
+            expect_parse_error(sql, "Expected Engine name, found: 1")?;
         }
 
         Ok(())
@@ -132,7 +142,7 @@ mod tests {
             if_not_exists: false,
             name: ObjectName(vec![Ident::new("t")]),
             columns: vec![make_column_def("c1", DataType::Int)],
-            engine: TableEngineType::Csv,
+            engine: "CSV".to_string(),
             options: vec![SqlOption {
                 name: Ident::new("LOCATION".to_string()),
                 value: Value::SingleQuotedString("/data/33.csv".into()),
@@ -150,7 +160,7 @@ mod tests {
                 make_column_def("c2", DataType::BigInt),
                 make_column_def("c3", DataType::Varchar(Some(255))),
             ],
-            engine: TableEngineType::Parquet,
+            engine: "Parquet".to_string(),
             options: vec![SqlOption {
                 name: Ident::new("LOCATION".to_string()),
                 value: Value::SingleQuotedString("foo.parquet".into()),
@@ -158,12 +168,9 @@ mod tests {
         });
         expect_parse_ok(sql, expected)?;
 
-        // Error cases: Invalid type
-        let sql = "CREATE TABLE t(c1 int) ENGINE = XX location = 'foo.parquet' ";
-        expect_parse_error(
-            sql,
-            "Expected Engine must one of Parquet, JSONEachRaw, Null or CSV, found: XX",
-        )?;
+        // Error cases: Invalid engine name
+        let sql = "CREATE TABLE t(c1 int) ENGINE = 1 location = 'foo.parquet' ";
+        expect_parse_error(sql, "Expected Engine name, found: 1")?;
 
         Ok(())
     }
@@ -2,9 +2,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use common_planners::DatabaseEngineType;
 use common_planners::ExplainType;
-use common_planners::TableEngineType;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::SqlOption;
@@ -31,7 +29,7 @@ pub struct DfCreateTable {
     /// Table name
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
-    pub engine: TableEngineType,
+    pub engine: String,
     pub options: Vec<SqlOption>,
 }
 
@@ -45,7 +43,7 @@ pub struct DfDropTable {
 pub struct DfCreateDatabase {
     pub if_not_exists: bool,
     pub name: ObjectName,
-    pub engine: DatabaseEngineType,
+    pub engine: String,
     pub options: Vec<SqlOption>,
 }
 
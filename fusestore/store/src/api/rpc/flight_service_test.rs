@@ -245,3 +245,26 @@ async fn test_do_append() -> anyhow::Result<()> {
     });
     Ok(())
 }
+
+#[test(tokio::test)]
+async fn test_flight_ping() -> anyhow::Result<()> {
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    use crate::configs::config::FUSE_COMMIT_VERSION;
+
+    // 1. Service starts.
+    let addr = crate::tests::start_store_server().await?;
+
+    let mut client = StoreClient::try_create(addr.as_str(), "root", "xxx").await?;
+
+    // 2. Ping.
+    let before = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    let res = client.ping().await?;
+    info!("ping res: {:?}", res);
+
+    assert_eq!(FUSE_COMMIT_VERSION, res.version.as_str());
+    assert!(res.server_time_ms >= before);
+
+    Ok(())
+}
@@ -143,6 +143,34 @@ impl MemEngine {
         }
     }
 
+    // Rename a table, the table id is kept.
+    pub fn rename_table(
+        &mut self,
+        db_name: &str,
+        tbl_name: &str,
+        new_tbl_name: &str,
+    ) -> Result<(), Status> {
+        let db = self
+            .dbs
+            .get_mut(db_name)
+            .ok_or_else(|| Status::not_found(format!("database {} not found", db_name)))?;
+
+        if db.table_name_to_id.contains_key(new_tbl_name) {
+            return Err(Status::already_exists(format!(
+                "table {} exists",
+                new_tbl_name
+            )));
+        }
+
+        let table_id = db
+            .table_name_to_id
+            .remove(tbl_name)
+            .ok_or_else(|| Status::not_found(format!("table {} not found", tbl_name)))?;
+        db.table_name_to_id
+            .insert(new_tbl_name.to_string(), table_id);
+        Ok(())
+    }
+
     pub fn get_table(&mut self, db_name: String, table_name: String) -> Result<Table, Status> {
         let db = self
             .dbs
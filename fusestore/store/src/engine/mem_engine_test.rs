@@ -272,3 +272,56 @@ fn test_mem_engine_drop_table() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_mem_engine_rename_table() -> anyhow::Result<()> {
+    let eng = MemEngine::create();
+    let test_db = "test_db";
+    let mut eng = eng.lock().unwrap();
+
+    let cmd_db = CmdCreateDatabase {
+        db_name: test_db.to_string(),
+        db: Some(Db {
+            db_id: -1,
+            ver: -1,
+            table_name_to_id: HashMap::new(),
+            tables: HashMap::new(),
+        }),
+    };
+    let cmd_table = |name: &str| CmdCreateTable {
+        db_name: test_db.to_string(),
+        table_name: name.to_string(),
+        table: Some(Table {
+            table_id: -1,
+            ver: -1,
+            schema: vec![1, 2, 3],
+            options: maplit::hashmap! {"key".into() => "val".into()},
+            placement_policy: vec![1, 2, 3],
+        }),
+    };
+
+    eng.create_database(cmd_db, false).unwrap();
+    let table_id = eng.create_table(cmd_table("t1"), false).unwrap();
+    eng.create_table(cmd_table("t2"), false).unwrap();
+
+    // The table id is kept.
+    eng.rename_table(test_db, "t1", "t3")?;
+    let got = eng.get_table(test_db.to_string(), "t3".to_string())?;
+    assert_eq!(table_id, got.table_id);
+    let r = eng.get_table(test_db.to_string(), "t1".to_string());
+    assert_eq!(r.unwrap_err().code(), Code::NotFound);
+
+    // New name exists.
+    let r = eng.rename_table(test_db, "t3", "t2");
+    assert_eq!(r.unwrap_err().code(), Code::AlreadyExists);
+
+    // Table not exist.
+    let r = eng.rename_table(test_db, "t1", "t4");
+    assert_eq!(r.unwrap_err().code(), Code::NotFound);
+
+    // Db not exist.
+    let r = eng.rename_table("fake_db", "t3", "t4");
+    assert_eq!(r.unwrap_err().code(), Code::NotFound);
+
+    Ok(())
+}
@@ -6,6 +6,8 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_arrow::arrow::datatypes::Schema;
 use common_arrow::arrow_flight;
@@ -20,6 +22,10 @@ use common_flights::DropTableAction;
 use common_flights::DropTableActionResult;
 use common_flights::GetTableAction;
 use common_flights::GetTableActionResult;
+use common_flights::PingAction;
+use common_flights::PingActionResult;
+use common_flights::RenameTableAction;
+use common_flights::RenameTableActionResult;
 use common_flights::StoreDoAction;
 use common_flights::StoreDoActionResult;
 #[allow(unused_imports)]
@@ -31,6 +37,7 @@ use tokio_stream::StreamExt;
 use tonic::Status;
 use tonic::Streaming;
 
+use crate::configs::config::FUSE_COMMIT_VERSION;
 use crate::data_part::appender::Appender;
 use crate::engine::MemEngine;
 use crate::fs::IFileSystem;
@@ -81,10 +88,24 @@ impl ActionHandler {
             StoreDoAction::DropDatabase(act) => self.drop_db(act).await,
             StoreDoAction::CreateTable(a) => self.create_table(a).await,
             StoreDoAction::DropTable(act) => self.drop_table(act).await,
+            StoreDoAction::RenameTable(act) => self.rename_table(act).await,
             StoreDoAction::GetTable(a) => self.get_table(a).await,
+            StoreDoAction::Ping(a) => self.ping(a).await,
         }
     }
 
+    async fn ping(&self, _act: PingAction) -> Result<StoreDoActionResult, Status> {
+        let server_time_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| Status::internal(e.to_string()))?
+            .as_millis() as u64;
+
+        Ok(StoreDoActionResult::Ping(PingActionResult {
+            version: FUSE_COMMIT_VERSION.to_string(),
+            server_time_ms,
+        }))
+    }
+
     async fn create_db(&self, act: CreateDatabaseAction) -> Result<StoreDoActionResult, Status> {
         let plan = act.plan;
         let mut meta = self.meta.lock().unwrap();
@@ -185,6 +206,12 @@ impl ActionHandler {
         let _ = meta.drop_table(&act.plan.db, &act.plan.table, act.plan.if_exists)?;
         Ok(StoreDoActionResult::DropTable(DropTableActionResult {}))
     }
+
+    async fn rename_table(&self, act: RenameTableAction) -> Result<StoreDoActionResult, Status> {
+        let mut meta = self.meta.lock().unwrap();
+        meta.rename_table(&act.db, &act.table, &act.new_table)?;
+        Ok(StoreDoActionResult::RenameTable(RenameTableActionResult {}))
+    }
 }
 
 impl ActionHandler {
@@ -0,0 +1,2 @@
+set max_threads=1;
+SELECT Number as c1, (NUMBER+1) as c2 FROM numbers_mt(5) where Number > 1 order by c1 desc;